@@ -0,0 +1,348 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::vrchat_api::{LimitedAvatar, PerformanceRatings, UnityPackageSummary};
+
+/// Oldest Unity release VRChat still accepts uploads from without a warning
+pub const DEFAULT_MIN_UNITY_VERSION: &str = "2022.3";
+
+const PLATFORMS: [&str; 3] = ["standalonewindows", "android", "ios"];
+
+/// Rating counts and flagged avatars for one platform
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct PlatformPerformanceSummary {
+    /// VRChat platform key, e.g. `standalonewindows` or `android`
+    pub platform: String,
+    /// Avatars with a build for this platform
+    pub built: u32,
+    pub excellent: u32,
+    pub good: u32,
+    pub medium: u32,
+    pub poor: u32,
+    pub very_poor: u32,
+    /// Built but not rated yet
+    pub unrated: u32,
+    /// IDs of avatars rated Poor or VeryPoor
+    pub poor_or_worse: Vec<String>,
+}
+
+/// Per-avatar line of the report
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct AvatarReportRow {
+    pub id: String,
+    pub name: String,
+    pub release_status: String,
+    pub pc_rating: Option<PerformanceRatings>,
+    pub android_rating: Option<PerformanceRatings>,
+    pub ios_rating: Option<PerformanceRatings>,
+    pub has_quest_build: bool,
+    /// Oldest Unity version across the avatar's current builds
+    pub unity_version: Option<String>,
+    pub outdated_unity: bool,
+    pub failed_scan: bool,
+}
+
+/// Aggregated performance overview of uploaded avatars
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct AvatarPerformanceReport {
+    pub total: u32,
+    pub min_unity_version: String,
+    pub platforms: Vec<PlatformPerformanceSummary>,
+    /// IDs of avatars without an Android build
+    pub missing_quest: Vec<String>,
+    /// IDs of avatars built with a Unity version older than `min_unity_version`
+    pub outdated_unity: Vec<String>,
+    /// IDs of avatars with a build that failed the security scan
+    pub failed_scan: Vec<String>,
+    pub avatars: Vec<AvatarReportRow>,
+}
+
+/// CSV export layout, one row per avatar
+#[derive(Debug, Serialize)]
+struct CsvRow<'a> {
+    id: &'a str,
+    name: &'a str,
+    release_status: &'a str,
+    pc_rating: String,
+    android_rating: String,
+    ios_rating: String,
+    has_quest_build: bool,
+    unity_version: &'a str,
+    outdated_unity: bool,
+    failed_scan: bool,
+}
+
+impl AvatarPerformanceReport {
+    pub fn build(avatars: &[LimitedAvatar], min_unity_version: &str) -> Self {
+        let min_unity = parse_unity_version(min_unity_version);
+        let mut platforms: Vec<PlatformPerformanceSummary> = PLATFORMS
+            .iter()
+            .map(|platform| PlatformPerformanceSummary {
+                platform: platform.to_string(),
+                ..Default::default()
+            })
+            .collect();
+        let mut missing_quest = Vec::new();
+        let mut outdated_unity = Vec::new();
+        let mut failed_scan = Vec::new();
+        let mut rows = Vec::with_capacity(avatars.len());
+
+        for avatar in avatars {
+            let packages = current_packages(avatar);
+            let mut ratings = [None; 3];
+
+            for (index, summary) in platforms.iter_mut().enumerate() {
+                let built = packages
+                    .iter()
+                    .any(|p| p.platform.as_deref() == Some(&summary.platform));
+                if !built {
+                    continue;
+                }
+
+                let rating = platform_rating(avatar, &packages, &summary.platform);
+                summary.built += 1;
+                match rating {
+                    Some(PerformanceRatings::Excellent) => summary.excellent += 1,
+                    Some(PerformanceRatings::Good) => summary.good += 1,
+                    Some(PerformanceRatings::Medium) => summary.medium += 1,
+                    Some(PerformanceRatings::Poor) => summary.poor += 1,
+                    Some(PerformanceRatings::VeryPoor) => summary.very_poor += 1,
+                    Some(PerformanceRatings::None) | None => summary.unrated += 1,
+                }
+                if matches!(
+                    rating,
+                    Some(PerformanceRatings::Poor | PerformanceRatings::VeryPoor)
+                ) {
+                    summary.poor_or_worse.push(avatar.id.clone());
+                }
+                ratings[index] = rating;
+            }
+
+            let has_quest_build = packages
+                .iter()
+                .any(|p| p.platform.as_deref() == Some("android"));
+            if !has_quest_build {
+                missing_quest.push(avatar.id.clone());
+            }
+
+            let unity_version = packages
+                .iter()
+                .filter_map(|p| p.unity_version.as_deref())
+                .min_by_key(|v| parse_unity_version(v))
+                .map(str::to_string);
+            let is_outdated = match (&unity_version, min_unity) {
+                (Some(version), Some(min)) => parse_unity_version(version).is_some_and(|v| v < min),
+                _ => false,
+            };
+            if is_outdated {
+                outdated_unity.push(avatar.id.clone());
+            }
+
+            let is_failed_scan = packages.iter().any(|p| {
+                p.scan_status
+                    .as_deref()
+                    .is_some_and(|s| s.eq_ignore_ascii_case("failed"))
+            });
+            if is_failed_scan {
+                failed_scan.push(avatar.id.clone());
+            }
+
+            rows.push(AvatarReportRow {
+                id: avatar.id.clone(),
+                name: avatar.name.clone(),
+                release_status: avatar.release_status.to_string(),
+                pc_rating: ratings[0],
+                android_rating: ratings[1],
+                ios_rating: ratings[2],
+                has_quest_build,
+                unity_version,
+                outdated_unity: is_outdated,
+                failed_scan: is_failed_scan,
+            });
+        }
+
+        Self {
+            total: avatars.len() as u32,
+            min_unity_version: min_unity_version.to_string(),
+            platforms,
+            missing_quest,
+            outdated_unity,
+            failed_scan,
+            avatars: rows,
+        }
+    }
+
+    pub fn to_csv(&self) -> Result<String, String> {
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        let rating = |r: Option<PerformanceRatings>| r.map(|r| r.to_string()).unwrap_or_default();
+
+        for row in &self.avatars {
+            writer
+                .serialize(CsvRow {
+                    id: &row.id,
+                    name: &row.name,
+                    release_status: &row.release_status,
+                    pc_rating: rating(row.pc_rating),
+                    android_rating: rating(row.android_rating),
+                    ios_rating: rating(row.ios_rating),
+                    has_quest_build: row.has_quest_build,
+                    unity_version: row.unity_version.as_deref().unwrap_or_default(),
+                    outdated_unity: row.outdated_unity,
+                    failed_scan: row.failed_scan,
+                })
+                .map_err(|e| format!("Failed to write avatar report CSV: {}", e))?;
+        }
+
+        let bytes = writer
+            .into_inner()
+            .map_err(|e| format!("Failed to write avatar report CSV: {}", e))?;
+        String::from_utf8(bytes).map_err(|e| format!("Failed to write avatar report CSV: {}", e))
+    }
+}
+
+/// Latest non-impostor package per platform; older uploads stay listed in `unityPackages`
+fn current_packages(avatar: &LimitedAvatar) -> Vec<&UnityPackageSummary> {
+    let mut latest: Vec<&UnityPackageSummary> = Vec::new();
+
+    for package in &avatar.unity_packages {
+        if package.platform.is_none() || package.variant.as_deref() == Some("impostor") {
+            continue;
+        }
+        match latest.iter_mut().find(|p| p.platform == package.platform) {
+            Some(existing) if existing.asset_version < package.asset_version => *existing = package,
+            Some(_) => {}
+            None => latest.push(package),
+        }
+    }
+
+    latest
+}
+
+/// Package rating for the platform, falling back to the avatar-level `performance` summary
+fn platform_rating(
+    avatar: &LimitedAvatar,
+    packages: &[&UnityPackageSummary],
+    platform: &str,
+) -> Option<PerformanceRatings> {
+    let from_package = packages
+        .iter()
+        .find(|p| p.platform.as_deref() == Some(platform))
+        .and_then(|p| p.performance_rating.as_deref());
+    let from_summary = avatar.performance.as_ref().and_then(|perf| match platform {
+        "standalonewindows" => perf.standalonewindows.as_deref(),
+        "android" => perf.android.as_deref(),
+        "ios" => perf.ios.as_deref(),
+        _ => None,
+    });
+
+    from_package.or(from_summary).and_then(parse_rating)
+}
+
+fn parse_rating(rating: &str) -> Option<PerformanceRatings> {
+    match rating {
+        "Excellent" => Some(PerformanceRatings::Excellent),
+        "Good" => Some(PerformanceRatings::Good),
+        "Medium" => Some(PerformanceRatings::Medium),
+        "Poor" => Some(PerformanceRatings::Poor),
+        "VeryPoor" => Some(PerformanceRatings::VeryPoor),
+        "None" => Some(PerformanceRatings::None),
+        _ => None,
+    }
+}
+
+/// `2022.3.22f1` -> `(2022, 3)`; patch releases don't matter for upload compatibility
+fn parse_unity_version(version: &str) -> Option<(u32, u32)> {
+    let mut parts = version.split('.');
+    let year = parts.next()?.trim().parse().ok()?;
+    let minor = parts.next().and_then(|m| m.parse().ok()).unwrap_or(0);
+    Some((year, minor))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vrchat_api::AvatarPerformance;
+
+    fn package(platform: &str, version: i32, unity: &str, rating: &str) -> UnityPackageSummary {
+        serde_json::from_value(serde_json::json!({
+            "platform": platform,
+            "assetVersion": version,
+            "unityVersion": unity,
+            "performanceRating": rating,
+            "scanStatus": "passed",
+        }))
+        .unwrap()
+    }
+
+    fn avatar(id: &str, packages: Vec<UnityPackageSummary>) -> LimitedAvatar {
+        let mut avatar: LimitedAvatar = serde_json::from_value(serde_json::json!({
+            "id": id,
+            "name": id,
+        }))
+        .unwrap();
+        avatar.unity_packages = packages;
+        avatar
+    }
+
+    #[test]
+    fn test_report_flags_per_platform() {
+        let mut failed = package("android", 2, "2022.3.22f1", "Good");
+        failed.scan_status = Some("failed".to_string());
+        let avatars = vec![
+            avatar(
+                "avtr_a",
+                vec![
+                    package("standalonewindows", 1, "2022.3.6f1", "Good"),
+                    // Superseded by version 3
+                    package("android", 2, "2022.3.6f1", "Good"),
+                    package("android", 3, "2022.3.22f1", "VeryPoor"),
+                ],
+            ),
+            avatar(
+                "avtr_b",
+                vec![package("standalonewindows", 1, "2019.4.31f1", "Poor")],
+            ),
+            avatar("avtr_c", vec![failed]),
+        ];
+
+        let report = AvatarPerformanceReport::build(&avatars, DEFAULT_MIN_UNITY_VERSION);
+        assert_eq!(report.total, 3);
+
+        let pc = &report.platforms[0];
+        assert_eq!((pc.built, pc.good, pc.poor), (2, 1, 1));
+        assert_eq!(pc.poor_or_worse, vec!["avtr_b"]);
+
+        let android = &report.platforms[1];
+        assert_eq!((android.built, android.good, android.very_poor), (2, 1, 1));
+        assert_eq!(android.poor_or_worse, vec!["avtr_a"]);
+
+        assert_eq!(report.missing_quest, vec!["avtr_b"]);
+        assert_eq!(report.outdated_unity, vec!["avtr_b"]);
+        assert_eq!(report.failed_scan, vec!["avtr_c"]);
+    }
+
+    #[test]
+    fn test_rating_falls_back_to_performance_summary() {
+        let mut unrated = package("android", 1, "2022.3.22f1", "");
+        unrated.performance_rating = None;
+        let mut avatar = avatar("avtr_a", vec![unrated]);
+        avatar.performance = Some(AvatarPerformance {
+            android: Some("Medium".to_string()),
+            ..Default::default()
+        });
+
+        let report = AvatarPerformanceReport::build(&[avatar], DEFAULT_MIN_UNITY_VERSION);
+        assert_eq!(
+            report.avatars[0].android_rating,
+            Some(PerformanceRatings::Medium)
+        );
+
+        let csv = report.to_csv().unwrap();
+        let mut lines = csv.lines();
+        assert!(lines.next().unwrap().starts_with("id,name,release_status"));
+        assert!(lines.next().unwrap().contains(",Medium,"));
+    }
+}
@@ -0,0 +1,479 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+use tokio::sync::Mutex;
+
+use crate::http_common::USER_AGENT_STRING;
+use crate::store::{
+    AlertAction, AlertRule, AlertRuleStore, AlertTrigger, FavoritesStore, UserStore,
+};
+use crate::vrchat_api::types::{
+    FavoriteType, GroupAccessType, InstanceAccessType, LimitedUserFriend, Location,
+};
+use crate::vrchat_api::{VRCError, VRChatClient};
+use crate::websocket::WebSocketMessage;
+
+const WEBHOOK_TIMEOUT_SECS: u64 = 10;
+
+/// Payload of `friend-alert` and `friend-alert-notification`, also the webhook body
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct FriendAlert {
+    pub rule_id: i32,
+    pub rule_name: String,
+    pub trigger: AlertTrigger,
+    pub user_id: String,
+    pub display_name: String,
+    /// Raw location, when the friend's location is known
+    pub location: Option<String>,
+    pub avatar_image_url: Option<String>,
+}
+
+/// Payload of `friend-alert-sound`
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct FriendAlertSound {
+    pub key: String,
+    pub alert: FriendAlert,
+}
+
+/// What a pipeline message means for alert rules
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlertEvent {
+    pub trigger: AlertTrigger,
+    pub user_id: String,
+    pub display_name: String,
+    pub location: Option<Location>,
+    pub avatar_image_url: Option<String>,
+}
+
+impl AlertEvent {
+    fn new(trigger: AlertTrigger, friend: &LimitedUserFriend, location: Option<Location>) -> Self {
+        Self {
+            trigger,
+            user_id: friend.id.clone(),
+            display_name: friend.display_name.clone(),
+            location,
+            avatar_image_url: friend.current_avatar_image_url.clone(),
+        }
+    }
+
+    /// Friend the message is about, whose previous state `from_message` needs
+    pub fn subject(message: &WebSocketMessage) -> Option<&str> {
+        match message {
+            WebSocketMessage::FriendOnline(payload) => Some(payload.as_inner().user_id.as_str()),
+            WebSocketMessage::FriendLocation(payload) => Some(payload.as_inner().user_id.as_str()),
+            WebSocketMessage::FriendUpdate(payload) => Some(payload.as_inner().user_id.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Events in `message`, given the friend as known before the message is applied
+    pub fn from_message(
+        message: &WebSocketMessage,
+        previous: Option<&LimitedUserFriend>,
+    ) -> Vec<Self> {
+        match message {
+            WebSocketMessage::FriendOnline(payload) => {
+                let content = payload.as_inner();
+                let mut events = vec![Self::new(
+                    AlertTrigger::FriendOnline,
+                    &content.user,
+                    content.location.clone(),
+                )];
+                if let Some(location) = content.location.as_ref().filter(|l| is_public(l)) {
+                    events.push(Self::new(
+                        AlertTrigger::JoinedPublicInstance,
+                        &content.user,
+                        Some(location.clone()),
+                    ));
+                }
+                events
+            }
+            WebSocketMessage::FriendLocation(payload) => {
+                let content = payload.as_inner();
+                let previous_location = previous.and_then(|friend| friend.location.as_ref());
+                if !is_public(&content.location) || previous_location == Some(&content.location) {
+                    return Vec::new();
+                }
+                match content.user.as_ref().or(previous) {
+                    Some(friend) => vec![Self::new(
+                        AlertTrigger::JoinedPublicInstance,
+                        friend,
+                        Some(content.location.clone()),
+                    )],
+                    None => vec![Self {
+                        trigger: AlertTrigger::JoinedPublicInstance,
+                        user_id: content.user_id.clone(),
+                        display_name: content.user_id.clone(),
+                        location: Some(content.location.clone()),
+                        avatar_image_url: None,
+                    }],
+                }
+            }
+            WebSocketMessage::FriendUpdate(payload) => {
+                let content = payload.as_inner();
+                match previous {
+                    Some(previous)
+                        if previous.current_avatar_image_url
+                            != content.user.current_avatar_image_url =>
+                    {
+                        let location = content.user.location.clone();
+                        vec![Self::new(
+                            AlertTrigger::AvatarChanged,
+                            &content.user,
+                            location,
+                        )]
+                    }
+                    _ => Vec::new(),
+                }
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn alert_for(&self, rule: &AlertRule) -> FriendAlert {
+        FriendAlert {
+            rule_id: rule.id,
+            rule_name: rule.name.clone(),
+            trigger: self.trigger,
+            user_id: self.user_id.clone(),
+            display_name: self.display_name.clone(),
+            location: self.location.as_ref().map(Location::to_string),
+            avatar_image_url: self.avatar_image_url.clone(),
+        }
+    }
+}
+
+/// Public instances and group instances open to everyone
+fn is_public(location: &Location) -> bool {
+    location
+        .instance()
+        .is_some_and(|instance| match instance.access_type() {
+            InstanceAccessType::Public => true,
+            InstanceAccessType::Group => {
+                instance.group_access_type() == Some(GroupAccessType::Public)
+            }
+            _ => false,
+        })
+}
+
+/// Enabled rules matching `event`, with the alert each one sends.
+/// `favorite_groups` are the friend favorite groups the event's friend is in.
+pub fn evaluate<'a>(
+    rules: &'a [AlertRule],
+    event: &AlertEvent,
+    favorite_groups: &[String],
+) -> Vec<(&'a AlertRule, FriendAlert)> {
+    rules
+        .iter()
+        .filter(|rule| {
+            rule.enabled
+                && rule.trigger == event.trigger
+                && rule.user_id.as_ref().is_none_or(|id| *id == event.user_id)
+                && rule
+                    .favorite_group
+                    .as_ref()
+                    .is_none_or(|group| favorite_groups.contains(group))
+        })
+        .map(|rule| (rule, event.alert_for(rule)))
+        .collect()
+}
+
+/// Runs friend alert rules against pipeline messages
+#[derive(Clone)]
+pub struct FriendAlerts {
+    client: VRChatClient,
+    rule_store: AlertRuleStore,
+    favorites_store: FavoritesStore,
+    /// Rules as last loaded, dropped whenever they change
+    rules: Arc<Mutex<Option<Vec<AlertRule>>>>,
+    http: reqwest::Client,
+}
+
+impl FriendAlerts {
+    pub fn new(
+        client: VRChatClient,
+        rule_store: AlertRuleStore,
+        favorites_store: FavoritesStore,
+    ) -> Self {
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_secs(WEBHOOK_TIMEOUT_SECS))
+            .user_agent(USER_AGENT_STRING)
+            .build()
+            .unwrap_or_default();
+
+        Self {
+            client,
+            rule_store,
+            favorites_store,
+            rules: Arc::new(Mutex::new(None)),
+            http,
+        }
+    }
+
+    /// Reload rules from the store on next use, after they were saved or deleted
+    pub async fn invalidate_rules(&self) {
+        *self.rules.lock().await = None;
+    }
+
+    async fn load_rules(&self) -> Option<Vec<AlertRule>> {
+        let mut cached = self.rules.lock().await;
+        if let Some(rules) = cached.as_ref() {
+            return Some(rules.clone());
+        }
+
+        match self.rule_store.get_rules().await {
+            Ok(rules) => {
+                *cached = Some(rules.clone());
+                Some(rules)
+            }
+            Err(e) => {
+                log::warn!("{}", e);
+                None
+            }
+        }
+    }
+
+    /// Friend favorite groups `user_id` is in. Favorites are fetched first when a rule
+    /// is scoped to a group and nothing else has loaded them this session.
+    pub async fn favorite_groups(&self, rules: &[AlertRule], user_id: &str) -> Vec<String> {
+        let scoped = rules
+            .iter()
+            .any(|rule| rule.enabled && rule.favorite_group.is_some());
+        if !scoped {
+            return Vec::new();
+        }
+
+        if !self.favorites_store.is_loaded().await {
+            let loaded = async {
+                let favorites = self.client.get_favorites(None).await?;
+                let groups = self.client.get_favorite_groups().await?;
+                self.favorites_store.set_all(favorites, groups).await;
+                Ok::<_, VRCError>(())
+            };
+            if let Err(e) = loaded.await {
+                log::warn!("Failed to load favorites for friend alerts: {}", e);
+                return Vec::new();
+            }
+        }
+
+        self.favorites_store
+            .get_favorite(user_id)
+            .await
+            .filter(|favorite| favorite.kind == FavoriteType::Friend)
+            .map(|favorite| favorite.tags)
+            .unwrap_or_default()
+    }
+
+    /// Must run before the message is applied, while `user_store` still has the previous state
+    pub async fn process(
+        &self,
+        message: &WebSocketMessage,
+        user_store: &UserStore,
+        app_handle: &AppHandle,
+    ) {
+        let Some(user_id) = AlertEvent::subject(message) else {
+            return;
+        };
+        let previous = user_store.get_user(user_id).await;
+        let events = AlertEvent::from_message(message, previous.as_ref());
+        if events.is_empty() {
+            return;
+        }
+
+        let Some(rules) = self.load_rules().await else {
+            return;
+        };
+        let favorite_groups = self.favorite_groups(&rules, user_id).await;
+
+        for event in &events {
+            for (rule, alert) in evaluate(&rules, event, &favorite_groups) {
+                log::info!(
+                    "Friend alert '{}' matched {} ({})",
+                    rule.name,
+                    alert.display_name,
+                    alert.user_id
+                );
+                for action in &rule.actions {
+                    self.run_action(action, &alert, app_handle);
+                }
+            }
+        }
+    }
+
+    fn run_action(&self, action: &AlertAction, alert: &FriendAlert, app_handle: &AppHandle) {
+        match action {
+            AlertAction::Event => {
+                let _ = app_handle.emit("friend-alert", alert);
+            }
+            AlertAction::Notification => {
+                let _ = app_handle.emit("friend-alert-notification", alert);
+            }
+            AlertAction::Sound { key } => {
+                let sound = FriendAlertSound {
+                    key: key.clone(),
+                    alert: alert.clone(),
+                };
+                let _ = app_handle.emit("friend-alert-sound", &sound);
+            }
+            AlertAction::Webhook { url } => {
+                // Don't hold up the pipeline on a slow endpoint
+                let request = self.http.post(url).json(alert);
+                let url = url.clone();
+                tauri::async_runtime::spawn(async move {
+                    match request.send().await {
+                        Ok(response) if response.status().is_success() => {}
+                        Ok(response) => {
+                            log::warn!(
+                                "Friend alert webhook {} returned {}",
+                                url,
+                                response.status()
+                            )
+                        }
+                        Err(e) => log::warn!("Friend alert webhook {} failed: {}", url, e),
+                    }
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Recorded pipeline messages, `content` is double encoded like on the wire
+    const FRIEND_ONLINE: &str = r#"{"type":"friend-online","content":"{\"userId\":\"usr_alice\",\"platform\":\"standalonewindows\",\"location\":\"wrld_plaza:12345~region(jp)\",\"canRequestInvite\":true,\"user\":{\"id\":\"usr_alice\",\"displayName\":\"Alice\",\"currentAvatarImageUrl\":\"https://img/alice-1.png\",\"status\":\"active\",\"platform\":\"standalonewindows\"}}"}"#;
+    const FRIEND_LOCATION_PRIVATE: &str = r#"{"type":"friend-location","content":"{\"userId\":\"usr_alice\",\"location\":\"wrld_home:1~private(usr_alice)~region(jp)\",\"worldId\":\"wrld_home\",\"user\":{\"id\":\"usr_alice\",\"displayName\":\"Alice\",\"status\":\"ask me\"}}"}"#;
+    const FRIEND_LOCATION_GROUP_PUBLIC: &str = r#"{"type":"friend-location","content":"{\"userId\":\"usr_bob\",\"location\":\"wrld_club:7~group(grp_club)~groupAccessType(public)~region(eu)\",\"worldId\":\"wrld_club\",\"travelingToLocation\":\"\"}"}"#;
+    const FRIEND_UPDATE: &str = r#"{"type":"friend-update","content":"{\"userId\":\"usr_alice\",\"user\":{\"id\":\"usr_alice\",\"displayName\":\"Alice\",\"currentAvatarImageUrl\":\"https://img/alice-2.png\",\"status\":\"active\"}}"}"#;
+
+    fn message(raw: &str) -> WebSocketMessage {
+        serde_json::from_str(raw).unwrap()
+    }
+
+    fn friend(id: &str, avatar: &str, location: Option<&str>) -> LimitedUserFriend {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "displayName": id,
+            "currentAvatarImageUrl": avatar,
+            "location": location,
+        }))
+        .unwrap()
+    }
+
+    fn rule(id: i32, trigger: AlertTrigger) -> AlertRule {
+        AlertRule {
+            id,
+            name: format!("rule {}", id),
+            enabled: true,
+            trigger,
+            user_id: None,
+            favorite_group: None,
+            actions: vec![AlertAction::Event],
+            created_at: "2026-03-01T00:00:00.000Z".to_string(),
+        }
+    }
+
+    fn matched(rules: &[AlertRule], events: &[AlertEvent], groups: &[String]) -> Vec<i32> {
+        events
+            .iter()
+            .flat_map(|event| evaluate(rules, event, groups))
+            .map(|(rule, _)| rule.id)
+            .collect()
+    }
+
+    #[test]
+    fn test_friend_online_in_public_instance() {
+        let online = message(FRIEND_ONLINE);
+        assert_eq!(AlertEvent::subject(&online), Some("usr_alice"));
+
+        let events = AlertEvent::from_message(&online, None);
+        let triggers: Vec<_> = events.iter().map(|e| e.trigger).collect();
+        assert_eq!(
+            triggers,
+            vec![
+                AlertTrigger::FriendOnline,
+                AlertTrigger::JoinedPublicInstance
+            ]
+        );
+
+        let mut alice = rule(1, AlertTrigger::FriendOnline);
+        alice.user_id = Some("usr_alice".to_string());
+        let mut bob = rule(2, AlertTrigger::FriendOnline);
+        bob.user_id = Some("usr_bob".to_string());
+        let mut close_friends = rule(3, AlertTrigger::JoinedPublicInstance);
+        close_friends.favorite_group = Some("group_0".to_string());
+        let mut disabled = rule(4, AlertTrigger::FriendOnline);
+        disabled.enabled = false;
+        let rules = [alice, bob, close_friends, disabled];
+
+        assert_eq!(matched(&rules, &events, &[]), vec![1]);
+        assert_eq!(
+            matched(&rules, &events, &["group_0".to_string()]),
+            vec![1, 3]
+        );
+
+        let (_, alert) = evaluate(&rules, &events[0], &[]).remove(0);
+        assert_eq!(alert.display_name, "Alice");
+        assert_eq!(
+            alert.location.as_deref(),
+            Some("wrld_plaza:12345~region(jp)")
+        );
+        assert_eq!(
+            alert.avatar_image_url.as_deref(),
+            Some("https://img/alice-1.png")
+        );
+    }
+
+    #[test]
+    fn test_location_alerts_only_for_new_public_instances() {
+        let rules = [rule(1, AlertTrigger::JoinedPublicInstance)];
+
+        let private = message(FRIEND_LOCATION_PRIVATE);
+        assert!(AlertEvent::from_message(&private, None).is_empty());
+
+        let group_public = message(FRIEND_LOCATION_GROUP_PUBLIC);
+        let bob = friend("usr_bob", "https://img/bob.png", Some("wrld_home:1"));
+        let events = AlertEvent::from_message(&group_public, Some(&bob));
+        assert_eq!(matched(&rules, &events, &[]), vec![1]);
+        // Display name falls back to the cached friend when the message has no user
+        assert_eq!(events[0].display_name, "usr_bob");
+        assert_eq!(
+            events[0].avatar_image_url.as_deref(),
+            Some("https://img/bob.png")
+        );
+
+        let already_there = friend(
+            "usr_bob",
+            "https://img/bob.png",
+            Some("wrld_club:7~group(grp_club)~groupAccessType(public)~region(eu)"),
+        );
+        assert!(AlertEvent::from_message(&group_public, Some(&already_there)).is_empty());
+    }
+
+    #[test]
+    fn test_avatar_change_needs_previous_state() {
+        let rules = [
+            rule(1, AlertTrigger::AvatarChanged),
+            rule(2, AlertTrigger::FriendOnline),
+        ];
+        let update = message(FRIEND_UPDATE);
+
+        assert!(AlertEvent::from_message(&update, None).is_empty());
+
+        let unchanged = friend("usr_alice", "https://img/alice-2.png", None);
+        assert!(AlertEvent::from_message(&update, Some(&unchanged)).is_empty());
+
+        let before = friend("usr_alice", "https://img/alice-1.png", None);
+        let events = AlertEvent::from_message(&update, Some(&before));
+        assert_eq!(matched(&rules, &events, &[]), vec![1]);
+        assert_eq!(
+            events[0].avatar_image_url.as_deref(),
+            Some("https://img/alice-2.png")
+        );
+    }
+}
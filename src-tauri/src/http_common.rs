@@ -15,10 +15,12 @@ use reqwest::header::{
     ACCEPT, AUTHORIZATION, CONTENT_TYPE, HeaderMap, HeaderValue, ORIGIN, USER_AGENT,
 };
 
-pub fn build_api_headers(auth: Option<&str>, cookie: Option<&str>) -> HeaderMap {
+pub fn build_api_headers(user_agent: &str, auth: Option<&str>, cookie: Option<&str>) -> HeaderMap {
     let mut headers = HeaderMap::new();
 
-    headers.insert(USER_AGENT, HeaderValue::from_static(USER_AGENT_STRING));
+    let user_agent = HeaderValue::from_str(user_agent)
+        .unwrap_or_else(|_| HeaderValue::from_static(USER_AGENT_STRING));
+    headers.insert(USER_AGENT, user_agent);
     headers.insert(ACCEPT, HeaderValue::from_static("*/*"));
     headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
     headers.insert(ORIGIN, HeaderValue::from_static("https://vrchat.com"));
@@ -1,39 +1,79 @@
+pub mod avatar_report;
 pub mod database_studio;
+pub mod friend_alerts;
 pub mod http_common;
 pub mod log_manager;
+pub mod rate_limiter;
+pub mod status_scheduler;
 pub mod store;
 pub mod vrchat_api;
 pub mod vrchat_status;
 pub mod websocket;
 
+use avatar_report::{
+    AvatarPerformanceReport, AvatarReportRow, DEFAULT_MIN_UNITY_VERSION, PlatformPerformanceSummary,
+};
 use database_studio::{ColumnInfo, DatabaseStudio, QueryResult, TableInfo};
+use friend_alerts::{FriendAlert, FriendAlertSound, FriendAlerts};
 use log::info;
 use log_manager::{LogEntry, LogManager};
+use status_scheduler::{StatusRuleChange, StatusScheduler};
+use std::collections::HashSet;
 use std::sync::Arc;
-use store::{AccountStore, AppSettings, ImageCacheStore, SettingsStore, StoredAccount, UserStore};
-use tauri::{Manager, State};
+use store::user_store::CurrentUserPipelineUpdate;
+use store::{
+    AccountStore, AlertAction, AlertRule, AlertRuleInput, AlertRuleStore, AlertTrigger,
+    AppSettings, FavoriteAnnotation, FavoritesStore, FeedEntry, FeedEventType, FeedPage, FeedQuery,
+    FeedStore, GroupAuditEntry, GroupAuditPage, GroupAuditQuery, GroupAuditStore, ImageCacheStore,
+    InboxNotification, InboxResponse, LocalCollection, LocalFavoriteEntry, LocalFavoriteInput,
+    LocalFavoriteKind, LocalFavoritesFormat, LocalFavoritesImport, LocalFavoritesStore,
+    ModerationStore, NotificationStore, SettingsStore, StatusHistoryEntry, StatusPreset,
+    StatusRule, StatusRuleInput, StatusRuleKind, StatusRuleStore, StoredAccount, UserStore,
+    WorldStore,
+};
+use tauri::{AppHandle, Emitter, Manager, State};
 use tauri_specta::{Builder as SpectaBuilder, collect_commands};
 use tokio::sync::Mutex;
 use vrchat_api::{
-    AgeVerificationStatus, AvatarPerformance, AvatarStyles, Badge, DeveloperType, DiscordDetails,
-    FriendRequestStatus, GoogleDetails, LimitedAvatar, LimitedUserFriend, LimitedWorld,
-    LoginCredentials, LoginResult, OrderOption, PastDisplayName, PerformanceRatings, ReleaseStatus,
-    SteamDetails, TwoFactorMethod, UnityPackageSummary, UpdateStatusRequest, User, UserState,
-    UserStatus, VRCError, VRChatClient,
+    AddFavoriteRequest, AgeVerificationStatus, AvatarPerformance, AvatarStyles, Badge,
+    CreateInstanceRequest, DeveloperType, DiscordDetails, Favorite, FavoriteGroup,
+    FavoriteGroupVisibility, FavoriteLimits, FavoriteType, FavoriteTypeLimits, FriendRequestStatus,
+    FriendStatus, GoogleDetails, Group, GroupAccessType, GroupAnnouncement, GroupInstance,
+    GroupJoinRequestAction, GroupJoinState, GroupMember, GroupMemberStatus, GroupMemberUser,
+    GroupPost, GroupPrivacy, GroupRole, Instance, InstancePlatforms, InstanceShortName,
+    InstanceType, LimitedAvatar, LimitedUser, LimitedUserFriend, LimitedWorld, LoginCredentials,
+    LoginResult, OrderOption, PastDisplayName, PerformanceRatings, PlayerModeration,
+    PlayerModerationType, ReleaseStatus, RepresentedGroup, SentNotification, SessionExpiredEvent,
+    SessionExpiredNotice, SortOption, SteamDetails, TwoFactorMethod, UnityPackageSummary,
+    UpdateFavoriteGroupRequest, UpdateStatusRequest, UpdateUserRequest, UploadedWorldsProgress,
+    User, UserGroup, UserModerationState, UserProfile, UserState, UserStatus, VRCError,
+    VRChatClient, VRChatClientConfig, WorldListSource, WorldSearchParams,
 };
 use vrchat_status::{StatusPage, SystemStatus, VRChatStatusResponse};
-use websocket::VRChatWebSocket;
+use websocket::{NotificationPayload, PipelineStores, VRChatWebSocket};
 
 #[cfg(debug_assertions)]
 use specta_typescript::Typescript;
 
 // Application State
 struct AppState {
-    vrchat_client: Arc<Mutex<VRChatClient>>,
+    // Cheap to clone and shares its cookie jar, so commands call it without locking
+    vrchat_client: VRChatClient,
     account_store: AccountStore,
     websocket: Arc<Mutex<VRChatWebSocket>>,
     user_store: UserStore,
     settings_store: SettingsStore,
+    feed_store: FeedStore,
+    notification_store: NotificationStore,
+    moderation_store: ModerationStore,
+    favorites_store: FavoritesStore,
+    local_favorites_store: LocalFavoritesStore,
+    world_store: WorldStore,
+    group_audit_store: GroupAuditStore,
+    status_rule_store: StatusRuleStore,
+    status_scheduler: StatusScheduler,
+    alert_rule_store: AlertRuleStore,
+    friend_alerts: FriendAlerts,
     #[allow(dead_code)]
     image_cache: Arc<ImageCacheStore>,
 }
@@ -47,9 +87,14 @@ async fn vrchat_login(
     state: State<'_, AppState>,
 ) -> Result<LoginResult, VRCError> {
     let credentials = LoginCredentials { email, password };
-    let client = state.vrchat_client.lock().await;
+    let result = state.vrchat_client.login(&credentials).await?;
+
+    if let LoginResult::Success { user } = &result {
+        state.user_store.set_current_user(user.clone()).await;
+        seed_status_history(&state, user).await;
+    }
 
-    client.login(&credentials).await
+    Ok(result)
 }
 
 #[tauri::command]
@@ -62,9 +107,10 @@ async fn vrchat_verify_2fa(
     let two_fa_method = TwoFactorMethod::from_str(&method)
         .ok_or_else(|| VRCError::invalid_input(format!("Invalid 2FA method: {}", method)))?;
 
-    let client = state.vrchat_client.lock().await;
-
-    client.verify_two_factor(&code, two_fa_method).await
+    state
+        .vrchat_client
+        .verify_two_factor(&code, two_fa_method)
+        .await
 }
 
 #[tauri::command]
@@ -74,10 +120,10 @@ async fn vrchat_get_current_user(state: State<'_, AppState>) -> Result<User, VRC
         return Ok(user);
     }
 
-    let client = state.vrchat_client.lock().await;
-    let user = client.get_current_user().await?;
+    let user = state.vrchat_client.get_current_user().await?;
 
     state.user_store.set_current_user(user.clone()).await;
+    seed_status_history(&state, &user).await;
 
     Ok(user)
 }
@@ -94,11 +140,35 @@ async fn vrchat_update_status(
         status_description,
     };
 
-    let client = state.vrchat_client.lock().await;
-    let user = client.update_status(&request).await?;
-    drop(client);
+    let current_user = current_user(&state).await?;
+    let user = state
+        .vrchat_client
+        .update_status(&current_user, &request)
+        .await?;
 
-    state.user_store.set_current_user(user.clone()).await;
+    state.user_store.patch_current_user_profile(&user).await;
+    record_status_history(&state, &user, None).await;
+
+    Ok(user)
+}
+
+/// Edit bio, bio links, pronouns, languages, status or icon of the logged-in user
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_update_current_user(
+    request: UpdateUserRequest,
+    state: State<'_, AppState>,
+) -> Result<User, VRCError> {
+    let current_user = current_user(&state).await?;
+    let user = state
+        .vrchat_client
+        .update_current_user(&current_user, &request)
+        .await?;
+
+    state.user_store.patch_current_user_profile(&user).await;
+    if request.status.is_some() || request.status_description.is_some() {
+        record_status_history(&state, &user, None).await;
+    }
 
     Ok(user)
 }
@@ -111,17 +181,45 @@ async fn vrchat_logout(state: State<'_, AppState>) -> Result<(), VRCError> {
     drop(websocket);
 
     state.user_store.clear_all().await;
+    state.moderation_store.clear().await;
+    state.favorites_store.clear().await;
+
+    state.vrchat_client.logout().await
+}
+
+/// Sign in again after the session expired, keeping the cached UserStore state
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_reauthenticate(
+    email: String,
+    password: String,
+    state: State<'_, AppState>,
+) -> Result<LoginResult, VRCError> {
+    let credentials = LoginCredentials { email, password };
+    let result = state.vrchat_client.login(&credentials).await?;
+
+    if let LoginResult::Success { user } = &result {
+        resume_session(&state, user.clone()).await?;
+    }
+
+    Ok(result)
+}
+
+/// Finish re-authentication once 2FA has been verified
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_resume_session(state: State<'_, AppState>) -> Result<User, VRCError> {
+    let user = state.vrchat_client.get_current_user().await?;
 
-    let client = state.vrchat_client.lock().await;
-    client.logout().await
+    resume_session(&state, user.clone()).await?;
+
+    Ok(user)
 }
 
 #[tauri::command]
 #[specta::specta]
 async fn websocket_start(state: State<'_, AppState>) -> Result<(), VRCError> {
-    let client = state.vrchat_client.lock().await;
-    let (auth_cookie, two_factor_cookie) = client.export_cookies().await;
-    drop(client);
+    let (auth_cookie, two_factor_cookie) = state.vrchat_client.export_cookies().await;
 
     log::debug!(
         "WebSocket starting with cookies - auth: {:?}, 2fa: {:?}",
@@ -157,8 +255,7 @@ async fn vrchat_get_online_friends(
         return Ok(cached_friends);
     }
 
-    let client = state.vrchat_client.lock().await;
-    let friends = client.get_all_friends().await?;
+    let friends = state.vrchat_client.get_all_friends().await?;
 
     state.user_store.set_friends(friends.clone()).await;
     Ok(friends)
@@ -167,10 +264,91 @@ async fn vrchat_get_online_friends(
 #[tauri::command]
 #[specta::specta]
 async fn vrchat_get_uploaded_worlds(
+    app: AppHandle,
+    state: State<'_, AppState>,
+) -> Result<Vec<LimitedWorld>, VRCError> {
+    let mut worlds = state.vrchat_client.list_uploaded_worlds().await?;
+
+    let missing: Vec<String> = worlds
+        .iter()
+        .filter(|world| world.is_missing_stats())
+        .map(|world| world.id.clone())
+        .collect();
+    let cached = state
+        .world_store
+        .get_many(&missing)
+        .await
+        .unwrap_or_else(|e| {
+            log::warn!("Failed to read world cache: {}", e);
+            Vec::new()
+        });
+
+    let mut known = HashSet::new();
+    for details in cached {
+        if let Some(world) = worlds.iter_mut().find(|world| world.id == details.id) {
+            world.merge_stats(&details);
+        }
+        known.insert(details.id);
+    }
+
+    let fetched = state
+        .vrchat_client
+        .backfill_world_stats(&mut worlds, &known, |progress| {
+            let _ = app.emit("uploaded-worlds-progress", progress);
+        })
+        .await;
+    if let Err(e) = state.world_store.put_many(&fetched).await {
+        log::warn!("Failed to cache uploaded worlds: {}", e);
+    }
+
+    Ok(worlds)
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_search_worlds(
+    params: WorldSearchParams,
     state: State<'_, AppState>,
 ) -> Result<Vec<LimitedWorld>, VRCError> {
-    let client = state.vrchat_client.lock().await;
-    client.get_uploaded_worlds().await
+    state.vrchat_client.search_worlds(&params).await
+}
+
+/// World details, served from the world cache while fresh
+#[tauri::command]
+#[specta::specta]
+async fn get_world(
+    world_id: String,
+    force_refresh: bool,
+    state: State<'_, AppState>,
+) -> Result<LimitedWorld, VRCError> {
+    if !force_refresh
+        && let Some(world) = state
+            .world_store
+            .get(&world_id)
+            .await
+            .map_err(VRCError::unknown)?
+    {
+        return Ok(world);
+    }
+
+    match state.vrchat_client.get_world_details(&world_id).await {
+        Ok(world) => {
+            if let Err(e) = state.world_store.put(&world).await {
+                log::warn!("Failed to cache world {}: {}", world_id, e);
+            }
+            Ok(world)
+        }
+        Err(err @ (VRCError::Network(_) | VRCError::RateLimit(_))) => {
+            match state.world_store.get_stale(&world_id).await {
+                Ok(Some(world)) => {
+                    log::warn!("Serving stale world {} after error: {}", world_id, err);
+                    Ok(world)
+                }
+                _ => Err(err),
+            }
+        }
+        Err(err) => Err(err),
+    }
 }
 
 #[tauri::command]
@@ -178,8 +356,192 @@ async fn vrchat_get_uploaded_worlds(
 async fn vrchat_get_uploaded_avatars(
     state: State<'_, AppState>,
 ) -> Result<Vec<LimitedAvatar>, VRCError> {
-    let client = state.vrchat_client.lock().await;
-    client.get_uploaded_avatars().await
+    state.vrchat_client.get_uploaded_avatars().await
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_get_avatar(
+    avatar_id: String,
+    state: State<'_, AppState>,
+) -> Result<LimitedAvatar, VRCError> {
+    state.vrchat_client.get_avatar(&avatar_id).await
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_get_favorited_avatars(
+    group: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<Vec<LimitedAvatar>, VRCError> {
+    state
+        .vrchat_client
+        .get_favorited_avatars(group.as_deref())
+        .await
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_select_avatar(
+    avatar_id: String,
+    state: State<'_, AppState>,
+) -> Result<User, VRCError> {
+    let user = state.vrchat_client.select_avatar(&avatar_id).await?;
+
+    // The pipeline confirms the switch later; reflect it right away
+    state
+        .user_store
+        .apply_current_user_update(CurrentUserPipelineUpdate::from(&user))
+        .await;
+
+    Ok(user)
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_select_fallback_avatar(
+    avatar_id: String,
+    state: State<'_, AppState>,
+) -> Result<User, VRCError> {
+    let user = state
+        .vrchat_client
+        .select_fallback_avatar(&avatar_id)
+        .await?;
+
+    state
+        .user_store
+        .apply_current_user_update(CurrentUserPipelineUpdate::from(&user))
+        .await;
+
+    Ok(user)
+}
+
+/// Summarise uploaded avatars per platform against `min_unity_version` (default `2022.3`)
+#[tauri::command]
+#[specta::specta]
+async fn get_avatar_performance_report(
+    min_unity_version: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<AvatarPerformanceReport, VRCError> {
+    let avatars = state.vrchat_client.get_uploaded_avatars().await?;
+    let min_unity_version = min_unity_version
+        .as_deref()
+        .unwrap_or(DEFAULT_MIN_UNITY_VERSION);
+
+    Ok(AvatarPerformanceReport::build(&avatars, min_unity_version))
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn export_avatar_performance_report(
+    min_unity_version: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<String, VRCError> {
+    let report = get_avatar_performance_report(min_unity_version, state).await?;
+    report.to_csv().map_err(VRCError::unknown)
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_get_instance(
+    location: String,
+    state: State<'_, AppState>,
+) -> Result<Instance, VRCError> {
+    state.vrchat_client.get_instance(&location).await
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_get_instance_short_name(
+    location: String,
+    state: State<'_, AppState>,
+) -> Result<InstanceShortName, VRCError> {
+    state.vrchat_client.get_instance_short_name(&location).await
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_create_instance(
+    request: CreateInstanceRequest,
+    state: State<'_, AppState>,
+) -> Result<Instance, VRCError> {
+    state.vrchat_client.create_instance(&request).await
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_invite_user(
+    user_id: String,
+    location: String,
+    message_slot: Option<u32>,
+    state: State<'_, AppState>,
+) -> Result<SentNotification, VRCError> {
+    state
+        .vrchat_client
+        .invite_user(&user_id, &location, message_slot)
+        .await
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_request_invite(
+    user_id: String,
+    message_slot: Option<u32>,
+    state: State<'_, AppState>,
+) -> Result<SentNotification, VRCError> {
+    state
+        .vrchat_client
+        .request_invite(&user_id, message_slot)
+        .await
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_invite_myself(
+    location: String,
+    state: State<'_, AppState>,
+) -> Result<SentNotification, VRCError> {
+    state.vrchat_client.invite_myself(&location).await
+}
+
+/// Reply to a `requestInvite` notification. With a `location` the sender is invited
+/// there (optionally with an invite message), otherwise the request is declined
+/// with the response message in `message_slot`.
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_respond_invite_request(
+    notification: NotificationPayload,
+    location: Option<String>,
+    message_slot: Option<u32>,
+    state: State<'_, AppState>,
+) -> Result<SentNotification, VRCError> {
+    let (Some(id), Some(sender_user_id)) = (
+        notification.id.as_deref(),
+        notification.sender_user_id.as_deref(),
+    ) else {
+        return Err(VRCError::invalid_input(
+            "Notification is missing its id or sender",
+        ));
+    };
+    if !notification.is_invite_request() {
+        return Err(VRCError::invalid_input(format!(
+            "Notification {} is not an invite request",
+            id
+        )));
+    }
+
+    match (location, message_slot) {
+        (Some(location), slot) => {
+            state
+                .vrchat_client
+                .invite_user(sender_user_id, &location, slot)
+                .await
+        }
+        (None, Some(slot)) => state.vrchat_client.respond_to_invite(id, slot).await,
+        (None, None) => Err(VRCError::invalid_input(
+            "Either a location or a response message slot is required",
+        )),
+    }
 }
 
 #[tauri::command]
@@ -217,9 +579,7 @@ async fn cache_image(url: String, state: State<'_, AppState>) -> Result<String,
 
     info!("Caching image: {}", url);
 
-    let client = state.vrchat_client.lock().await;
-    let (auth_cookie, two_factor_cookie) = client.export_cookies().await;
-    drop(client);
+    let (auth_cookie, two_factor_cookie) = state.vrchat_client.export_cookies().await;
 
     let cookies = match (auth_cookie, two_factor_cookie) {
         (Some(auth), Some(two_fa)) => Some(format!("{}; {}", auth, two_fa)),
@@ -233,265 +593,1546 @@ async fn cache_image(url: String, state: State<'_, AppState>) -> Result<String,
         .get_or_fetch(&url, cookies)
         .await
         .map_err(VRCError::unknown)?;
-    info!("Image cached to: {}", path.display());
+    info!("Image cached to: {}", path.display());
+
+    Ok(path.to_string_lossy().to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn get_cache_directory(state: State<'_, AppState>) -> Result<String, VRCError> {
+    let cache = state.image_cache.clone();
+    let dir = cache.get_cache_dir();
+    Ok(dir.to_string_lossy().to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn get_all_friends(state: State<'_, AppState>) -> Result<Vec<LimitedUserFriend>, VRCError> {
+    Ok(state.user_store.get_all_friends().await)
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn get_user(
+    user_id: String,
+    state: State<'_, AppState>,
+) -> Result<Option<LimitedUserFriend>, VRCError> {
+    Ok(state.user_store.get_user(&user_id).await)
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn get_user_by_id(user_id: String, state: State<'_, AppState>) -> Result<User, VRCError> {
+    let mut user = match state.user_store.get_full_user(&user_id).await {
+        Some(cached_user) => {
+            log::debug!("get_user_by_id: Returning cached user for {}", user_id);
+            cached_user
+        }
+        None => {
+            log::info!("get_user_by_id: Fetching user {} from API", user_id);
+
+            let user = state.vrchat_client.get_user_by_id(&user_id).await?;
+
+            log::info!("get_user_by_id: Successfully fetched user {}", user_id);
+
+            state.user_store.cache_full_user(user.clone()).await;
+            user
+        }
+    };
+
+    user.moderation = moderation_state(&state, &user_id).await;
+
+    Ok(user)
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_search_users(
+    query: String,
+    n: u32,
+    offset: u32,
+    state: State<'_, AppState>,
+) -> Result<Vec<LimitedUser>, VRCError> {
+    state.vrchat_client.search_users(&query, n, offset).await
+}
+
+/// Profile page data in one call; also refreshes the cached user
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_get_user_profile(
+    user_id: String,
+    state: State<'_, AppState>,
+) -> Result<UserProfile, VRCError> {
+    let mut profile = state.vrchat_client.get_user_profile(&user_id).await?;
+    state.user_store.cache_full_user(profile.user.clone()).await;
+    profile.user.moderation = moderation_state(&state, &user_id).await;
+    Ok(profile)
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn is_friend(user_id: String, state: State<'_, AppState>) -> Result<bool, VRCError> {
+    Ok(state.user_store.is_friend(&user_id).await)
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn is_user_online(user_id: String, state: State<'_, AppState>) -> Result<bool, VRCError> {
+    Ok(state.user_store.is_user_online(&user_id).await)
+}
+
+/// Fetch the moderation list once per session so profile lookups can be answered from cache
+async fn ensure_moderations_loaded(state: &AppState) -> Result<(), VRCError> {
+    if !state.moderation_store.is_loaded().await {
+        let moderations = state.vrchat_client.get_player_moderations().await?;
+        state.moderation_store.set_all(moderations).await;
+    }
+    Ok(())
+}
+
+/// Moderation annotation for user responses; left out when the list can't be fetched
+async fn moderation_state(state: &AppState, user_id: &str) -> Option<UserModerationState> {
+    if let Err(e) = ensure_moderations_loaded(state).await {
+        log::warn!("Failed to load player moderations: {}", e);
+        return None;
+    }
+
+    Some(state.moderation_store.get_for_user(user_id).await)
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_get_player_moderations(
+    kind: Option<PlayerModerationType>,
+    state: State<'_, AppState>,
+) -> Result<Vec<PlayerModeration>, VRCError> {
+    let moderations = state.vrchat_client.get_player_moderations().await?;
+    state.moderation_store.set_all(moderations).await;
+
+    Ok(state.moderation_store.list(kind).await)
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_moderate_user(
+    user_id: String,
+    kind: PlayerModerationType,
+    state: State<'_, AppState>,
+) -> Result<PlayerModeration, VRCError> {
+    let moderation = state.vrchat_client.moderate_user(&user_id, kind).await?;
+
+    state.moderation_store.upsert(moderation.clone()).await;
+
+    Ok(moderation)
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_unmoderate_user(
+    user_id: String,
+    kind: PlayerModerationType,
+    state: State<'_, AppState>,
+) -> Result<(), VRCError> {
+    state.vrchat_client.unmoderate_user(&user_id, kind).await?;
+
+    state.moderation_store.remove(&user_id, kind).await;
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn export_block_list(state: State<'_, AppState>) -> Result<String, VRCError> {
+    ensure_moderations_loaded(&state).await?;
+
+    state
+        .moderation_store
+        .export_block_list()
+        .await
+        .map_err(VRCError::unknown)
+}
+
+// Group Commands
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_get_my_groups(state: State<'_, AppState>) -> Result<Vec<UserGroup>, VRCError> {
+    let user_id = current_account_id(&state).await?;
+    state.vrchat_client.get_user_groups(&user_id).await
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_get_user_groups(
+    user_id: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<UserGroup>, VRCError> {
+    state.vrchat_client.get_user_groups(&user_id).await
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_get_group(group_id: String, state: State<'_, AppState>) -> Result<Group, VRCError> {
+    state.vrchat_client.get_group(&group_id).await
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_get_group_members(
+    group_id: String,
+    n: u32,
+    offset: u32,
+    state: State<'_, AppState>,
+) -> Result<Vec<GroupMember>, VRCError> {
+    state
+        .vrchat_client
+        .get_group_members(&group_id, n, offset)
+        .await
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_get_group_roles(
+    group_id: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<GroupRole>, VRCError> {
+    state.vrchat_client.get_group_roles(&group_id).await
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_get_group_announcement(
+    group_id: String,
+    state: State<'_, AppState>,
+) -> Result<Option<GroupAnnouncement>, VRCError> {
+    state.vrchat_client.get_group_announcement(&group_id).await
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_get_group_posts(
+    group_id: String,
+    n: u32,
+    offset: u32,
+    state: State<'_, AppState>,
+) -> Result<Vec<GroupPost>, VRCError> {
+    state
+        .vrchat_client
+        .get_group_posts(&group_id, n, offset)
+        .await
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_get_group_instances(
+    group_id: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<GroupInstance>, VRCError> {
+    state.vrchat_client.get_group_instances(&group_id).await
+}
+
+// Group Moderation Commands
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_ban_group_member(
+    group_id: String,
+    user_id: String,
+    state: State<'_, AppState>,
+) -> Result<GroupMember, VRCError> {
+    state
+        .vrchat_client
+        .ban_group_member(&group_id, &user_id)
+        .await
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_unban_group_member(
+    group_id: String,
+    user_id: String,
+    state: State<'_, AppState>,
+) -> Result<(), VRCError> {
+    state
+        .vrchat_client
+        .unban_group_member(&group_id, &user_id)
+        .await
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_get_group_bans(
+    group_id: String,
+    n: u32,
+    offset: u32,
+    state: State<'_, AppState>,
+) -> Result<Vec<GroupMember>, VRCError> {
+    state
+        .vrchat_client
+        .get_group_bans(&group_id, n, offset)
+        .await
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_kick_group_member(
+    group_id: String,
+    user_id: String,
+    state: State<'_, AppState>,
+) -> Result<(), VRCError> {
+    state
+        .vrchat_client
+        .kick_group_member(&group_id, &user_id)
+        .await
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_get_group_join_requests(
+    group_id: String,
+    n: u32,
+    offset: u32,
+    state: State<'_, AppState>,
+) -> Result<Vec<GroupMember>, VRCError> {
+    state
+        .vrchat_client
+        .get_group_join_requests(&group_id, n, offset)
+        .await
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_respond_group_join_request(
+    group_id: String,
+    user_id: String,
+    action: GroupJoinRequestAction,
+    block: bool,
+    state: State<'_, AppState>,
+) -> Result<(), VRCError> {
+    state
+        .vrchat_client
+        .respond_group_join_request(&group_id, &user_id, action, block)
+        .await
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_add_group_member_role(
+    group_id: String,
+    user_id: String,
+    role_id: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<String>, VRCError> {
+    state
+        .vrchat_client
+        .add_group_member_role(&group_id, &user_id, &role_id)
+        .await
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_remove_group_member_role(
+    group_id: String,
+    user_id: String,
+    role_id: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<String>, VRCError> {
+    state
+        .vrchat_client
+        .remove_group_member_role(&group_id, &user_id, &role_id)
+        .await
+}
+
+/// Pull audit log entries newer than the local mirror; returns how many were added
+#[tauri::command]
+#[specta::specta]
+async fn sync_group_audit_log(
+    group_id: String,
+    state: State<'_, AppState>,
+) -> Result<u32, VRCError> {
+    let since = state
+        .group_audit_store
+        .latest_timestamp(&group_id)
+        .await
+        .map_err(VRCError::unknown)?;
+
+    // Store nothing until every page arrived; a partial sync would move `since`
+    // past the entries that are still missing
+    let entries = state
+        .vrchat_client
+        .get_all_group_audit_logs(&group_id, since.as_deref())
+        .await?;
+    let added = state
+        .group_audit_store
+        .insert(entries)
+        .await
+        .map_err(VRCError::unknown)?;
+
+    Ok(added as u32)
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn search_group_audit_log(
+    query: GroupAuditQuery,
+    state: State<'_, AppState>,
+) -> Result<GroupAuditPage, VRCError> {
+    state
+        .group_audit_store
+        .query(query)
+        .await
+        .map_err(VRCError::unknown)
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn clear_group_audit_log(
+    group_id: String,
+    state: State<'_, AppState>,
+) -> Result<(), VRCError> {
+    state
+        .group_audit_store
+        .clear(&group_id)
+        .await
+        .map_err(VRCError::unknown)
+}
+
+async fn load_favorites(state: &AppState) -> Result<(), VRCError> {
+    let favorites = state.vrchat_client.get_favorites(None).await?;
+    let groups = state.vrchat_client.get_favorite_groups().await?;
+    state.favorites_store.set_all(favorites, groups).await;
+    Ok(())
+}
+
+async fn ensure_favorites_loaded(state: &AppState) -> Result<(), VRCError> {
+    if !state.favorites_store.is_loaded().await {
+        load_favorites(state).await?;
+    }
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_get_favorites(
+    kind: Option<FavoriteType>,
+    state: State<'_, AppState>,
+) -> Result<Vec<Favorite>, VRCError> {
+    load_favorites(&state).await?;
+
+    Ok(state.favorites_store.favorites(kind).await)
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_get_favorite_groups(
+    kind: Option<FavoriteType>,
+    state: State<'_, AppState>,
+) -> Result<Vec<FavoriteGroup>, VRCError> {
+    ensure_favorites_loaded(&state).await?;
+
+    Ok(state.favorites_store.groups(kind).await)
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_get_favorite_limits(
+    state: State<'_, AppState>,
+) -> Result<FavoriteLimits, VRCError> {
+    state.vrchat_client.get_favorite_limits().await
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_add_favorite(
+    request: AddFavoriteRequest,
+    state: State<'_, AppState>,
+) -> Result<Favorite, VRCError> {
+    if request.tags.is_empty() {
+        return Err(VRCError::invalid_input("A favorite group is required"));
+    }
+
+    let favorite = state.vrchat_client.add_favorite(&request).await?;
+
+    state
+        .favorites_store
+        .upsert_favorite(favorite.clone())
+        .await;
+
+    Ok(favorite)
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_remove_favorite(
+    favorite_id: String,
+    state: State<'_, AppState>,
+) -> Result<(), VRCError> {
+    state.vrchat_client.remove_favorite(&favorite_id).await?;
+
+    state.favorites_store.remove_favorite(&favorite_id).await;
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_update_favorite_group(
+    kind: FavoriteType,
+    name: String,
+    request: UpdateFavoriteGroupRequest,
+    state: State<'_, AppState>,
+) -> Result<(), VRCError> {
+    if request
+        .display_name
+        .as_deref()
+        .is_some_and(|n| n.trim().is_empty())
+    {
+        return Err(VRCError::invalid_input(
+            "Favorite group name cannot be empty",
+        ));
+    }
+
+    let user_id = current_account_id(&state).await?;
+    state
+        .vrchat_client
+        .update_favorite_group(kind, &name, &user_id, &request)
+        .await?;
+
+    state
+        .favorites_store
+        .update_group(kind, &name, &request)
+        .await;
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_clear_favorite_group(
+    kind: FavoriteType,
+    name: String,
+    state: State<'_, AppState>,
+) -> Result<(), VRCError> {
+    let user_id = current_account_id(&state).await?;
+    state
+        .vrchat_client
+        .clear_favorite_group(kind, &name, &user_id)
+        .await?;
+
+    state.favorites_store.clear_group(kind, &name).await;
+
+    Ok(())
+}
+
+/// Favorite groups of the given users, worlds or avatars; entries that are not favorited are left out
+#[tauri::command]
+#[specta::specta]
+async fn get_favorite_annotations(
+    kind: FavoriteType,
+    target_ids: Vec<String>,
+    state: State<'_, AppState>,
+) -> Result<Vec<FavoriteAnnotation>, VRCError> {
+    ensure_favorites_loaded(&state).await?;
+
+    Ok(state.favorites_store.annotate_ids(kind, &target_ids).await)
+}
+
+// Local Favorites Commands
+#[tauri::command]
+#[specta::specta]
+async fn get_local_collections(
+    kind: Option<LocalFavoriteKind>,
+    state: State<'_, AppState>,
+) -> Result<Vec<LocalCollection>, VRCError> {
+    state
+        .local_favorites_store
+        .list_collections(kind)
+        .await
+        .map_err(VRCError::unknown)
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn create_local_collection(
+    name: String,
+    kind: LocalFavoriteKind,
+    description: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<LocalCollection, VRCError> {
+    state
+        .local_favorites_store
+        .create_collection(&name, kind, description)
+        .await
+        .map_err(VRCError::invalid_input)
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn update_local_collection(
+    collection_id: i32,
+    name: String,
+    description: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<LocalCollection, VRCError> {
+    state
+        .local_favorites_store
+        .update_collection(collection_id, &name, description)
+        .await
+        .map_err(VRCError::invalid_input)
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn delete_local_collection(
+    collection_id: i32,
+    state: State<'_, AppState>,
+) -> Result<(), VRCError> {
+    state
+        .local_favorites_store
+        .delete_collection(collection_id)
+        .await
+        .map_err(VRCError::unknown)
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn get_local_favorites(
+    collection_id: i32,
+    tag: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<Vec<LocalFavoriteEntry>, VRCError> {
+    state
+        .local_favorites_store
+        .list_entries(collection_id, tag.as_deref())
+        .await
+        .map_err(VRCError::unknown)
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn add_local_favorite(
+    collection_id: i32,
+    input: LocalFavoriteInput,
+    state: State<'_, AppState>,
+) -> Result<LocalFavoriteEntry, VRCError> {
+    state
+        .local_favorites_store
+        .add_entry(collection_id, input)
+        .await
+        .map_err(VRCError::invalid_input)
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn update_local_favorite(
+    entry_id: i32,
+    notes: Option<String>,
+    tags: Vec<String>,
+    state: State<'_, AppState>,
+) -> Result<LocalFavoriteEntry, VRCError> {
+    state
+        .local_favorites_store
+        .update_entry(entry_id, notes, tags)
+        .await
+        .map_err(VRCError::unknown)
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn remove_local_favorite(entry_id: i32, state: State<'_, AppState>) -> Result<(), VRCError> {
+    state
+        .local_favorites_store
+        .remove_entry(entry_id)
+        .await
+        .map_err(VRCError::unknown)
+}
+
+/// Export collections as JSON or CSV; an empty `collection_ids` exports everything
+#[tauri::command]
+#[specta::specta]
+async fn export_local_favorites(
+    format: LocalFavoritesFormat,
+    collection_ids: Vec<i32>,
+    state: State<'_, AppState>,
+) -> Result<String, VRCError> {
+    state
+        .local_favorites_store
+        .export(format, &collection_ids)
+        .await
+        .map_err(VRCError::unknown)
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn import_local_favorites(
+    format: LocalFavoritesFormat,
+    data: String,
+    state: State<'_, AppState>,
+) -> Result<LocalFavoritesImport, VRCError> {
+    state
+        .local_favorites_store
+        .import(format, &data)
+        .await
+        .map_err(VRCError::invalid_input)
+}
+
+/// Add a local entry to one of the user's VRChat favorite groups
+#[tauri::command]
+#[specta::specta]
+async fn promote_local_favorite(
+    entry_id: i32,
+    group_name: String,
+    state: State<'_, AppState>,
+) -> Result<Favorite, VRCError> {
+    let entry = state
+        .local_favorites_store
+        .get_entry(entry_id)
+        .await
+        .map_err(VRCError::unknown)?
+        .ok_or_else(|| VRCError::invalid_input(format!("Local favorite {} not found", entry_id)))?;
+    let collection = state
+        .local_favorites_store
+        .get_collection(entry.collection_id)
+        .await
+        .map_err(VRCError::unknown)?
+        .ok_or_else(|| {
+            VRCError::invalid_input(format!(
+                "Local collection {} not found",
+                entry.collection_id
+            ))
+        })?;
+
+    let favorite = state
+        .vrchat_client
+        .add_favorite(&AddFavoriteRequest {
+            kind: collection.kind.favorite_type(),
+            favorite_id: entry.target_id,
+            tags: vec![group_name],
+        })
+        .await?;
+
+    state
+        .favorites_store
+        .upsert_favorite(favorite.clone())
+        .await;
+    state
+        .local_favorites_store
+        .set_vrchat_favorite(entry_id, &favorite.id)
+        .await
+        .map_err(VRCError::unknown)?;
+
+    Ok(favorite)
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_send_friend_request(
+    user_id: String,
+    state: State<'_, AppState>,
+) -> Result<SentNotification, VRCError> {
+    let notification = state.vrchat_client.send_friend_request(&user_id).await?;
+
+    state
+        .user_store
+        .set_friend_request_status(&user_id, FriendRequestStatus::Outgoing)
+        .await;
+
+    Ok(notification)
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_cancel_friend_request(
+    user_id: String,
+    state: State<'_, AppState>,
+) -> Result<(), VRCError> {
+    state.vrchat_client.cancel_friend_request(&user_id).await?;
+
+    state
+        .user_store
+        .set_friend_request_status(&user_id, FriendRequestStatus::None)
+        .await;
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_get_friend_status(
+    user_id: String,
+    state: State<'_, AppState>,
+) -> Result<FriendStatus, VRCError> {
+    let status = state.vrchat_client.get_friend_status(&user_id).await?;
+
+    state
+        .user_store
+        .apply_friend_status(&user_id, &status)
+        .await;
+
+    Ok(status)
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_unfriend(user_id: String, state: State<'_, AppState>) -> Result<(), VRCError> {
+    state.vrchat_client.unfriend(&user_id).await?;
+
+    state.user_store.remove_friend(&user_id).await;
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_check_session(state: State<'_, AppState>) -> Result<bool, VRCError> {
+    Ok(state.vrchat_client.has_valid_session().await)
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn vrchat_clear_session(state: State<'_, AppState>) -> Result<(), VRCError> {
+    state.vrchat_client.clear_cookies().await;
+
+    state.user_store.clear_all().await;
+    state.moderation_store.clear().await;
+    state.favorites_store.clear().await;
+
+    state
+        .account_store
+        .clear_last_active_account()
+        .await
+        .map_err(|e| VRCError::unknown(e))?;
+
+    Ok(())
+}
+
+// Account Management Commands
+#[tauri::command]
+#[specta::specta]
+async fn save_current_account(user: User, state: State<'_, AppState>) -> Result<(), VRCError> {
+    let (auth_cookie, two_factor_cookie) = state.vrchat_client.export_cookies().await;
+
+    state
+        .account_store
+        .save_account(stored_account_from_user(
+            &user,
+            auth_cookie,
+            two_factor_cookie,
+        ))
+        .await
+        .map_err(|e| VRCError::unknown(e))
+}
+
+fn stored_account_from_user(
+    user: &User,
+    auth_cookie: Option<String>,
+    two_factor_cookie: Option<String>,
+) -> StoredAccount {
+    let avatar_override = user
+        .user_icon
+        .clone()
+        .or_else(|| user.profile_pic_override.clone())
+        .or_else(|| user.profile_pic_override_thumbnail.clone());
+
+    let avatar_fallback = user
+        .current_avatar_thumbnail_image_url
+        .clone()
+        .or_else(|| user.current_avatar_image_url.clone());
+
+    StoredAccount {
+        user_id: user.id.clone(),
+        username: user.username.clone(),
+        display_name: user.display_name.clone(),
+        avatar_url: avatar_override.clone().or_else(|| avatar_fallback.clone()),
+        avatar_fallback_url: avatar_fallback,
+        auth_cookie,
+        two_factor_cookie,
+        last_login: chrono::Utc::now().to_rfc3339(),
+        needs_relogin: false,
+    }
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn get_all_accounts(state: State<'_, AppState>) -> Result<Vec<StoredAccount>, VRCError> {
+    state
+        .account_store
+        .get_all_accounts()
+        .await
+        .map_err(|e| VRCError::unknown(e))
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn switch_account(user_id: String, state: State<'_, AppState>) -> Result<User, VRCError> {
+    state.user_store.clear_all().await;
+    state.moderation_store.clear().await;
+    state.favorites_store.clear().await;
+
+    let account = state
+        .account_store
+        .get_account(&user_id)
+        .await
+        .map_err(|e| VRCError::unknown(e))?
+        .ok_or_else(|| VRCError::invalid_input("Account not found"))?;
+
+    state
+        .account_store
+        .set_active_account(&user_id)
+        .await
+        .map_err(|e| VRCError::unknown(e))?;
+
+    state
+        .vrchat_client
+        .import_cookies(account.auth_cookie, account.two_factor_cookie)
+        .await;
+
+    match state.vrchat_client.get_current_user().await {
+        Ok(user) => {
+            state.user_store.set_current_user(user.clone()).await;
+            Ok(user)
+        }
+        Err(err) => {
+            // TODO: Handle if switching account fails
+            Err(err)
+        }
+    }
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn remove_account(user_id: String, state: State<'_, AppState>) -> Result<(), VRCError> {
+    state
+        .account_store
+        .remove_account(&user_id)
+        .await
+        .map_err(|e| VRCError::unknown(e))
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn clear_all_accounts(state: State<'_, AppState>) -> Result<(), VRCError> {
+    state
+        .account_store
+        .clear_all_accounts()
+        .await
+        .map_err(|e| VRCError::unknown(e))
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn load_last_account(state: State<'_, AppState>) -> Result<Option<User>, VRCError> {
+    let account = match state
+        .account_store
+        .get_last_active_account()
+        .await
+        .map_err(|e| VRCError::unknown(e))?
+    {
+        Some(acc) => acc,
+        None => return Ok(None),
+    };
+
+    state
+        .vrchat_client
+        .import_cookies(account.auth_cookie, account.two_factor_cookie)
+        .await;
+
+    match state.vrchat_client.get_current_user().await {
+        Ok(user) => {
+            state.user_store.set_current_user(user.clone()).await;
+            seed_status_history(&state, &user).await;
+            Ok(Some(user))
+        }
+        // The session-expired handler flags the account, let the frontend prompt for re-login
+        Err(err @ VRCError::SessionExpired(_)) => Err(err),
+        Err(err) => {
+            log::warn!("Failed to restore last account: {}", err);
+            Ok(None)
+        }
+    }
+}
+
+// Session Expiry Handling
+
+/// Adopt a freshly authenticated session without dropping cached friends and users
+async fn resume_session(state: &AppState, user: User) -> Result<(), VRCError> {
+    let (auth_cookie, two_factor_cookie) = state.vrchat_client.export_cookies().await;
+
+    state.user_store.set_current_user(user.clone()).await;
+
+    state
+        .account_store
+        .save_account(stored_account_from_user(
+            &user,
+            auth_cookie.clone(),
+            two_factor_cookie.clone(),
+        ))
+        .await
+        .map_err(VRCError::unknown)?;
+
+    let websocket = state.websocket.lock().await;
+    websocket.set_cookies(auth_cookie, two_factor_cookie).await;
+    websocket.start().await
+}
+
+/// Stop realtime updates, flag the account and tell the frontend to re-authenticate
+async fn handle_session_expired(app: &AppHandle, notice: SessionExpiredNotice) {
+    let state = app.state::<AppState>();
+
+    let websocket = state.websocket.lock().await;
+    websocket.stop().await;
+    drop(websocket);
+
+    let user_id = match state.user_store.get_current_user_id().await {
+        Some(id) => Some(id),
+        None => state
+            .account_store
+            .get_last_active_account()
+            .await
+            .ok()
+            .flatten()
+            .map(|account| account.user_id),
+    };
+
+    if let Some(id) = &user_id
+        && let Err(e) = state.account_store.set_needs_relogin(id, true).await
+    {
+        log::error!("Failed to flag account {} for re-login: {}", id, e);
+    }
+
+    let _ = app.emit(
+        "session-expired",
+        SessionExpiredEvent {
+            user_id,
+            message: notice.message,
+        },
+    );
+}
+
+// Friend Feed Commands
+#[tauri::command]
+#[specta::specta]
+async fn get_friend_feed(
+    query: FeedQuery,
+    state: State<'_, AppState>,
+) -> Result<FeedPage, VRCError> {
+    let account_id = current_account_id(&state).await?;
+
+    state
+        .feed_store
+        .query(&account_id, query)
+        .await
+        .map_err(VRCError::unknown)
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn clear_friend_feed(state: State<'_, AppState>) -> Result<(), VRCError> {
+    let account_id = current_account_id(&state).await?;
+
+    state
+        .feed_store
+        .clear(&account_id)
+        .await
+        .map_err(VRCError::unknown)
+}
+
+// Notification Inbox Commands
+#[tauri::command]
+#[specta::specta]
+async fn get_notification_inbox(
+    include_hidden: bool,
+    state: State<'_, AppState>,
+) -> Result<Vec<InboxNotification>, VRCError> {
+    let account_id = current_account_id(&state).await?;
+
+    state
+        .notification_store
+        .list(&account_id, include_hidden)
+        .await
+        .map_err(VRCError::unknown)
+}
+
+/// Fetch both notification lists from VRChat and reconcile the local inbox
+#[tauri::command]
+#[specta::specta]
+async fn refresh_notifications(
+    state: State<'_, AppState>,
+) -> Result<Vec<InboxNotification>, VRCError> {
+    let account_id = current_account_id(&state).await?;
+
+    let v1 = state.vrchat_client.get_notifications(false).await?;
+    let v2 = state.vrchat_client.get_notifications_v2().await?;
+
+    let store = &state.notification_store;
+    store
+        .sync(
+            &account_id,
+            1,
+            v1.iter()
+                .filter_map(|n| InboxNotification::from_v1(&account_id, n))
+                .collect(),
+        )
+        .await
+        .map_err(VRCError::unknown)?;
+    store
+        .sync(
+            &account_id,
+            2,
+            v2.iter()
+                .map(|n| InboxNotification::from_v2(&account_id, n))
+                .collect(),
+        )
+        .await
+        .map_err(VRCError::unknown)?;
 
-    Ok(path.to_string_lossy().to_string())
+    store
+        .list(&account_id, false)
+        .await
+        .map_err(VRCError::unknown)
 }
 
 #[tauri::command]
 #[specta::specta]
-async fn get_cache_directory(state: State<'_, AppState>) -> Result<String, VRCError> {
-    let cache = state.image_cache.clone();
-    let dir = cache.get_cache_dir();
-    Ok(dir.to_string_lossy().to_string())
-}
+async fn see_notification(
+    notification_id: String,
+    state: State<'_, AppState>,
+) -> Result<(), VRCError> {
+    let account_id = current_account_id(&state).await?;
+
+    if notification_version(&state, &account_id, &notification_id).await? == 2 {
+        state
+            .vrchat_client
+            .see_notification_v2(&notification_id)
+            .await?;
+    } else {
+        state
+            .vrchat_client
+            .see_notification(&notification_id)
+            .await?;
+    }
 
-#[tauri::command]
-#[specta::specta]
-async fn get_all_friends(state: State<'_, AppState>) -> Result<Vec<LimitedUserFriend>, VRCError> {
-    Ok(state.user_store.get_all_friends().await)
+    state
+        .notification_store
+        .mark_seen(&account_id, &notification_id)
+        .await
+        .map_err(VRCError::unknown)
 }
 
+/// Hide a notification. v2 notifications cannot be hidden on VRChat's side, so
+/// they are deleted there and only kept (hidden) locally.
 #[tauri::command]
 #[specta::specta]
-async fn get_user(
-    user_id: String,
+async fn hide_notification(
+    notification_id: String,
     state: State<'_, AppState>,
-) -> Result<Option<LimitedUserFriend>, VRCError> {
-    Ok(state.user_store.get_user(&user_id).await)
+) -> Result<(), VRCError> {
+    let account_id = current_account_id(&state).await?;
+
+    if notification_version(&state, &account_id, &notification_id).await? == 2 {
+        state
+            .vrchat_client
+            .delete_notification_v2(&notification_id)
+            .await?;
+    } else {
+        state
+            .vrchat_client
+            .hide_notification(&notification_id)
+            .await?;
+    }
+
+    state
+        .notification_store
+        .hide(&account_id, &notification_id)
+        .await
+        .map_err(VRCError::unknown)
 }
 
 #[tauri::command]
 #[specta::specta]
-async fn get_user_by_id(user_id: String, state: State<'_, AppState>) -> Result<User, VRCError> {
-    if let Some(cached_user) = state.user_store.get_full_user(&user_id).await {
-        log::debug!("get_user_by_id: Returning cached user for {}", user_id);
-        return Ok(cached_user);
+async fn delete_notification(
+    notification_id: String,
+    state: State<'_, AppState>,
+) -> Result<(), VRCError> {
+    let account_id = current_account_id(&state).await?;
+
+    if notification_version(&state, &account_id, &notification_id).await? == 2 {
+        state
+            .vrchat_client
+            .delete_notification_v2(&notification_id)
+            .await?;
+    } else {
+        state
+            .vrchat_client
+            .hide_notification(&notification_id)
+            .await?;
     }
 
-    log::info!("get_user_by_id: Fetching user {} from API", user_id);
-
-    let client = state.vrchat_client.lock().await;
-    let user = client.get_user_by_id(&user_id).await?;
-    drop(client);
-
-    log::info!("get_user_by_id: Successfully fetched user {}", user_id);
-
-    state.user_store.cache_full_user(user.clone()).await;
-
-    Ok(user)
+    state
+        .notification_store
+        .remove(&account_id, &[notification_id])
+        .await
+        .map_err(VRCError::unknown)
 }
 
 #[tauri::command]
 #[specta::specta]
-async fn is_friend(user_id: String, state: State<'_, AppState>) -> Result<bool, VRCError> {
-    Ok(state.user_store.is_friend(&user_id).await)
-}
+async fn clear_notifications(state: State<'_, AppState>) -> Result<(), VRCError> {
+    let account_id = current_account_id(&state).await?;
 
-#[tauri::command]
-#[specta::specta]
-async fn is_user_online(user_id: String, state: State<'_, AppState>) -> Result<bool, VRCError> {
-    Ok(state.user_store.is_user_online(&user_id).await)
+    state.vrchat_client.clear_notifications().await?;
+
+    state
+        .notification_store
+        .clear(&account_id, Some(1))
+        .await
+        .map_err(VRCError::unknown)
 }
 
 #[tauri::command]
 #[specta::specta]
-async fn vrchat_check_session(state: State<'_, AppState>) -> Result<bool, VRCError> {
-    let client = state.vrchat_client.lock().await;
-    Ok(client.has_valid_session().await)
+async fn accept_friend_request(
+    notification_id: String,
+    state: State<'_, AppState>,
+) -> Result<(), VRCError> {
+    let account_id = current_account_id(&state).await?;
+
+    let notification = state
+        .notification_store
+        .get(&account_id, &notification_id)
+        .await
+        .map_err(VRCError::unknown)?;
+
+    state
+        .vrchat_client
+        .accept_friend_request(&notification_id)
+        .await?;
+
+    if let Some(sender_id) = notification.and_then(|n| n.sender_user_id) {
+        state.user_store.mark_friend(&sender_id).await;
+    }
+
+    state
+        .notification_store
+        .remove(&account_id, &[notification_id])
+        .await
+        .map_err(VRCError::unknown)
 }
 
+/// Trigger one of the `responses` of a v2 notification
 #[tauri::command]
 #[specta::specta]
-async fn vrchat_clear_session(state: State<'_, AppState>) -> Result<(), VRCError> {
-    let client = state.vrchat_client.lock().await;
-    client.clear_cookies().await;
-    drop(client);
+async fn respond_notification(
+    notification_id: String,
+    response: InboxResponse,
+    state: State<'_, AppState>,
+) -> Result<(), VRCError> {
+    let account_id = current_account_id(&state).await?;
 
-    state.user_store.clear_all().await;
+    state
+        .vrchat_client
+        .respond_notification_v2(&notification_id, &response.to_v2())
+        .await?;
 
     state
-        .account_store
-        .clear_last_active_account()
+        .notification_store
+        .mark_seen(&account_id, &notification_id)
         .await
-        .map_err(|e| VRCError::unknown(e))?;
-
-    Ok(())
+        .map_err(VRCError::unknown)
 }
 
-// Account Management Commands
-#[tauri::command]
-#[specta::specta]
-async fn save_current_account(user: User, state: State<'_, AppState>) -> Result<(), VRCError> {
-    let client = state.vrchat_client.lock().await;
-    let (auth_cookie, two_factor_cookie) = client.export_cookies().await;
+/// API version of an inbox notification, v1 unless the inbox knows otherwise
+async fn notification_version(
+    state: &AppState,
+    account_id: &str,
+    notification_id: &str,
+) -> Result<i32, VRCError> {
+    let notification = state
+        .notification_store
+        .get(account_id, notification_id)
+        .await
+        .map_err(VRCError::unknown)?;
 
-    let avatar_override = user
-        .user_icon
-        .clone()
-        .or_else(|| user.profile_pic_override.clone())
-        .or_else(|| user.profile_pic_override_thumbnail.clone());
+    Ok(notification.map_or(1, |n| n.version))
+}
 
-    let avatar_fallback = user
-        .current_avatar_thumbnail_image_url
-        .clone()
-        .or_else(|| user.current_avatar_image_url.clone());
+async fn current_user(state: &AppState) -> Result<User, VRCError> {
+    state
+        .user_store
+        .get_current_user()
+        .await
+        .ok_or_else(|| VRCError::invalid_input("No account is logged in"))
+}
 
-    let account = StoredAccount {
-        user_id: user.id.clone(),
-        username: user.username.clone(),
-        display_name: user.display_name.clone(),
-        avatar_url: avatar_override.clone().or_else(|| avatar_fallback.clone()),
-        avatar_fallback_url: avatar_fallback,
-        auth_cookie,
-        two_factor_cookie,
-        last_login: chrono::Utc::now().to_rfc3339(),
-    };
+async fn current_account_id(state: &AppState) -> Result<String, VRCError> {
+    state
+        .user_store
+        .get_current_user_id()
+        .await
+        .ok_or_else(|| VRCError::invalid_input("No account is logged in"))
+}
 
+// Settings Commands
+#[tauri::command]
+#[specta::specta]
+async fn get_settings(state: State<'_, AppState>) -> Result<AppSettings, VRCError> {
     state
-        .account_store
-        .save_account(account)
+        .settings_store
+        .get_settings()
         .await
         .map_err(|e| VRCError::unknown(e))
 }
 
 #[tauri::command]
 #[specta::specta]
-async fn get_all_accounts(state: State<'_, AppState>) -> Result<Vec<StoredAccount>, VRCError> {
+async fn save_settings(settings: AppSettings, state: State<'_, AppState>) -> Result<(), VRCError> {
+    info!(
+        "Saving settings: developer_mode={}",
+        settings.developer_mode
+    );
     state
-        .account_store
-        .get_all_accounts()
+        .settings_store
+        .save_settings(settings)
         .await
         .map_err(|e| VRCError::unknown(e))
 }
 
 #[tauri::command]
 #[specta::specta]
-async fn switch_account(user_id: String, state: State<'_, AppState>) -> Result<User, VRCError> {
-    state.user_store.clear_all().await;
-
-    let account = state
-        .account_store
-        .get_account(&user_id)
+async fn get_developer_mode(state: State<'_, AppState>) -> Result<bool, VRCError> {
+    state
+        .settings_store
+        .get_developer_mode()
         .await
-        .map_err(|e| VRCError::unknown(e))?
-        .ok_or_else(|| VRCError::invalid_input("Account not found"))?;
+        .map_err(|e| VRCError::unknown(e))
+}
 
+#[tauri::command]
+#[specta::specta]
+async fn set_developer_mode(enabled: bool, state: State<'_, AppState>) -> Result<(), VRCError> {
+    info!("Setting developer mode: {}", enabled);
     state
-        .account_store
-        .set_active_account(&user_id)
+        .settings_store
+        .set_developer_mode(enabled)
         .await
-        .map_err(|e| VRCError::unknown(e))?;
+        .map_err(|e| VRCError::unknown(e))
+}
 
-    let client = state.vrchat_client.lock().await;
-    client
-        .import_cookies(account.auth_cookie, account.two_factor_cookie)
-        .await;
+// Status Preset Commands
 
-    match client.get_current_user().await {
-        Ok(user) => {
-            state.user_store.set_current_user(user.clone()).await;
-            Ok(user)
-        }
-        Err(err) => {
-            // TODO: Handle if switching account fails
-            Err(err)
-        }
-    }
+#[tauri::command]
+#[specta::specta]
+async fn get_status_presets(state: State<'_, AppState>) -> Result<Vec<StatusPreset>, VRCError> {
+    state
+        .settings_store
+        .get_status_presets()
+        .await
+        .map_err(VRCError::unknown)
 }
 
+/// Create a preset, or replace the one with the same name
 #[tauri::command]
 #[specta::specta]
-async fn remove_account(user_id: String, state: State<'_, AppState>) -> Result<(), VRCError> {
+async fn save_status_preset(
+    preset: StatusPreset,
+    state: State<'_, AppState>,
+) -> Result<(), VRCError> {
+    preset.validate().map_err(VRCError::invalid_input)?;
     state
-        .account_store
-        .remove_account(&user_id)
+        .settings_store
+        .save_status_preset(preset)
         .await
-        .map_err(|e| VRCError::unknown(e))
+        .map_err(VRCError::unknown)
 }
 
 #[tauri::command]
 #[specta::specta]
-async fn clear_all_accounts(state: State<'_, AppState>) -> Result<(), VRCError> {
+async fn delete_status_preset(name: String, state: State<'_, AppState>) -> Result<bool, VRCError> {
     state
-        .account_store
-        .clear_all_accounts()
+        .settings_store
+        .delete_status_preset(&name)
         .await
-        .map_err(|e| VRCError::unknown(e))
+        .map_err(VRCError::unknown)
 }
 
+/// Set the status and description stored under `name`
 #[tauri::command]
 #[specta::specta]
-async fn load_last_account(state: State<'_, AppState>) -> Result<Option<User>, VRCError> {
-    let account = match state
-        .account_store
-        .get_last_active_account()
+async fn apply_status_preset(name: String, state: State<'_, AppState>) -> Result<User, VRCError> {
+    let preset = state
+        .settings_store
+        .get_status_preset(&name)
         .await
-        .map_err(|e| VRCError::unknown(e))?
-    {
-        Some(acc) => acc,
-        None => return Ok(None),
+        .map_err(VRCError::unknown)?
+        .ok_or_else(|| VRCError::not_found(format!("Status preset '{}' not found", name)))?;
+
+    let request = UpdateStatusRequest {
+        status: preset.status,
+        status_description: preset.status_description,
     };
+    let current_user = current_user(&state).await?;
+    let user = state
+        .vrchat_client
+        .update_status(&current_user, &request)
+        .await?;
 
-    let client = state.vrchat_client.lock().await;
-    client
-        .import_cookies(account.auth_cookie, account.two_factor_cookie)
-        .await;
+    state.user_store.patch_current_user_profile(&user).await;
+    record_status_history(&state, &user, Some(&preset.name)).await;
 
-    match client.get_current_user().await {
-        Ok(user) => Ok(Some(user)),
-        Err(_) => {
-            // TODO: Handle if last account cannot be loaded
-            // client.clear_cookies().await;
-            Ok(None)
-        }
-    }
+    Ok(user)
 }
 
-// Settings Commands
+/// Statuses the logged-in account had, set from this app or anywhere else, newest first
 #[tauri::command]
 #[specta::specta]
-async fn get_settings(state: State<'_, AppState>) -> Result<AppSettings, VRCError> {
+async fn get_status_history(
+    limit: Option<u32>,
+    state: State<'_, AppState>,
+) -> Result<Vec<StatusHistoryEntry>, VRCError> {
+    let account_id = current_account_id(&state).await?;
     state
         .settings_store
-        .get_settings()
+        .get_status_history(&account_id, limit)
         .await
-        .map_err(|e| VRCError::unknown(e))
+        .map_err(VRCError::unknown)
 }
 
 #[tauri::command]
 #[specta::specta]
-async fn save_settings(settings: AppSettings, state: State<'_, AppState>) -> Result<(), VRCError> {
-    info!(
-        "Saving settings: developer_mode={}",
-        settings.developer_mode
-    );
+async fn clear_status_history(state: State<'_, AppState>) -> Result<(), VRCError> {
+    let account_id = current_account_id(&state).await?;
     state
         .settings_store
-        .save_settings(settings)
+        .clear_status_history(&account_id)
         .await
-        .map_err(|e| VRCError::unknown(e))
+        .map_err(VRCError::unknown)
+}
+
+/// Best effort, a failed write must not fail the status change itself
+async fn record_status_history(state: &AppState, user: &User, preset_name: Option<&str>) {
+    if let Err(e) = state
+        .settings_store
+        .record_status(&user.id, user.status, &user.status_description, preset_name)
+        .await
+    {
+        log::warn!("Failed to record status history: {}", e);
+    }
+}
+
+/// Give a new account a history to start from; best effort like `record_status_history`
+async fn seed_status_history(state: &AppState, user: &User) {
+    if let Err(e) = state
+        .settings_store
+        .seed_status_history(&user.id, &user.status_history)
+        .await
+    {
+        log::warn!("Failed to seed status history: {}", e);
+    }
 }
 
+// Status Rule Commands
+
 #[tauri::command]
 #[specta::specta]
-async fn get_developer_mode(state: State<'_, AppState>) -> Result<bool, VRCError> {
+async fn get_status_rules(state: State<'_, AppState>) -> Result<Vec<StatusRule>, VRCError> {
     state
-        .settings_store
-        .get_developer_mode()
+        .status_rule_store
+        .get_rules()
         .await
-        .map_err(|e| VRCError::unknown(e))
+        .map_err(VRCError::unknown)
 }
 
+/// Create a rule, or update the one with `rule.id`
 #[tauri::command]
 #[specta::specta]
-async fn set_developer_mode(enabled: bool, state: State<'_, AppState>) -> Result<(), VRCError> {
-    info!("Setting developer mode: {}", enabled);
+async fn save_status_rule(
+    rule: StatusRuleInput,
+    state: State<'_, AppState>,
+) -> Result<StatusRule, VRCError> {
+    rule.validate().map_err(VRCError::invalid_input)?;
+    if let Some(id) = rule.id {
+        state
+            .status_rule_store
+            .get_rule(id)
+            .await
+            .map_err(VRCError::unknown)?
+            .ok_or_else(|| VRCError::not_found(format!("Status rule {} not found", id)))?;
+    }
+
+    let rule = state
+        .status_rule_store
+        .save_rule(rule)
+        .await
+        .map_err(VRCError::unknown)?;
+    state.status_scheduler.invalidate_rules().await;
+
+    Ok(rule)
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn delete_status_rule(id: i32, state: State<'_, AppState>) -> Result<bool, VRCError> {
+    let deleted = state
+        .status_rule_store
+        .delete_rule(id)
+        .await
+        .map_err(VRCError::unknown)?;
+    state.status_scheduler.invalidate_rules().await;
+
+    Ok(deleted)
+}
+
+// Friend Alert Commands
+
+#[tauri::command]
+#[specta::specta]
+async fn get_friend_alerts(state: State<'_, AppState>) -> Result<Vec<AlertRule>, VRCError> {
     state
-        .settings_store
-        .set_developer_mode(enabled)
+        .alert_rule_store
+        .get_rules()
         .await
-        .map_err(|e| VRCError::unknown(e))
+        .map_err(VRCError::unknown)
+}
+
+/// Create an alert rule, or update the one with `rule.id`
+#[tauri::command]
+#[specta::specta]
+async fn save_friend_alert(
+    rule: AlertRuleInput,
+    state: State<'_, AppState>,
+) -> Result<AlertRule, VRCError> {
+    rule.validate().map_err(VRCError::invalid_input)?;
+    if let Some(id) = rule.id {
+        state
+            .alert_rule_store
+            .get_rule(id)
+            .await
+            .map_err(VRCError::unknown)?
+            .ok_or_else(|| VRCError::not_found(format!("Friend alert {} not found", id)))?;
+    }
+
+    let rule = state
+        .alert_rule_store
+        .save_rule(rule)
+        .await
+        .map_err(VRCError::unknown)?;
+    state.friend_alerts.invalidate_rules().await;
+
+    Ok(rule)
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn delete_friend_alert(id: i32, state: State<'_, AppState>) -> Result<bool, VRCError> {
+    let deleted = state
+        .alert_rule_store
+        .delete_rule(id)
+        .await
+        .map_err(VRCError::unknown)?;
+    state.friend_alerts.invalidate_rules().await;
+
+    Ok(deleted)
 }
 
 // Log Commands
@@ -596,16 +2237,82 @@ fn create_specta_builder() -> SpectaBuilder<tauri::Wry> {
             vrchat_verify_2fa,
             vrchat_get_current_user,
             vrchat_update_status,
+            vrchat_update_current_user,
             vrchat_logout,
+            vrchat_reauthenticate,
+            vrchat_resume_session,
             vrchat_get_online_friends,
             vrchat_get_uploaded_worlds,
+            vrchat_search_worlds,
+            get_world,
             vrchat_get_uploaded_avatars,
+            vrchat_get_avatar,
+            vrchat_get_favorited_avatars,
+            vrchat_select_avatar,
+            vrchat_select_fallback_avatar,
+            get_avatar_performance_report,
+            export_avatar_performance_report,
+            vrchat_get_instance,
+            vrchat_get_instance_short_name,
+            vrchat_create_instance,
+            vrchat_invite_user,
+            vrchat_request_invite,
+            vrchat_invite_myself,
+            vrchat_respond_invite_request,
             get_online_friends,
             get_all_friends,
             get_user,
             get_user_by_id,
+            vrchat_search_users,
+            vrchat_get_user_profile,
             is_friend,
             is_user_online,
+            vrchat_send_friend_request,
+            vrchat_cancel_friend_request,
+            vrchat_get_friend_status,
+            vrchat_unfriend,
+            vrchat_get_player_moderations,
+            vrchat_moderate_user,
+            vrchat_unmoderate_user,
+            export_block_list,
+            vrchat_get_my_groups,
+            vrchat_get_user_groups,
+            vrchat_get_group,
+            vrchat_get_group_members,
+            vrchat_get_group_roles,
+            vrchat_get_group_announcement,
+            vrchat_get_group_posts,
+            vrchat_get_group_instances,
+            vrchat_ban_group_member,
+            vrchat_unban_group_member,
+            vrchat_get_group_bans,
+            vrchat_kick_group_member,
+            vrchat_get_group_join_requests,
+            vrchat_respond_group_join_request,
+            vrchat_add_group_member_role,
+            vrchat_remove_group_member_role,
+            sync_group_audit_log,
+            search_group_audit_log,
+            clear_group_audit_log,
+            vrchat_get_favorites,
+            vrchat_get_favorite_groups,
+            vrchat_get_favorite_limits,
+            vrchat_add_favorite,
+            vrchat_remove_favorite,
+            vrchat_update_favorite_group,
+            vrchat_clear_favorite_group,
+            get_favorite_annotations,
+            get_local_collections,
+            create_local_collection,
+            update_local_collection,
+            delete_local_collection,
+            get_local_favorites,
+            add_local_favorite,
+            update_local_favorite,
+            remove_local_favorite,
+            export_local_favorites,
+            import_local_favorites,
+            promote_local_favorite,
             vrchat_check_session,
             vrchat_clear_session,
             websocket_start,
@@ -616,10 +2323,32 @@ fn create_specta_builder() -> SpectaBuilder<tauri::Wry> {
             remove_account,
             clear_all_accounts,
             load_last_account,
+            get_friend_feed,
+            clear_friend_feed,
+            get_notification_inbox,
+            refresh_notifications,
+            see_notification,
+            hide_notification,
+            delete_notification,
+            clear_notifications,
+            accept_friend_request,
+            respond_notification,
             get_settings,
             save_settings,
             get_developer_mode,
             set_developer_mode,
+            get_status_presets,
+            save_status_preset,
+            delete_status_preset,
+            apply_status_preset,
+            get_status_history,
+            clear_status_history,
+            get_status_rules,
+            save_status_rule,
+            delete_status_rule,
+            get_friend_alerts,
+            save_friend_alert,
+            delete_friend_alert,
             get_backend_logs,
             clear_backend_logs,
             export_backend_logs,
@@ -638,6 +2367,8 @@ fn create_specta_builder() -> SpectaBuilder<tauri::Wry> {
         .typ::<User>()
         .typ::<LoginResult>()
         .typ::<UpdateStatusRequest>()
+        .typ::<UpdateUserRequest>()
+        .typ::<SessionExpiredEvent>()
         // Enum types
         .typ::<UserStatus>()
         .typ::<ReleaseStatus>()
@@ -649,21 +2380,94 @@ fn create_specta_builder() -> SpectaBuilder<tauri::Wry> {
         .typ::<OrderOption>()
         // User-related types
         .typ::<LimitedUserFriend>()
+        .typ::<LimitedUser>()
+        .typ::<UserProfile>()
         .typ::<PastDisplayName>()
         .typ::<Badge>()
         .typ::<DiscordDetails>()
         .typ::<GoogleDetails>()
         .typ::<SteamDetails>()
+        .typ::<FriendStatus>()
+        .typ::<PlayerModerationType>()
+        .typ::<PlayerModeration>()
+        .typ::<UserModerationState>()
+        .typ::<FavoriteType>()
+        .typ::<FavoriteGroupVisibility>()
+        .typ::<Favorite>()
+        .typ::<FavoriteGroup>()
+        .typ::<FavoriteTypeLimits>()
+        .typ::<FavoriteLimits>()
+        .typ::<AddFavoriteRequest>()
+        .typ::<UpdateFavoriteGroupRequest>()
+        .typ::<FavoriteAnnotation>()
+        .typ::<LocalFavoriteKind>()
+        .typ::<LocalFavoritesFormat>()
+        .typ::<LocalCollection>()
+        .typ::<LocalFavoriteEntry>()
+        .typ::<LocalFavoriteInput>()
+        .typ::<LocalFavoritesImport>()
+        .typ::<SortOption>()
+        .typ::<WorldListSource>()
+        .typ::<WorldSearchParams>()
+        .typ::<UploadedWorldsProgress>()
         // World types
         .typ::<LimitedWorld>()
         .typ::<UnityPackageSummary>()
+        // Instance types
+        .typ::<Instance>()
+        .typ::<InstanceType>()
+        .typ::<InstancePlatforms>()
+        .typ::<InstanceShortName>()
+        .typ::<CreateInstanceRequest>()
+        .typ::<GroupAccessType>()
+        // Group types
+        .typ::<Group>()
+        .typ::<UserGroup>()
+        .typ::<RepresentedGroup>()
+        .typ::<GroupMember>()
+        .typ::<GroupMemberUser>()
+        .typ::<GroupRole>()
+        .typ::<GroupAnnouncement>()
+        .typ::<GroupPost>()
+        .typ::<GroupInstance>()
+        .typ::<GroupPrivacy>()
+        .typ::<GroupJoinState>()
+        .typ::<GroupMemberStatus>()
+        .typ::<GroupJoinRequestAction>()
+        .typ::<GroupAuditEntry>()
+        .typ::<GroupAuditQuery>()
+        .typ::<GroupAuditPage>()
+        // Invite types
+        .typ::<SentNotification>()
+        .typ::<NotificationPayload>()
         // Avatar types
         .typ::<LimitedAvatar>()
         .typ::<AvatarPerformance>()
         .typ::<AvatarStyles>()
+        .typ::<AvatarPerformanceReport>()
+        .typ::<PlatformPerformanceSummary>()
+        .typ::<AvatarReportRow>()
         // Store types
         .typ::<StoredAccount>()
         .typ::<AppSettings>()
+        .typ::<StatusPreset>()
+        .typ::<StatusHistoryEntry>()
+        .typ::<StatusRule>()
+        .typ::<StatusRuleInput>()
+        .typ::<StatusRuleKind>()
+        .typ::<StatusRuleChange>()
+        .typ::<AlertRule>()
+        .typ::<AlertRuleInput>()
+        .typ::<AlertTrigger>()
+        .typ::<AlertAction>()
+        .typ::<FriendAlert>()
+        .typ::<FriendAlertSound>()
+        .typ::<FeedEntry>()
+        .typ::<FeedEventType>()
+        .typ::<FeedQuery>()
+        .typ::<FeedPage>()
+        .typ::<InboxNotification>()
+        .typ::<InboxResponse>()
         // Log types
         .typ::<LogEntry>()
         // Database types
@@ -722,16 +2526,39 @@ pub fn run() {
         generate_bindings();
     }
 
-    let vrchat_client = VRChatClient::new().expect("Failed to create VRChat client");
+    let client_config = VRChatClientConfig::default();
+    let vrchat_client =
+        VRChatClient::new(client_config.clone()).expect("Failed to create VRChat client");
     let account_store =
         tauri::async_runtime::block_on(AccountStore::new()).expect("Failed to create AccountStore");
     let settings_store = tauri::async_runtime::block_on(SettingsStore::new())
         .expect("Failed to create SettingsStore");
     let image_cache = Arc::new(
-        tauri::async_runtime::block_on(ImageCacheStore::new())
+        tauri::async_runtime::block_on(ImageCacheStore::new(vrchat_client.rate_limiter().clone()))
             .expect("Failed to create ImageCacheStore"),
     );
+    let feed_store =
+        tauri::async_runtime::block_on(FeedStore::new()).expect("Failed to create FeedStore");
+    let notification_store = tauri::async_runtime::block_on(NotificationStore::new())
+        .expect("Failed to create NotificationStore");
+    let local_favorites_store = tauri::async_runtime::block_on(LocalFavoritesStore::new())
+        .expect("Failed to create LocalFavoritesStore");
+    let world_store =
+        tauri::async_runtime::block_on(WorldStore::new()).expect("Failed to create WorldStore");
+    let group_audit_store = tauri::async_runtime::block_on(GroupAuditStore::new())
+        .expect("Failed to create GroupAuditStore");
+    let status_rule_store = tauri::async_runtime::block_on(StatusRuleStore::new())
+        .expect("Failed to create StatusRuleStore");
+    let alert_rule_store = tauri::async_runtime::block_on(AlertRuleStore::new())
+        .expect("Failed to create AlertRuleStore");
     let user_store = UserStore::new();
+    let favorites_store = FavoritesStore::new();
+    let status_scheduler = StatusScheduler::new(
+        vrchat_client.clone(),
+        user_store.clone(),
+        status_rule_store.clone(),
+        settings_store.clone(),
+    );
 
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
@@ -750,15 +2577,68 @@ pub fn run() {
         )
         .invoke_handler(specta_builder.invoke_handler())
         .setup(move |app| {
+            let friend_alerts = FriendAlerts::new(
+                vrchat_client.clone(),
+                alert_rule_store.clone(),
+                favorites_store.clone(),
+            );
+
             // Initialize WebSocket with app handle and UserStore
-            let websocket = VRChatWebSocket::new(app.handle().clone(), user_store.clone());
+            let websocket = VRChatWebSocket::new(
+                app.handle().clone(),
+                PipelineStores {
+                    user_store: user_store.clone(),
+                    feed_store: feed_store.clone(),
+                    notification_store: notification_store.clone(),
+                    settings_store: settings_store.clone(),
+                    status_scheduler: status_scheduler.clone(),
+                    friend_alerts: friend_alerts.clone(),
+                },
+                client_config,
+            );
+
+            let mut session_expired = vrchat_client.subscribe_session_expired();
+            let app_handle = app.handle().clone();
+            tauri::async_runtime::spawn(async move {
+                use tokio::sync::broadcast::error::RecvError;
+
+                loop {
+                    match session_expired.recv().await {
+                        Ok(notice) => handle_session_expired(&app_handle, notice).await,
+                        Err(RecvError::Lagged(_)) => continue,
+                        Err(RecvError::Closed) => break,
+                    }
+                }
+            });
+
+            let expired_worlds = world_store.clone();
+            tauri::async_runtime::spawn(async move {
+                match expired_worlds.purge_expired().await {
+                    Ok(0) => {}
+                    Ok(count) => log::info!("Purged {} expired worlds from the cache", count),
+                    Err(e) => log::warn!("Failed to purge world cache: {}", e),
+                }
+            });
+
+            tauri::async_runtime::spawn(status_scheduler.clone().run(app.handle().clone()));
 
             let app_state = AppState {
-                vrchat_client: Arc::new(Mutex::new(vrchat_client)),
+                vrchat_client,
                 account_store,
                 websocket: Arc::new(Mutex::new(websocket)),
                 user_store,
                 settings_store,
+                feed_store,
+                notification_store,
+                moderation_store: ModerationStore::new(),
+                favorites_store,
+                local_favorites_store,
+                world_store,
+                group_audit_store,
+                status_rule_store,
+                status_scheduler,
+                alert_rule_store,
+                friend_alerts,
                 image_cache: image_cache.clone(),
             };
 
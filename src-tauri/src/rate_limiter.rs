@@ -0,0 +1,360 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::time::{Duration, Instant, sleep};
+
+use crate::http_common::MAX_DOWNLOADS_PER_SECOND;
+
+/// Groups of VRChat endpoints that are throttled independently
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EndpointClass {
+    /// Login, 2FA, current user and logout
+    Auth,
+    /// Friend lists and friend requests
+    Friends,
+    /// World and instance lookups
+    Worlds,
+    /// File and image downloads
+    Files,
+    /// Everything else (users, avatars, ...)
+    Other,
+}
+
+impl EndpointClass {
+    /// Classify a REST path relative to the API root, e.g. `/auth/user/friends`
+    pub fn from_api_path(path: &str) -> Self {
+        let path = path.trim_start_matches('/');
+        let mut segments = path.split('/');
+        let first = segments.next().unwrap_or_default();
+        let second = segments.next().unwrap_or_default();
+        let third = segments.next().unwrap_or_default();
+
+        match (first, second, third) {
+            ("auth", "user", "friends") | ("friends", _, _) => Self::Friends,
+            ("user", _, "friendRequest") => Self::Friends,
+            ("auth", _, _) | ("logout", _, _) => Self::Auth,
+            ("worlds", _, _) | ("instances", _, _) => Self::Worlds,
+            ("file", _, _) | ("files", _, _) | ("image", _, _) => Self::Files,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Token bucket settings: `burst` requests at once, refilled at `per_second`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    pub burst: u32,
+    pub per_second: f64,
+}
+
+impl RateLimit {
+    pub const fn new(burst: u32, per_second: f64) -> Self {
+        Self { burst, per_second }
+    }
+
+    /// Never throttles, useful for tests and local mock servers
+    pub const fn unlimited() -> Self {
+        Self {
+            burst: u32::MAX,
+            per_second: f64::INFINITY,
+        }
+    }
+
+    fn is_unlimited(&self) -> bool {
+        self.per_second.is_infinite()
+    }
+
+    /// A bucket that never holds a token or never refills would stall every request
+    pub fn validate(&self) -> Result<(), String> {
+        if self.burst == 0 {
+            return Err("Rate limit burst must be at least 1".to_string());
+        }
+        if self.per_second.is_nan() || self.per_second <= 0.0 {
+            return Err(format!(
+                "Rate limit refill must be positive, got {}",
+                self.per_second
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Per endpoint class limits
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateLimitConfig {
+    pub auth: RateLimit,
+    pub friends: RateLimit,
+    pub worlds: RateLimit,
+    pub files: RateLimit,
+    pub other: RateLimit,
+}
+
+impl RateLimitConfig {
+    pub fn unlimited() -> Self {
+        Self {
+            auth: RateLimit::unlimited(),
+            friends: RateLimit::unlimited(),
+            worlds: RateLimit::unlimited(),
+            files: RateLimit::unlimited(),
+            other: RateLimit::unlimited(),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        [
+            ("auth", self.auth),
+            ("friends", self.friends),
+            ("worlds", self.worlds),
+            ("files", self.files),
+            ("other", self.other),
+        ]
+        .into_iter()
+        .try_for_each(|(class, limit)| limit.validate().map_err(|e| format!("{}: {}", class, e)))
+    }
+
+    pub fn limit_for(&self, class: EndpointClass) -> RateLimit {
+        match class {
+            EndpointClass::Auth => self.auth,
+            EndpointClass::Friends => self.friends,
+            EndpointClass::Worlds => self.worlds,
+            EndpointClass::Files => self.files,
+            EndpointClass::Other => self.other,
+        }
+    }
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            auth: RateLimit::new(3, 0.5),
+            friends: RateLimit::new(3, 1.0),
+            worlds: RateLimit::new(5, 2.0),
+            files: RateLimit::new(MAX_DOWNLOADS_PER_SECOND, MAX_DOWNLOADS_PER_SECOND as f64),
+            other: RateLimit::new(5, 2.0),
+        }
+    }
+}
+
+struct Bucket {
+    limit: RateLimit,
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl Bucket {
+    fn new(limit: RateLimit, now: Instant) -> Self {
+        Self {
+            limit,
+            tokens: limit.burst as f64,
+            last_refill: now,
+        }
+    }
+
+    /// Take a token, or return how long until one is available
+    fn try_take(&mut self, now: Instant) -> Result<(), Duration> {
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.limit.per_second).min(self.limit.burst as f64);
+        self.last_refill = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64(
+                (1.0 - self.tokens) / self.limit.per_second,
+            ))
+        }
+    }
+}
+
+struct LimiterState {
+    buckets: HashMap<EndpointClass, Bucket>,
+    paused_until: Option<Instant>,
+}
+
+/// Token bucket rate limiter shared by every outbound VRChat request.
+/// A server `Retry-After` pauses all classes, so one 429 holds back every caller.
+#[derive(Clone)]
+pub struct RateLimiter {
+    config: Arc<RateLimitConfig>,
+    state: Arc<Mutex<LimiterState>>,
+}
+
+impl RateLimiter {
+    pub fn new(config: RateLimitConfig) -> Self {
+        Self {
+            config: Arc::new(config),
+            state: Arc::new(Mutex::new(LimiterState {
+                buckets: HashMap::new(),
+                paused_until: None,
+            })),
+        }
+    }
+
+    pub fn config(&self) -> &RateLimitConfig {
+        &self.config
+    }
+
+    /// Wait until a request of the given class may be sent
+    pub async fn acquire(&self, class: EndpointClass) {
+        let limit = self.config.limit_for(class);
+
+        loop {
+            let wait = {
+                let mut state = self.state.lock().unwrap();
+                let now = Instant::now();
+
+                match state.paused_until {
+                    Some(until) if until > now => until - now,
+                    _ => {
+                        state.paused_until = None;
+
+                        if limit.is_unlimited() {
+                            return;
+                        }
+
+                        let bucket = state
+                            .buckets
+                            .entry(class)
+                            .or_insert_with(|| Bucket::new(limit, now));
+                        match bucket.try_take(now) {
+                            Ok(()) => return,
+                            Err(wait) => wait,
+                        }
+                    }
+                }
+            };
+
+            sleep(wait).await;
+        }
+    }
+
+    /// Hold back every class for `duration`, typically the server's `Retry-After`
+    pub fn pause_for(&self, duration: Duration) {
+        let until = Instant::now() + duration;
+        let mut state = self.state.lock().unwrap();
+
+        if state.paused_until.is_none_or(|current| current < until) {
+            log::warn!("Pausing VRChat requests for {:?}", duration);
+            state.paused_until = Some(until);
+        }
+    }
+
+    /// Remaining global pause, if any
+    pub fn paused_for(&self) -> Option<Duration> {
+        let state = self.state.lock().unwrap();
+        state
+            .paused_until
+            .map(|until| until.saturating_duration_since(Instant::now()))
+            .filter(|remaining| !remaining.is_zero())
+    }
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new(RateLimitConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limiter(limit: RateLimit) -> RateLimiter {
+        RateLimiter::new(RateLimitConfig {
+            worlds: limit,
+            ..RateLimitConfig::unlimited()
+        })
+    }
+
+    #[test]
+    fn test_endpoint_classification() {
+        assert_eq!(
+            EndpointClass::from_api_path("/auth/user"),
+            EndpointClass::Auth
+        );
+        assert_eq!(
+            EndpointClass::from_api_path("/auth/twofactorauth/totp/verify"),
+            EndpointClass::Auth
+        );
+        assert_eq!(
+            EndpointClass::from_api_path("/auth/user/friends"),
+            EndpointClass::Friends
+        );
+        assert_eq!(
+            EndpointClass::from_api_path("/user/usr_123/friendRequest"),
+            EndpointClass::Friends
+        );
+        assert_eq!(
+            EndpointClass::from_api_path("/worlds/wrld_123"),
+            EndpointClass::Worlds
+        );
+        assert_eq!(
+            EndpointClass::from_api_path("/file/file_123/1/file"),
+            EndpointClass::Files
+        );
+        assert_eq!(
+            EndpointClass::from_api_path("/users/usr_123"),
+            EndpointClass::Other
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_stalling_limits() {
+        assert!(RateLimitConfig::default().validate().is_ok());
+        assert!(RateLimitConfig::unlimited().validate().is_ok());
+
+        assert!(RateLimit::new(0, 1.0).validate().is_err());
+        assert!(RateLimit::new(1, 0.0).validate().is_err());
+        assert!(RateLimit::new(1, -1.0).validate().is_err());
+        assert!(RateLimit::new(1, f64::NAN).validate().is_err());
+
+        let config = RateLimitConfig {
+            files: RateLimit::new(0, 1.0),
+            ..RateLimitConfig::default()
+        };
+        assert!(config.validate().unwrap_err().starts_with("files:"));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_burst_then_refill() {
+        let limiter = limiter(RateLimit::new(2, 4.0));
+        let started = Instant::now();
+
+        limiter.acquire(EndpointClass::Worlds).await;
+        limiter.acquire(EndpointClass::Worlds).await;
+        assert_eq!(started.elapsed(), Duration::ZERO);
+
+        limiter.acquire(EndpointClass::Worlds).await;
+        assert_eq!(started.elapsed(), Duration::from_millis(250));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_classes_have_separate_buckets() {
+        let limiter = limiter(RateLimit::new(1, 1.0));
+        let started = Instant::now();
+
+        limiter.acquire(EndpointClass::Worlds).await;
+        limiter.acquire(EndpointClass::Friends).await;
+        limiter.acquire(EndpointClass::Files).await;
+
+        assert_eq!(started.elapsed(), Duration::ZERO);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_pause_holds_back_every_class() {
+        let limiter = RateLimiter::new(RateLimitConfig::unlimited());
+        let started = Instant::now();
+
+        limiter.pause_for(Duration::from_secs(3));
+        // A shorter pause never shortens an existing one
+        limiter.pause_for(Duration::from_secs(1));
+        assert_eq!(limiter.paused_for(), Some(Duration::from_secs(3)));
+
+        limiter.acquire(EndpointClass::Files).await;
+        assert_eq!(started.elapsed(), Duration::from_secs(3));
+        assert_eq!(limiter.paused_for(), None);
+
+        limiter.acquire(EndpointClass::Auth).await;
+        assert_eq!(started.elapsed(), Duration::from_secs(3));
+    }
+}
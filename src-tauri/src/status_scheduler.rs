@@ -0,0 +1,553 @@
+use chrono::{DateTime, Duration, FixedOffset, Local, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter};
+use tokio::sync::{Mutex, mpsc};
+
+use crate::store::{SettingsStore, StatusRule, StatusRuleKind, StatusRuleStore, UserStore};
+use crate::vrchat_api::VRChatClient;
+use crate::vrchat_api::types::{
+    InstanceAccessType, Location, UpdateStatusRequest, User, UserStatus,
+};
+
+/// How often time based rules are checked
+const TICK_INTERVAL_SECS: u64 = 30;
+
+/// `at_time` rules still fire this long after their time, e.g. when the app was just started
+const AT_TIME_GRACE_MINUTES: i64 = 5;
+
+/// Source of the current time, replaced by a fake clock in tests
+pub trait Clock: Send + Sync {
+    fn now(&self) -> DateTime<FixedOffset>;
+}
+
+/// Wall clock in the system time zone
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<FixedOffset> {
+        Local::now().fixed_offset()
+    }
+}
+
+/// A status change made by a rule, emitted as `status-rule-applied`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+pub struct StatusRuleChange {
+    pub rule_id: i32,
+    pub rule_name: String,
+    pub status: UserStatus,
+    pub status_description: String,
+}
+
+/// Decides which rule fires, without touching the API
+pub struct StatusRuleEvaluator {
+    clock: Arc<dyn Clock>,
+    /// Last status seen on the current user and since when
+    observed: Option<(UserStatus, DateTime<FixedOffset>)>,
+    /// Status to restore when leaving a private instance
+    before_private: Option<(UserStatus, String)>,
+}
+
+impl StatusRuleEvaluator {
+    pub fn new(clock: Arc<dyn Clock>) -> Self {
+        Self {
+            clock,
+            observed: None,
+            before_private: None,
+        }
+    }
+
+    pub fn now(&self) -> DateTime<FixedOffset> {
+        self.clock.now()
+    }
+
+    /// Forget what was observed for the previous account
+    pub fn reset(&mut self) {
+        self.observed = None;
+        self.before_private = None;
+    }
+
+    /// First due `at_time` or `revert_after` rule. `revert_after` counts from the
+    /// moment the status was first observed, which is app start at the earliest.
+    pub fn due(
+        &mut self,
+        rules: &[StatusRule],
+        status: UserStatus,
+        status_description: &str,
+    ) -> Option<StatusRuleChange> {
+        let now = self.clock.now();
+        let elapsed = match self.observed {
+            Some((observed, since)) if observed == status => now - since,
+            _ => {
+                self.observed = Some((status, now));
+                Duration::zero()
+            }
+        };
+
+        rules.iter().filter(|rule| rule.enabled).find_map(|rule| {
+            let fires = match rule.kind {
+                StatusRuleKind::AtTime => at_time_due(rule, now),
+                StatusRuleKind::RevertAfter => {
+                    rule.from_status == Some(status)
+                        && rule
+                            .minutes
+                            .is_some_and(|minutes| elapsed >= Duration::minutes(minutes as i64))
+                }
+                StatusRuleKind::PrivateInstance => false,
+            };
+            fires
+                .then(|| change_for(rule, status_description))
+                .filter(|change| !change.matches(status, status_description))
+        })
+    }
+
+    /// Switch to the `private_instance` rule's status in invite instances and restore
+    /// the previous one when leaving, unless it was changed by hand in between
+    pub fn on_location(
+        &mut self,
+        rules: &[StatusRule],
+        location: &Location,
+        status: UserStatus,
+        status_description: &str,
+    ) -> Option<StatusRuleChange> {
+        let rule = rules
+            .iter()
+            .find(|rule| rule.enabled && rule.kind == StatusRuleKind::PrivateInstance)?;
+
+        match location {
+            // Between instances or hidden, wait for the next real location
+            Location::Traveling | Location::Private => None,
+            Location::Instance(instance)
+                if matches!(
+                    instance.access_type(),
+                    InstanceAccessType::Invite | InstanceAccessType::InvitePlus
+                ) =>
+            {
+                if self.before_private.is_some() {
+                    return None;
+                }
+                let change = change_for(rule, status_description);
+                if change.matches(status, status_description) {
+                    return None;
+                }
+                self.before_private = Some((status, status_description.to_string()));
+                Some(change)
+            }
+            _ => {
+                let (previous, previous_description) = self.before_private.take()?;
+                if status != rule.status {
+                    return None;
+                }
+                Some(StatusRuleChange {
+                    rule_id: rule.id,
+                    rule_name: rule.name.clone(),
+                    status: previous,
+                    status_description: previous_description,
+                })
+            }
+        }
+    }
+}
+
+impl StatusRuleChange {
+    fn matches(&self, status: UserStatus, status_description: &str) -> bool {
+        self.status == status && self.status_description == status_description
+    }
+}
+
+fn change_for(rule: &StatusRule, status_description: &str) -> StatusRuleChange {
+    StatusRuleChange {
+        rule_id: rule.id,
+        rule_name: rule.name.clone(),
+        status: rule.status,
+        status_description: rule
+            .status_description
+            .clone()
+            .unwrap_or_else(|| status_description.to_string()),
+    }
+}
+
+/// Within the grace period after today's `time` and not applied since
+fn at_time_due(rule: &StatusRule, now: DateTime<FixedOffset>) -> bool {
+    let Some(trigger) = rule.time_of_day().and_then(|time| {
+        now.date_naive()
+            .and_time(time)
+            .and_local_timezone(*now.offset())
+            .single()
+    }) else {
+        return false;
+    };
+    let last_applied = rule
+        .last_applied_at
+        .as_deref()
+        .and_then(|at| DateTime::parse_from_rfc3339(at).ok());
+
+    now >= trigger
+        && now - trigger < Duration::minutes(AT_TIME_GRACE_MINUTES)
+        && last_applied.is_none_or(|at| at < trigger)
+}
+
+/// Applies status rules from its own tick loop and from `UserLocation` pipeline events
+#[derive(Clone)]
+pub struct StatusScheduler {
+    client: VRChatClient,
+    user_store: UserStore,
+    rule_store: StatusRuleStore,
+    settings_store: SettingsStore,
+    evaluator: Arc<Mutex<StatusRuleEvaluator>>,
+    /// Rules as last loaded, dropped whenever they change
+    rules: Arc<Mutex<Option<Vec<StatusRule>>>>,
+    locations: mpsc::UnboundedSender<Location>,
+    /// Taken by `run`, which handles locations in the order they arrived
+    location_receiver: Arc<Mutex<Option<mpsc::UnboundedReceiver<Location>>>>,
+}
+
+impl StatusScheduler {
+    pub fn new(
+        client: VRChatClient,
+        user_store: UserStore,
+        rule_store: StatusRuleStore,
+        settings_store: SettingsStore,
+    ) -> Self {
+        Self::with_clock(
+            client,
+            user_store,
+            rule_store,
+            settings_store,
+            Arc::new(SystemClock),
+        )
+    }
+
+    pub fn with_clock(
+        client: VRChatClient,
+        user_store: UserStore,
+        rule_store: StatusRuleStore,
+        settings_store: SettingsStore,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        let (locations, location_receiver) = mpsc::unbounded_channel();
+        Self {
+            client,
+            user_store,
+            rule_store,
+            settings_store,
+            evaluator: Arc::new(Mutex::new(StatusRuleEvaluator::new(clock))),
+            rules: Arc::new(Mutex::new(None)),
+            locations,
+            location_receiver: Arc::new(Mutex::new(Some(location_receiver))),
+        }
+    }
+
+    /// Check time based rules and queued locations until the app exits
+    pub async fn run(self, app_handle: AppHandle) {
+        let Some(mut locations) = self.location_receiver.lock().await.take() else {
+            log::warn!("Status scheduler is already running");
+            return;
+        };
+        let mut interval =
+            tokio::time::interval(std::time::Duration::from_secs(TICK_INTERVAL_SECS));
+        loop {
+            let change = tokio::select! {
+                _ = interval.tick() => self.tick().await,
+                Some(location) = locations.recv() => self.on_location(&location).await,
+            };
+            if let Some(change) = change {
+                let _ = app_handle.emit("status-rule-applied", &change);
+            }
+        }
+    }
+
+    /// Queue a location of the current user for `run`, without waiting for the API
+    pub fn notify_location(&self, location: Location) {
+        let _ = self.locations.send(location);
+    }
+
+    /// Reload rules from the store on next use, after they were saved or deleted
+    pub async fn invalidate_rules(&self) {
+        *self.rules.lock().await = None;
+    }
+
+    pub async fn tick(&self) -> Option<StatusRuleChange> {
+        let Some(user) = self.user_store.get_current_user().await else {
+            self.evaluator.lock().await.reset();
+            return None;
+        };
+        let rules = self.load_rules().await?;
+        let change =
+            self.evaluator
+                .lock()
+                .await
+                .due(&rules, user.status, &user.status_description)?;
+
+        self.apply(&user, change).await
+    }
+
+    /// Called with every location of the current user
+    pub async fn on_location(&self, location: &Location) -> Option<StatusRuleChange> {
+        let user = self.user_store.get_current_user().await?;
+        let rules = self.load_rules().await?;
+        let change = self.evaluator.lock().await.on_location(
+            &rules,
+            location,
+            user.status,
+            &user.status_description,
+        )?;
+
+        self.apply(&user, change).await
+    }
+
+    async fn load_rules(&self) -> Option<Vec<StatusRule>> {
+        let mut cached = self.rules.lock().await;
+        if let Some(rules) = cached.as_ref() {
+            return Some(rules.clone());
+        }
+
+        match self.rule_store.get_rules().await {
+            Ok(rules) => {
+                *cached = Some(rules.clone());
+                Some(rules)
+            }
+            Err(e) => {
+                log::warn!("Failed to load status rules: {}", e);
+                None
+            }
+        }
+    }
+
+    async fn apply(
+        &self,
+        current_user: &User,
+        change: StatusRuleChange,
+    ) -> Option<StatusRuleChange> {
+        let request = UpdateStatusRequest {
+            status: change.status,
+            status_description: change.status_description.clone(),
+        };
+        let user = match self.client.update_status(current_user, &request).await {
+            Ok(user) => user,
+            Err(e) => {
+                log::warn!("Status rule '{}' failed: {}", change.rule_name, e);
+                return None;
+            }
+        };
+        log::info!(
+            "Status rule '{}' changed status to {}",
+            change.rule_name,
+            user.status
+        );
+
+        self.user_store.patch_current_user_profile(&user).await;
+        if let Err(e) = self
+            .settings_store
+            .record_status(&user.id, user.status, &user.status_description, None)
+            .await
+        {
+            log::warn!("Failed to record status history: {}", e);
+        }
+
+        let now = self.evaluator.lock().await.now().with_timezone(&Utc);
+        if let Err(e) = self.rule_store.mark_applied(change.rule_id, now).await {
+            log::warn!("{}", e);
+        }
+        // `at_time` rules read `last_applied_at`
+        self.invalidate_rules().await;
+
+        Some(change)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::StatusRuleInput;
+    use crate::store::db::memory_db;
+    use crate::vrchat_api::VRChatClientConfig;
+    use chrono::{SecondsFormat, TimeZone};
+
+    struct FakeClock {
+        now: std::sync::Mutex<DateTime<FixedOffset>>,
+    }
+
+    impl FakeClock {
+        fn at(hour: u32, minute: u32) -> Arc<Self> {
+            let now = FixedOffset::east_opt(9 * 3600)
+                .unwrap()
+                .with_ymd_and_hms(2026, 3, 1, hour, minute, 0)
+                .unwrap();
+            Arc::new(Self {
+                now: std::sync::Mutex::new(now),
+            })
+        }
+
+        fn advance(&self, minutes: i64) {
+            *self.now.lock().unwrap() += Duration::minutes(minutes);
+        }
+    }
+
+    impl Clock for FakeClock {
+        fn now(&self) -> DateTime<FixedOffset> {
+            *self.now.lock().unwrap()
+        }
+    }
+
+    fn rule(id: i32, kind: StatusRuleKind, status: UserStatus) -> StatusRule {
+        StatusRule {
+            id,
+            name: format!("rule {}", id),
+            enabled: true,
+            kind,
+            status,
+            status_description: None,
+            time: None,
+            from_status: None,
+            minutes: None,
+            last_applied_at: None,
+        }
+    }
+
+    #[test]
+    fn test_at_time_fires_once_within_grace_period() {
+        let clock = FakeClock::at(20, 59);
+        let mut evaluator = StatusRuleEvaluator::new(clock.clone());
+        let mut busy = rule(1, StatusRuleKind::AtTime, UserStatus::Busy);
+        busy.time = Some("21:00".to_string());
+        busy.status_description = Some("Dinner".to_string());
+
+        assert_eq!(evaluator.due(&[busy.clone()], UserStatus::Active, ""), None);
+
+        clock.advance(1);
+        let change = evaluator
+            .due(&[busy.clone()], UserStatus::Active, "")
+            .unwrap();
+        assert_eq!(change.status, UserStatus::Busy);
+        assert_eq!(change.status_description, "Dinner");
+
+        // The scheduler marks the rule once the change went through
+        busy.last_applied_at = Some(
+            clock
+                .now()
+                .with_timezone(&Utc)
+                .to_rfc3339_opts(SecondsFormat::Millis, true),
+        );
+        clock.advance(1);
+        assert_eq!(evaluator.due(&[busy.clone()], UserStatus::Active, ""), None);
+
+        // Missed by more than the grace period, e.g. the app was closed
+        let mut late = busy.clone();
+        late.last_applied_at = None;
+        clock.advance(AT_TIME_GRACE_MINUTES);
+        assert_eq!(evaluator.due(&[late], UserStatus::Active, ""), None);
+
+        // 21:00 the next day
+        clock.advance(24 * 60 - 6);
+        assert!(evaluator.due(&[busy], UserStatus::Active, "").is_some());
+    }
+
+    #[test]
+    fn test_revert_after_counts_from_status_change() {
+        let clock = FakeClock::at(12, 0);
+        let mut evaluator = StatusRuleEvaluator::new(clock.clone());
+        let mut revert = rule(1, StatusRuleKind::RevertAfter, UserStatus::Active);
+        revert.from_status = Some(UserStatus::Busy);
+        revert.minutes = Some(30);
+        let rules = [revert];
+
+        assert_eq!(evaluator.due(&rules, UserStatus::Busy, "Focus"), None);
+        clock.advance(20);
+        assert_eq!(evaluator.due(&rules, UserStatus::Busy, "Focus"), None);
+
+        // Going active and busy again restarts the timer
+        assert_eq!(evaluator.due(&rules, UserStatus::Active, "Focus"), None);
+        assert_eq!(evaluator.due(&rules, UserStatus::Busy, "Focus"), None);
+        clock.advance(29);
+        assert_eq!(evaluator.due(&rules, UserStatus::Busy, "Focus"), None);
+
+        clock.advance(1);
+        let change = evaluator.due(&rules, UserStatus::Busy, "Focus").unwrap();
+        assert_eq!(change.status, UserStatus::Active);
+        assert_eq!(change.status_description, "Focus");
+
+        let mut disabled = rules[0].clone();
+        disabled.enabled = false;
+        assert_eq!(evaluator.due(&[disabled], UserStatus::Busy, "Focus"), None);
+    }
+
+    #[test]
+    fn test_private_instance_sets_and_restores_status() {
+        let mut evaluator = StatusRuleEvaluator::new(FakeClock::at(12, 0));
+        let rules = [rule(1, StatusRuleKind::PrivateInstance, UserStatus::AskMe)];
+        let invite = Location::parse("wrld_abc:1~private(usr_me)~region(jp)");
+        let invite_plus = Location::parse("wrld_abc:2~private(usr_me)~canRequestInvite");
+        let public = Location::parse("wrld_abc:3~region(jp)");
+
+        let change = evaluator
+            .on_location(&rules, &invite, UserStatus::JoinMe, "Hi")
+            .unwrap();
+        assert_eq!(change.status, UserStatus::AskMe);
+        assert_eq!(change.status_description, "Hi");
+
+        // Still private while traveling or hopping between invite instances
+        let inside = [Location::Traveling, invite_plus];
+        for location in &inside {
+            assert_eq!(
+                evaluator.on_location(&rules, location, UserStatus::AskMe, "Hi"),
+                None
+            );
+        }
+
+        let restore = evaluator
+            .on_location(&rules, &public, UserStatus::AskMe, "Hi")
+            .unwrap();
+        assert_eq!(restore.status, UserStatus::JoinMe);
+        assert_eq!(
+            evaluator.on_location(&rules, &public, UserStatus::JoinMe, "Hi"),
+            None
+        );
+
+        // A status picked by hand inside the instance is kept
+        assert!(
+            evaluator
+                .on_location(&rules, &invite, UserStatus::Active, "")
+                .is_some()
+        );
+        assert_eq!(
+            evaluator.on_location(&rules, &Location::Offline, UserStatus::Busy, ""),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rules_are_cached_until_invalidated() {
+        let rule_store = StatusRuleStore::with_connection(memory_db().await)
+            .await
+            .unwrap();
+        let scheduler = StatusScheduler::with_clock(
+            VRChatClient::new(VRChatClientConfig::default()).unwrap(),
+            UserStore::new(),
+            rule_store.clone(),
+            SettingsStore::with_connection(memory_db().await)
+                .await
+                .unwrap(),
+            FakeClock::at(12, 0),
+        );
+        assert!(scheduler.load_rules().await.unwrap().is_empty());
+
+        let input = StatusRuleInput {
+            id: None,
+            name: "Private".to_string(),
+            enabled: true,
+            kind: StatusRuleKind::PrivateInstance,
+            status: UserStatus::AskMe,
+            status_description: None,
+            time: None,
+            from_status: None,
+            minutes: None,
+        };
+        rule_store.save_rule(input).await.unwrap();
+        assert!(scheduler.load_rules().await.unwrap().is_empty());
+
+        scheduler.invalidate_rules().await;
+        assert_eq!(scheduler.load_rules().await.unwrap().len(), 1);
+    }
+}
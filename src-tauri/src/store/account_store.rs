@@ -18,6 +18,9 @@ pub struct StoredAccount {
     pub auth_cookie: Option<String>,
     pub two_factor_cookie: Option<String>,
     pub last_login: String,
+    /// Set when VRChat rejected the stored session and the user has to sign in again
+    #[serde(default)]
+    pub needs_relogin: bool,
 }
 
 mod account_entity {
@@ -38,6 +41,8 @@ mod account_entity {
         pub last_login: String,
         #[sea_orm(column_type = "Boolean", default_value = 0)]
         pub last_active: bool,
+        #[sea_orm(column_type = "Boolean", default_value = 0)]
+        pub needs_relogin: bool,
     }
 
     #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -80,6 +85,14 @@ impl AccountStore {
             .await
             .map_err(|e| format!("Failed to initialize accounts table: {}", e))?;
 
+        crate::store::ensure_column(
+            &self.db,
+            "accounts",
+            "needs_relogin",
+            "BOOLEAN NOT NULL DEFAULT 0",
+        )
+        .await?;
+
         Ok(())
     }
 
@@ -111,6 +124,7 @@ impl AccountStore {
                         AccountColumn::TwoFactorCookie,
                         AccountColumn::LastLogin,
                         AccountColumn::LastActive,
+                        AccountColumn::NeedsRelogin,
                     ])
                     .to_owned(),
             )
@@ -224,6 +238,20 @@ impl AccountStore {
             .map_err(|e| format!("Failed to commit account activation: {}", e))
     }
 
+    pub async fn set_needs_relogin(
+        &self,
+        user_id: &str,
+        needs_relogin: bool,
+    ) -> Result<(), String> {
+        AccountEntity::update_many()
+            .col_expr(AccountColumn::NeedsRelogin, Expr::value(needs_relogin))
+            .filter(AccountColumn::UserId.eq(user_id))
+            .exec(&self.db)
+            .await
+            .map_err(|e| format!("Failed to update re-login flag: {}", e))?;
+        Ok(())
+    }
+
     pub async fn clear_all_accounts(&self) -> Result<(), String> {
         AccountEntity::delete_many()
             .exec(&self.db)
@@ -253,6 +281,7 @@ fn to_active_model(account: StoredAccount) -> AccountActiveModel {
         two_factor_cookie: Set(account.two_factor_cookie),
         last_login: Set(account.last_login),
         last_active: Set(false),
+        needs_relogin: Set(account.needs_relogin),
     }
 }
 
@@ -267,6 +296,7 @@ impl From<AccountModel> for StoredAccount {
             auth_cookie: model.auth_cookie,
             two_factor_cookie: model.two_factor_cookie,
             last_login: model.last_login,
+            needs_relogin: model.needs_relogin,
         }
     }
 }
@@ -0,0 +1,322 @@
+use chrono::{SecondsFormat, Utc};
+use sea_orm::{
+    ActiveValue::{NotSet, Set},
+    ConnectionTrait, DatabaseConnection, EntityTrait, QueryOrder, Schema, Statement,
+};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Pipeline event a friend alert reacts to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertTrigger {
+    FriendOnline,
+    /// A friend moved into a public or group public instance
+    JoinedPublicInstance,
+    AvatarChanged,
+}
+
+impl AlertTrigger {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::FriendOnline => "friend_online",
+            Self::JoinedPublicInstance => "joined_public_instance",
+            Self::AvatarChanged => "avatar_changed",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "friend_online" => Some(Self::FriendOnline),
+            "joined_public_instance" => Some(Self::JoinedPublicInstance),
+            "avatar_changed" => Some(Self::AvatarChanged),
+            _ => None,
+        }
+    }
+}
+
+/// What happens when a rule matches
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AlertAction {
+    /// Emit `friend-alert` to the frontend
+    Event,
+    /// Ask the frontend to show an OS notification via `friend-alert-notification`
+    Notification,
+    /// POST the alert as JSON
+    Webhook { url: String },
+    /// Ask the frontend to play the sound registered under `key` via `friend-alert-sound`
+    Sound { key: String },
+}
+
+impl AlertAction {
+    fn validate(&self) -> Result<(), String> {
+        match self {
+            Self::Webhook { url } => match url::Url::parse(url) {
+                Ok(parsed) if matches!(parsed.scheme(), "http" | "https") => Ok(()),
+                _ => Err(format!("Invalid webhook URL '{}'", url)),
+            },
+            Self::Sound { key } if key.trim().is_empty() => {
+                Err("Sound key must not be empty".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// A stored friend alert
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+pub struct AlertRule {
+    pub id: i32,
+    pub name: String,
+    pub enabled: bool,
+    pub trigger: AlertTrigger,
+    /// Only this friend, any friend when `None`
+    pub user_id: Option<String>,
+    /// Only friends in this friend favorite group (its `name`, e.g. `group_0`)
+    pub favorite_group: Option<String>,
+    pub actions: Vec<AlertAction>,
+    /// RFC 3339 timestamp in UTC
+    pub created_at: String,
+}
+
+/// Rule fields set by the user; `id` updates an existing rule instead of adding one
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct AlertRuleInput {
+    pub id: Option<i32>,
+    pub name: String,
+    pub enabled: bool,
+    pub trigger: AlertTrigger,
+    pub user_id: Option<String>,
+    pub favorite_group: Option<String>,
+    pub actions: Vec<AlertAction>,
+}
+
+impl AlertRuleInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("Rule name must not be empty".to_string());
+        }
+        if self.actions.is_empty() {
+            return Err("At least one action is required".to_string());
+        }
+        self.actions.iter().try_for_each(AlertAction::validate)
+    }
+}
+
+mod rule_entity {
+    use sea_orm::ActiveModelBehavior;
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "friend_alert_rules")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub name: String,
+        pub enabled: bool,
+        pub trigger: String,
+        pub user_id: Option<String>,
+        pub favorite_group: Option<String>,
+        /// `AlertAction` list as JSON
+        pub actions: String,
+        pub created_at: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+use rule_entity::{
+    ActiveModel as RuleActiveModel, Column as RuleColumn, Entity as RuleEntity, Model as RuleModel,
+};
+
+impl From<RuleModel> for AlertRule {
+    fn from(model: RuleModel) -> Self {
+        Self {
+            id: model.id,
+            name: model.name,
+            enabled: model.enabled,
+            trigger: AlertTrigger::parse(&model.trigger).unwrap_or(AlertTrigger::FriendOnline),
+            user_id: model.user_id,
+            favorite_group: model.favorite_group,
+            actions: serde_json::from_str(&model.actions).unwrap_or_default(),
+            created_at: model.created_at,
+        }
+    }
+}
+
+/// Rules of the friend alert engine
+#[derive(Clone)]
+pub struct AlertRuleStore {
+    db: DatabaseConnection,
+}
+
+impl AlertRuleStore {
+    pub async fn new() -> Result<Self, String> {
+        let db = crate::store::connect_db("friend alerts").await?;
+        Self::with_connection(db).await
+    }
+
+    pub async fn with_connection(db: DatabaseConnection) -> Result<Self, String> {
+        let store = Self { db };
+        store.init_schema().await?;
+
+        Ok(store)
+    }
+
+    async fn init_schema(&self) -> Result<(), String> {
+        let backend = self.db.get_database_backend();
+        let schema = Schema::new(backend);
+
+        let create_table = schema
+            .create_table_from_entity(RuleEntity)
+            .if_not_exists()
+            .to_owned();
+
+        let statement: Statement = backend.build(&create_table);
+        self.db
+            .execute(statement)
+            .await
+            .map_err(|e| format!("Failed to initialize friend alert table: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Oldest first
+    pub async fn get_rules(&self) -> Result<Vec<AlertRule>, String> {
+        let rows = RuleEntity::find()
+            .order_by_asc(RuleColumn::Id)
+            .all(&self.db)
+            .await
+            .map_err(|e| format!("Failed to load friend alerts: {}", e))?;
+
+        Ok(rows.into_iter().map(AlertRule::from).collect())
+    }
+
+    pub async fn get_rule(&self, id: i32) -> Result<Option<AlertRule>, String> {
+        let row = RuleEntity::find_by_id(id)
+            .one(&self.db)
+            .await
+            .map_err(|e| format!("Failed to load friend alert {}: {}", id, e))?;
+
+        Ok(row.map(AlertRule::from))
+    }
+
+    pub async fn save_rule(&self, input: AlertRuleInput) -> Result<AlertRule, String> {
+        input.validate()?;
+
+        let actions = serde_json::to_string(&input.actions)
+            .map_err(|e| format!("Failed to encode alert actions: {}", e))?;
+        let mut active_model = RuleActiveModel {
+            id: NotSet,
+            name: Set(input.name.trim().to_string()),
+            enabled: Set(input.enabled),
+            trigger: Set(input.trigger.as_str().to_string()),
+            user_id: Set(input.user_id.filter(|id| !id.is_empty())),
+            favorite_group: Set(input.favorite_group.filter(|group| !group.is_empty())),
+            actions: Set(actions),
+            created_at: NotSet,
+        };
+
+        let model = match input.id {
+            Some(id) => {
+                active_model.id = Set(id);
+                RuleEntity::update(active_model)
+                    .exec(&self.db)
+                    .await
+                    .map_err(|e| format!("Failed to save friend alert {}: {}", id, e))?
+            }
+            None => {
+                active_model.created_at =
+                    Set(Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true));
+                let id = RuleEntity::insert(active_model)
+                    .exec(&self.db)
+                    .await
+                    .map_err(|e| format!("Failed to save friend alert: {}", e))?
+                    .last_insert_id;
+                RuleEntity::find_by_id(id)
+                    .one(&self.db)
+                    .await
+                    .map_err(|e| format!("Failed to load friend alert {}: {}", id, e))?
+                    .ok_or_else(|| format!("Friend alert {} does not exist", id))?
+            }
+        };
+
+        Ok(AlertRule::from(model))
+    }
+
+    /// Returns whether a rule was removed
+    pub async fn delete_rule(&self, id: i32) -> Result<bool, String> {
+        let result = RuleEntity::delete_by_id(id)
+            .exec(&self.db)
+            .await
+            .map_err(|e| format!("Failed to delete friend alert {}: {}", id, e))?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::db::memory_db;
+
+    fn online_alert(actions: Vec<AlertAction>) -> AlertRuleInput {
+        AlertRuleInput {
+            id: None,
+            name: "Best friend online".to_string(),
+            enabled: true,
+            trigger: AlertTrigger::FriendOnline,
+            user_id: Some("usr_friend".to_string()),
+            favorite_group: Some(String::new()),
+            actions,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rule_crud_round_trips_actions() {
+        let store = AlertRuleStore::with_connection(memory_db().await)
+            .await
+            .unwrap();
+        let actions = vec![
+            AlertAction::Event,
+            AlertAction::Webhook {
+                url: "https://example.com/hook".to_string(),
+            },
+            AlertAction::Sound {
+                key: "chime".to_string(),
+            },
+        ];
+
+        let rule = store
+            .save_rule(online_alert(actions.clone()))
+            .await
+            .unwrap();
+        assert_eq!(rule.actions, actions);
+        assert_eq!(rule.favorite_group, None);
+
+        assert!(store.save_rule(online_alert(vec![])).await.is_err());
+        let err = store
+            .save_rule(online_alert(vec![AlertAction::Webhook {
+                url: "file:///etc/passwd".to_string(),
+            }]))
+            .await
+            .unwrap_err();
+        assert!(err.contains("webhook URL"));
+
+        let mut update = online_alert(vec![AlertAction::Notification]);
+        update.id = Some(rule.id);
+        update.trigger = AlertTrigger::AvatarChanged;
+        let updated = store.save_rule(update).await.unwrap();
+        assert_eq!(updated.trigger, AlertTrigger::AvatarChanged);
+        assert_eq!(updated.actions, vec![AlertAction::Notification]);
+        assert_eq!(updated.created_at, rule.created_at);
+
+        assert_eq!(store.get_rules().await.unwrap().len(), 1);
+        assert!(store.delete_rule(rule.id).await.unwrap());
+        assert!(store.get_rule(rule.id).await.unwrap().is_none());
+    }
+}
@@ -1,4 +1,5 @@
-use sea_orm::{ConnectOptions, Database, DatabaseConnection};
+use chrono::{DateTime, SecondsFormat, Utc};
+use sea_orm::{ConnectOptions, ConnectionTrait, Database, DatabaseConnection, Statement};
 
 pub async fn connect_db(component: &str) -> Result<DatabaseConnection, String> {
     // Use per-user local data directory (this is %LOCALAPPDATA% on Windows)
@@ -22,3 +23,57 @@ pub async fn connect_db(component: &str) -> Result<DatabaseConnection, String> {
         .await
         .map_err(|e| format!("Failed to connect to {} database: {}", component, e))
 }
+
+/// Stored timestamps are fixed-width UTC strings, so bounds must use the same format
+pub(crate) fn normalize_timestamp(value: &str) -> Result<String, String> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|time| {
+            time.with_timezone(&Utc)
+                .to_rfc3339_opts(SecondsFormat::Millis, true)
+        })
+        .map_err(|e| format!("Invalid timestamp '{}': {}", value, e))
+}
+
+/// Empty in-memory database for store tests
+#[cfg(test)]
+pub(crate) async fn memory_db() -> DatabaseConnection {
+    Database::connect("sqlite::memory:")
+        .await
+        .expect("Failed to open in-memory database")
+}
+
+/// Add a column to an existing table when an older database predates it.
+/// `definition` is the SQL type and constraints, e.g. `BOOLEAN NOT NULL DEFAULT 0`.
+pub async fn ensure_column(
+    db: &DatabaseConnection,
+    table: &str,
+    column: &str,
+    definition: &str,
+) -> Result<(), String> {
+    let backend = db.get_database_backend();
+    let rows = db
+        .query_all(Statement::from_string(
+            backend,
+            format!("PRAGMA table_info(\"{}\")", table),
+        ))
+        .await
+        .map_err(|e| format!("Failed to inspect {} table: {}", table, e))?;
+
+    let exists = rows
+        .iter()
+        .any(|row| row.try_get::<String>("", "name").ok().as_deref() == Some(column));
+
+    if !exists {
+        db.execute(Statement::from_string(
+            backend,
+            format!(
+                "ALTER TABLE \"{}\" ADD COLUMN \"{}\" {}",
+                table, column, definition
+            ),
+        ))
+        .await
+        .map_err(|e| format!("Failed to add {}.{} column: {}", table, column, e))?;
+    }
+
+    Ok(())
+}
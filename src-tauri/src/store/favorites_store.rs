@@ -0,0 +1,274 @@
+use crate::vrchat_api::types::{Favorite, FavoriteGroup, FavoriteType, UpdateFavoriteGroupRequest};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// Which favorite group a user, world or avatar belongs to
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct FavoriteAnnotation {
+    /// ID of the favorited user, world or avatar
+    pub target_id: String,
+    /// Favorite record ID, needed to remove the favorite
+    pub favorite_id: String,
+    pub kind: FavoriteType,
+    pub group_name: Option<String>,
+    pub group_display_name: Option<String>,
+}
+
+#[derive(Default)]
+struct FavoritesData {
+    /// Keyed by favorited object ID
+    favorites: HashMap<String, Favorite>,
+    groups: Vec<FavoriteGroup>,
+    loaded: bool,
+}
+
+/// In-memory mirror of the current user's VRChat favorites and favorite groups
+#[derive(Clone)]
+pub struct FavoritesStore {
+    data: Arc<RwLock<FavoritesData>>,
+}
+
+impl FavoritesStore {
+    pub fn new() -> Self {
+        Self {
+            data: Arc::new(RwLock::new(FavoritesData::default())),
+        }
+    }
+
+    /// Whether favorites have been fetched since the last clear
+    pub async fn is_loaded(&self) -> bool {
+        self.data.read().await.loaded
+    }
+
+    /// Replace the mirror with freshly fetched favorites and groups
+    pub async fn set_all(&self, favorites: Vec<Favorite>, groups: Vec<FavoriteGroup>) {
+        let mut data = self.data.write().await;
+        data.favorites = favorites
+            .into_iter()
+            .map(|f| (f.favorite_id.clone(), f))
+            .collect();
+        data.groups = groups;
+        data.loaded = true;
+
+        log::info!(
+            "FavoritesStore: Cached {} favorites in {} groups",
+            data.favorites.len(),
+            data.groups.len()
+        );
+    }
+
+    pub async fn upsert_favorite(&self, favorite: Favorite) {
+        let mut data = self.data.write().await;
+        data.favorites
+            .insert(favorite.favorite_id.clone(), favorite);
+    }
+
+    /// Remove a favorite by its record ID
+    pub async fn remove_favorite(&self, favorite_id: &str) {
+        let mut data = self.data.write().await;
+        data.favorites.retain(|_, f| f.id != favorite_id);
+    }
+
+    pub async fn get_favorite(&self, target_id: &str) -> Option<Favorite> {
+        self.data.read().await.favorites.get(target_id).cloned()
+    }
+
+    pub async fn favorites(&self, kind: Option<FavoriteType>) -> Vec<Favorite> {
+        let data = self.data.read().await;
+        let mut favorites: Vec<Favorite> = data
+            .favorites
+            .values()
+            .filter(|f| kind.is_none_or(|kind| f.kind == kind))
+            .cloned()
+            .collect();
+
+        favorites.sort_by(|a, b| a.id.cmp(&b.id));
+        favorites
+    }
+
+    pub async fn groups(&self, kind: Option<FavoriteType>) -> Vec<FavoriteGroup> {
+        let data = self.data.read().await;
+        data.groups
+            .iter()
+            .filter(|g| kind.is_none_or(|kind| g.kind == kind))
+            .cloned()
+            .collect()
+    }
+
+    pub async fn get_group(&self, kind: FavoriteType, name: &str) -> Option<FavoriteGroup> {
+        let data = self.data.read().await;
+        data.groups
+            .iter()
+            .find(|g| g.kind == kind && g.name == name)
+            .cloned()
+    }
+
+    /// Apply a successful group update locally
+    pub async fn update_group(
+        &self,
+        kind: FavoriteType,
+        name: &str,
+        request: &UpdateFavoriteGroupRequest,
+    ) {
+        let mut data = self.data.write().await;
+        if let Some(group) = data
+            .groups
+            .iter_mut()
+            .find(|g| g.kind == kind && g.name == name)
+        {
+            if let Some(display_name) = &request.display_name {
+                group.display_name = display_name.clone();
+            }
+            if let Some(visibility) = request.visibility {
+                group.visibility = visibility;
+            }
+            if let Some(tags) = &request.tags {
+                group.tags = tags.clone();
+            }
+        }
+    }
+
+    /// Drop every favorite tagged with the group, mirroring a group clear
+    pub async fn clear_group(&self, kind: FavoriteType, name: &str) {
+        let mut data = self.data.write().await;
+        data.favorites
+            .retain(|_, f| f.kind != kind || !f.tags.iter().any(|tag| tag == name));
+    }
+
+    /// Annotations for the favorited entries among `target_ids`
+    pub async fn annotate_ids(
+        &self,
+        kind: FavoriteType,
+        target_ids: &[String],
+    ) -> Vec<FavoriteAnnotation> {
+        let data = self.data.read().await;
+        target_ids
+            .iter()
+            .filter_map(|id| data.favorites.get(id))
+            .filter(|favorite| favorite.kind == kind)
+            .map(|favorite| annotation(&data.groups, favorite))
+            .collect()
+    }
+
+    pub async fn clear(&self) {
+        *self.data.write().await = FavoritesData::default();
+
+        log::info!("FavoritesStore: Cleared all data");
+    }
+}
+
+impl Default for FavoritesStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn annotation(groups: &[FavoriteGroup], favorite: &Favorite) -> FavoriteAnnotation {
+    let group = groups
+        .iter()
+        .find(|g| g.kind == favorite.kind && favorite.tags.contains(&g.name));
+
+    FavoriteAnnotation {
+        target_id: favorite.favorite_id.clone(),
+        favorite_id: favorite.id.clone(),
+        kind: favorite.kind,
+        group_name: group
+            .map(|g| g.name.clone())
+            .or_else(|| favorite.tags.first().cloned()),
+        group_display_name: group.map(|g| g.display_name.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vrchat_api::types::FavoriteGroupVisibility;
+
+    fn favorite(id: &str, kind: FavoriteType, target: &str, group: &str) -> Favorite {
+        Favorite {
+            id: id.to_string(),
+            kind,
+            favorite_id: target.to_string(),
+            tags: vec![group.to_string()],
+        }
+    }
+
+    fn group(kind: FavoriteType, name: &str, display_name: &str) -> FavoriteGroup {
+        FavoriteGroup {
+            id: format!("fvgrp_{}", name),
+            owner_id: "usr_me".to_string(),
+            owner_display_name: None,
+            name: name.to_string(),
+            display_name: display_name.to_string(),
+            kind,
+            visibility: FavoriteGroupVisibility::Private,
+            tags: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_annotations_resolve_group_display_names() {
+        let store = FavoritesStore::new();
+        store
+            .set_all(
+                vec![
+                    favorite("fvrt_1", FavoriteType::World, "wrld_a", "worlds1"),
+                    favorite("fvrt_2", FavoriteType::Friend, "usr_b", "group_0"),
+                ],
+                vec![group(FavoriteType::World, "worlds1", "Chill")],
+            )
+            .await;
+
+        let ids = vec![
+            "wrld_a".to_string(),
+            "wrld_x".to_string(),
+            "usr_b".to_string(),
+        ];
+        let annotations = store.annotate_ids(FavoriteType::World, &ids).await;
+        assert_eq!(annotations.len(), 1);
+        assert_eq!(annotations[0].favorite_id, "fvrt_1");
+        assert_eq!(annotations[0].group_display_name.as_deref(), Some("Chill"));
+
+        store
+            .update_group(
+                FavoriteType::World,
+                "worlds1",
+                &UpdateFavoriteGroupRequest {
+                    display_name: Some("Cozy".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await;
+        let annotations = store.annotate_ids(FavoriteType::World, &ids).await;
+        assert_eq!(annotations[0].group_display_name.as_deref(), Some("Cozy"));
+
+        let friends = store.annotate_ids(FavoriteType::Friend, &ids).await;
+        assert_eq!(friends[0].group_name.as_deref(), Some("group_0"));
+        assert_eq!(friends[0].group_display_name, None);
+    }
+
+    #[tokio::test]
+    async fn test_clear_group_only_drops_its_favorites() {
+        let store = FavoritesStore::new();
+        store
+            .set_all(
+                vec![
+                    favorite("fvrt_1", FavoriteType::Avatar, "avtr_a", "avatars1"),
+                    favorite("fvrt_2", FavoriteType::Avatar, "avtr_b", "avatars2"),
+                ],
+                Vec::new(),
+            )
+            .await;
+
+        store.clear_group(FavoriteType::Avatar, "avatars1").await;
+        assert!(store.get_favorite("avtr_a").await.is_none());
+        assert!(store.get_favorite("avtr_b").await.is_some());
+
+        store.remove_favorite("fvrt_2").await;
+        assert!(store.favorites(None).await.is_empty());
+    }
+}
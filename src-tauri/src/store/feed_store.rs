@@ -0,0 +1,490 @@
+use chrono::{SecondsFormat, Utc};
+use sea_orm::{
+    ActiveValue::{NotSet, Set},
+    ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder, QuerySelect, Schema, Statement,
+};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::store::db::normalize_timestamp;
+use crate::vrchat_api::types::{LimitedUserFriend, Location};
+
+const DEFAULT_PAGE_SIZE: u32 = 50;
+const MAX_PAGE_SIZE: u32 = 500;
+
+/// Kind of friend transition recorded in the feed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum FeedEventType {
+    Online,
+    Offline,
+    Location,
+    Status,
+    Avatar,
+    Bio,
+}
+
+impl FeedEventType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Online => "online",
+            Self::Offline => "offline",
+            Self::Location => "location",
+            Self::Status => "status",
+            Self::Avatar => "avatar",
+            Self::Bio => "bio",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "online" => Some(Self::Online),
+            "offline" => Some(Self::Offline),
+            "location" => Some(Self::Location),
+            "status" => Some(Self::Status),
+            "avatar" => Some(Self::Avatar),
+            "bio" => Some(Self::Bio),
+            _ => None,
+        }
+    }
+}
+
+/// A recorded friend transition
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct FeedEntry {
+    pub id: i32,
+    /// Logged-in account that observed the event
+    pub account_id: String,
+    pub user_id: String,
+    pub display_name: String,
+    pub event_type: FeedEventType,
+    pub previous_value: Option<String>,
+    pub value: Option<String>,
+    /// Extra context, e.g. the status description or the platform
+    pub details: Option<String>,
+    /// RFC 3339 timestamp in UTC
+    pub created_at: String,
+}
+
+/// A transition waiting to be recorded
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeedEntryDraft {
+    pub user_id: String,
+    pub display_name: String,
+    pub event_type: FeedEventType,
+    pub previous_value: Option<String>,
+    pub value: Option<String>,
+    pub details: Option<String>,
+}
+
+impl FeedEntryDraft {
+    pub fn new(user_id: &str, display_name: &str, event_type: FeedEventType) -> Self {
+        Self {
+            user_id: user_id.to_string(),
+            display_name: display_name.to_string(),
+            event_type,
+            previous_value: None,
+            value: None,
+            details: None,
+        }
+    }
+
+    pub fn change(mut self, previous: Option<String>, value: Option<String>) -> Self {
+        self.previous_value = previous;
+        self.value = value;
+        self
+    }
+
+    pub fn details(mut self, details: Option<String>) -> Self {
+        self.details = details;
+        self
+    }
+}
+
+/// Feed filters, all optional. Time bounds are RFC 3339 timestamps.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+pub struct FeedQuery {
+    pub user_id: Option<String>,
+    pub event_types: Option<Vec<FeedEventType>>,
+    pub since: Option<String>,
+    pub until: Option<String>,
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct FeedPage {
+    pub entries: Vec<FeedEntry>,
+    pub total: u32,
+    pub has_more: bool,
+}
+
+mod feed_entity {
+    use sea_orm::ActiveModelBehavior;
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "friend_feed")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        #[sea_orm(indexed)]
+        pub account_id: String,
+        #[sea_orm(indexed)]
+        pub user_id: String,
+        pub display_name: String,
+        pub event_type: String,
+        pub previous_value: Option<String>,
+        pub value: Option<String>,
+        pub details: Option<String>,
+        #[sea_orm(indexed)]
+        pub created_at: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+use feed_entity::{
+    ActiveModel as FeedActiveModel, Column as FeedColumn, Entity as FeedEntity, Model as FeedModel,
+};
+
+/// Persistent history of friend activity received from the pipeline
+#[derive(Clone)]
+pub struct FeedStore {
+    db: DatabaseConnection,
+}
+
+impl FeedStore {
+    pub async fn new() -> Result<Self, String> {
+        let db = crate::store::connect_db("feed").await?;
+        Self::with_connection(db).await
+    }
+
+    pub async fn with_connection(db: DatabaseConnection) -> Result<Self, String> {
+        let store = Self { db };
+        store.init_schema().await?;
+
+        Ok(store)
+    }
+
+    async fn init_schema(&self) -> Result<(), String> {
+        let backend = self.db.get_database_backend();
+        let schema = Schema::new(backend);
+
+        let create_table = schema
+            .create_table_from_entity(FeedEntity)
+            .if_not_exists()
+            .to_owned();
+
+        let statement: Statement = backend.build(&create_table);
+        self.db
+            .execute(statement)
+            .await
+            .map_err(|e| format!("Failed to initialize friend feed table: {}", e))?;
+
+        for mut index in schema.create_index_from_entity(FeedEntity) {
+            let statement = backend.build(index.if_not_exists());
+            self.db
+                .execute(statement)
+                .await
+                .map_err(|e| format!("Failed to create friend feed index: {}", e))?;
+        }
+
+        Ok(())
+    }
+
+    /// Transitions between two snapshots of the same friend (status, avatar, bio)
+    pub fn profile_changes(
+        previous: &LimitedUserFriend,
+        current: &LimitedUserFriend,
+    ) -> Vec<FeedEntryDraft> {
+        let mut changes = Vec::new();
+        let draft =
+            |event_type| FeedEntryDraft::new(&current.id, &current.display_name, event_type);
+
+        if previous.status != current.status
+            || previous.status_description != current.status_description
+        {
+            changes.push(
+                draft(FeedEventType::Status)
+                    .change(
+                        Some(previous.status.to_string()),
+                        Some(current.status.to_string()),
+                    )
+                    .details(Some(current.status_description.clone())),
+            );
+        }
+
+        if previous.current_avatar_image_url != current.current_avatar_image_url {
+            changes.push(
+                draft(FeedEventType::Avatar)
+                    .change(
+                        previous.current_avatar_image_url.clone(),
+                        current.current_avatar_image_url.clone(),
+                    )
+                    .details(current.current_avatar_thumbnail_image_url.clone()),
+            );
+        }
+
+        if previous.bio != current.bio {
+            changes.push(
+                draft(FeedEventType::Bio)
+                    .change(Some(previous.bio.clone()), Some(current.bio.clone())),
+            );
+        }
+
+        changes
+    }
+
+    /// `Online` entry for a `friend-online` event. Repeated events for a friend who is
+    /// already online (pipeline reconnects, web to game) yield nothing.
+    pub fn online_change(
+        previous: Option<&LimitedUserFriend>,
+        current: &LimitedUserFriend,
+        location: Option<&Location>,
+        platform: Option<String>,
+    ) -> Option<FeedEntryDraft> {
+        let was_online = previous
+            .and_then(|friend| friend.location.as_ref())
+            .is_some_and(Location::is_online);
+        if was_online {
+            return None;
+        }
+
+        Some(
+            FeedEntryDraft::new(&current.id, &current.display_name, FeedEventType::Online)
+                .change(None, location.map(Location::to_string))
+                .details(platform),
+        )
+    }
+
+    pub async fn record(
+        &self,
+        account_id: &str,
+        drafts: Vec<FeedEntryDraft>,
+    ) -> Result<(), String> {
+        if drafts.is_empty() {
+            return Ok(());
+        }
+
+        let created_at = Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true);
+        let models = drafts.into_iter().map(|draft| FeedActiveModel {
+            id: NotSet,
+            account_id: Set(account_id.to_string()),
+            user_id: Set(draft.user_id),
+            display_name: Set(draft.display_name),
+            event_type: Set(draft.event_type.as_str().to_string()),
+            previous_value: Set(draft.previous_value),
+            value: Set(draft.value),
+            details: Set(draft.details),
+            created_at: Set(created_at.clone()),
+        });
+
+        FeedEntity::insert_many(models)
+            .exec(&self.db)
+            .await
+            .map_err(|e| format!("Failed to record feed entries: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Newest first page of the feed for an account
+    pub async fn query(&self, account_id: &str, query: FeedQuery) -> Result<FeedPage, String> {
+        let mut select = FeedEntity::find().filter(FeedColumn::AccountId.eq(account_id));
+
+        if let Some(user_id) = query.user_id.filter(|id| !id.is_empty()) {
+            select = select.filter(FeedColumn::UserId.eq(user_id));
+        }
+
+        if let Some(event_types) = query.event_types.filter(|types| !types.is_empty()) {
+            select = select
+                .filter(FeedColumn::EventType.is_in(event_types.iter().map(|kind| kind.as_str())));
+        }
+
+        if let Some(since) = query.since.as_deref() {
+            select = select.filter(FeedColumn::CreatedAt.gte(normalize_timestamp(since)?));
+        }
+
+        if let Some(until) = query.until.as_deref() {
+            select = select.filter(FeedColumn::CreatedAt.lte(normalize_timestamp(until)?));
+        }
+
+        let total = select
+            .clone()
+            .count(&self.db)
+            .await
+            .map_err(|e| format!("Failed to count feed entries: {}", e))?;
+
+        let limit = query
+            .limit
+            .unwrap_or(DEFAULT_PAGE_SIZE)
+            .clamp(1, MAX_PAGE_SIZE);
+        let offset = query.offset.unwrap_or(0);
+
+        let models = select
+            .order_by_desc(FeedColumn::CreatedAt)
+            .order_by_desc(FeedColumn::Id)
+            .offset(offset as u64)
+            .limit(limit as u64)
+            .all(&self.db)
+            .await
+            .map_err(|e| format!("Failed to load feed entries: {}", e))?;
+
+        let entries: Vec<FeedEntry> = models.into_iter().filter_map(to_entry).collect();
+
+        Ok(FeedPage {
+            has_more: (offset as u64) + (entries.len() as u64) < total,
+            total: total as u32,
+            entries,
+        })
+    }
+
+    pub async fn clear(&self, account_id: &str) -> Result<(), String> {
+        FeedEntity::delete_many()
+            .filter(FeedColumn::AccountId.eq(account_id))
+            .exec(&self.db)
+            .await
+            .map_err(|e| format!("Failed to clear friend feed: {}", e))?;
+        Ok(())
+    }
+}
+
+fn to_entry(model: FeedModel) -> Option<FeedEntry> {
+    let event_type = FeedEventType::parse(&model.event_type)?;
+
+    Some(FeedEntry {
+        id: model.id,
+        account_id: model.account_id,
+        user_id: model.user_id,
+        display_name: model.display_name,
+        event_type,
+        previous_value: model.previous_value,
+        value: model.value,
+        details: model.details,
+        created_at: model.created_at,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::db::memory_db;
+    use crate::vrchat_api::types::UserStatus;
+
+    fn friend(status: UserStatus, bio: &str, avatar: &str) -> LimitedUserFriend {
+        serde_json::from_value(serde_json::json!({
+            "id": "usr_friend",
+            "displayName": "Friend",
+            "bio": bio,
+            "currentAvatarImageUrl": avatar,
+            "status": status,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_profile_changes() {
+        let before = friend(UserStatus::Active, "hello", "https://img/a.png");
+        let after = friend(UserStatus::Busy, "hello", "https://img/b.png");
+
+        let changes = FeedStore::profile_changes(&before, &after);
+        let kinds: Vec<_> = changes.iter().map(|c| c.event_type).collect();
+        assert_eq!(kinds, vec![FeedEventType::Status, FeedEventType::Avatar]);
+        assert_eq!(changes[0].previous_value.as_deref(), Some("active"));
+        assert_eq!(changes[0].value.as_deref(), Some("busy"));
+
+        assert!(FeedStore::profile_changes(&after, &after).is_empty());
+    }
+
+    #[test]
+    fn test_online_change_skips_friends_already_online() {
+        let mut friend = friend(UserStatus::Active, "hello", "https://img/a.png");
+        let location = Location::parse("wrld_abc:1~region(jp)");
+
+        let entry = FeedStore::online_change(None, &friend, Some(&location), None).unwrap();
+        assert_eq!(entry.event_type, FeedEventType::Online);
+        assert_eq!(entry.value.as_deref(), Some("wrld_abc:1~region(jp)"));
+
+        friend.location = Some(Location::Offline);
+        assert!(FeedStore::online_change(Some(&friend), &friend, Some(&location), None).is_some());
+
+        friend.location = Some(location.clone());
+        assert!(FeedStore::online_change(Some(&friend), &friend, Some(&location), None).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_query_filters_and_pages() {
+        let store = FeedStore::with_connection(memory_db().await).await.unwrap();
+        let drafts = (0..5)
+            .map(|i| {
+                FeedEntryDraft::new("usr_a", "A", FeedEventType::Location)
+                    .change(None, Some(format!("wrld_{}:1", i)))
+            })
+            .chain([FeedEntryDraft::new("usr_b", "B", FeedEventType::Online)])
+            .collect();
+        store.record("usr_me", drafts).await.unwrap();
+        store
+            .record(
+                "usr_other_account",
+                vec![FeedEntryDraft::new("usr_a", "A", FeedEventType::Offline)],
+            )
+            .await
+            .unwrap();
+
+        let page = store
+            .query(
+                "usr_me",
+                FeedQuery {
+                    user_id: Some("usr_a".to_string()),
+                    limit: Some(2),
+                    ..FeedQuery::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(page.total, 5);
+        assert_eq!(page.entries.len(), 2);
+        assert!(page.has_more);
+        // Newest first
+        assert_eq!(page.entries[0].value.as_deref(), Some("wrld_4:1"));
+
+        let online = store
+            .query(
+                "usr_me",
+                FeedQuery {
+                    event_types: Some(vec![FeedEventType::Online, FeedEventType::Offline]),
+                    ..FeedQuery::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(online.total, 1);
+        assert_eq!(online.entries[0].user_id, "usr_b");
+
+        let future = store
+            .query(
+                "usr_me",
+                FeedQuery {
+                    since: Some("2999-01-01T00:00:00+09:00".to_string()),
+                    ..FeedQuery::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(future.total, 0);
+        assert!(!future.has_more);
+
+        store.clear("usr_me").await.unwrap();
+        let cleared = store.query("usr_me", FeedQuery::default()).await.unwrap();
+        assert_eq!(cleared.total, 0);
+        let other = store
+            .query("usr_other_account", FeedQuery::default())
+            .await
+            .unwrap();
+        assert_eq!(other.total, 1);
+    }
+}
@@ -0,0 +1,413 @@
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, Condition, ConnectionTrait, DatabaseConnection, DbErr,
+    EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Schema, Statement,
+    TransactionTrait, sea_query::OnConflict,
+};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::store::db::normalize_timestamp;
+use crate::vrchat_api::types::GroupAuditLogEntry;
+
+const DEFAULT_PAGE_SIZE: u32 = 50;
+const MAX_PAGE_SIZE: u32 = 500;
+const INSERT_CHUNK_SIZE: usize = 500;
+
+/// A mirrored audit log entry
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct GroupAuditEntry {
+    pub id: String,
+    pub group_id: String,
+    pub actor_id: String,
+    pub actor_display_name: Option<String>,
+    pub target_id: Option<String>,
+    /// e.g. `group.user.ban`
+    pub event_type: String,
+    pub description: String,
+    /// Event-specific details as JSON
+    pub data: Option<String>,
+    /// RFC 3339 timestamp in UTC
+    pub created_at: String,
+}
+
+/// Audit log filters; everything but `group_id` is optional. Time bounds are RFC 3339 timestamps.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+pub struct GroupAuditQuery {
+    pub group_id: String,
+    pub actor_id: Option<String>,
+    pub target_id: Option<String>,
+    /// Exact event types, or prefixes ending in `.` such as `group.member.`
+    pub event_types: Option<Vec<String>>,
+    /// Case-insensitive match against the description
+    pub text: Option<String>,
+    pub since: Option<String>,
+    pub until: Option<String>,
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct GroupAuditPage {
+    pub entries: Vec<GroupAuditEntry>,
+    pub total: u32,
+    pub has_more: bool,
+}
+
+mod audit_entity {
+    use sea_orm::ActiveModelBehavior;
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "group_audit_log")]
+    pub struct Model {
+        #[sea_orm(primary_key, auto_increment = false)]
+        pub id: String,
+        #[sea_orm(indexed)]
+        pub group_id: String,
+        #[sea_orm(indexed)]
+        pub actor_id: String,
+        pub actor_display_name: Option<String>,
+        #[sea_orm(indexed)]
+        pub target_id: Option<String>,
+        #[sea_orm(indexed)]
+        pub event_type: String,
+        pub description: String,
+        pub data: Option<String>,
+        #[sea_orm(indexed)]
+        pub created_at: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+use audit_entity::{
+    ActiveModel as AuditActiveModel, Column as AuditColumn, Entity as AuditEntity,
+    Model as AuditModel,
+};
+
+/// Local mirror of group audit logs, kept beyond the window the API returns
+#[derive(Clone)]
+pub struct GroupAuditStore {
+    db: DatabaseConnection,
+}
+
+impl GroupAuditStore {
+    pub async fn new() -> Result<Self, String> {
+        let db = crate::store::connect_db("group audit log").await?;
+        Self::with_connection(db).await
+    }
+
+    pub async fn with_connection(db: DatabaseConnection) -> Result<Self, String> {
+        let store = Self { db };
+        store.init_schema().await?;
+
+        Ok(store)
+    }
+
+    async fn init_schema(&self) -> Result<(), String> {
+        let backend = self.db.get_database_backend();
+        let schema = Schema::new(backend);
+
+        let create_table = schema
+            .create_table_from_entity(AuditEntity)
+            .if_not_exists()
+            .to_owned();
+
+        let statement: Statement = backend.build(&create_table);
+        self.db
+            .execute(statement)
+            .await
+            .map_err(|e| format!("Failed to initialize group audit log table: {}", e))?;
+
+        for mut index in schema.create_index_from_entity(AuditEntity) {
+            let statement = backend.build(index.if_not_exists());
+            self.db
+                .execute(statement)
+                .await
+                .map_err(|e| format!("Failed to create group audit log index: {}", e))?;
+        }
+
+        Ok(())
+    }
+
+    /// Store entries that are not mirrored yet, returning how many were new.
+    /// Either all entries are stored or none are.
+    pub async fn insert(&self, entries: Vec<GroupAuditLogEntry>) -> Result<u64, String> {
+        if entries.is_empty() {
+            return Ok(0);
+        }
+
+        let models: Vec<AuditActiveModel> = entries
+            .into_iter()
+            .map(|entry| AuditActiveModel {
+                id: Set(entry.id),
+                group_id: Set(entry.group_id),
+                actor_id: Set(entry.actor_id),
+                actor_display_name: Set(entry.actor_display_name),
+                target_id: Set(entry.target_id),
+                event_type: Set(entry.event_type),
+                description: Set(entry.description),
+                data: Set((!entry.data.is_null()).then(|| entry.data.to_string())),
+                created_at: Set(normalize_timestamp(&entry.created_at).unwrap_or(entry.created_at)),
+            })
+            .collect();
+
+        self.db
+            .transaction::<_, u64, DbErr>(|txn| {
+                Box::pin(async move {
+                    let mut added = 0;
+                    // Keeps each statement below SQLite's bound parameter limit
+                    for chunk in models.chunks(INSERT_CHUNK_SIZE) {
+                        added += AuditEntity::insert_many(chunk.to_vec())
+                            .on_conflict(
+                                OnConflict::column(AuditColumn::Id).do_nothing().to_owned(),
+                            )
+                            .exec_without_returning(txn)
+                            .await?;
+                    }
+                    Ok(added)
+                })
+            })
+            .await
+            .map_err(|e| format!("Failed to save group audit log: {}", e))
+    }
+
+    /// Timestamp of the newest mirrored entry, the starting point of the next sync
+    pub async fn latest_timestamp(&self, group_id: &str) -> Result<Option<String>, String> {
+        let latest = AuditEntity::find()
+            .filter(AuditColumn::GroupId.eq(group_id))
+            .order_by_desc(AuditColumn::CreatedAt)
+            .one(&self.db)
+            .await
+            .map_err(|e| format!("Failed to load group audit log: {}", e))?;
+
+        Ok(latest.map(|model| model.created_at))
+    }
+
+    /// Newest first page of a group's mirrored audit log
+    pub async fn query(&self, query: GroupAuditQuery) -> Result<GroupAuditPage, String> {
+        let mut select =
+            AuditEntity::find().filter(AuditColumn::GroupId.eq(query.group_id.as_str()));
+
+        if let Some(actor_id) = query.actor_id.filter(|id| !id.is_empty()) {
+            select = select.filter(AuditColumn::ActorId.eq(actor_id));
+        }
+
+        if let Some(target_id) = query.target_id.filter(|id| !id.is_empty()) {
+            select = select.filter(AuditColumn::TargetId.eq(target_id));
+        }
+
+        if let Some(event_types) = query.event_types.filter(|types| !types.is_empty()) {
+            let mut condition = Condition::any();
+            for event_type in event_types {
+                condition = if event_type.ends_with('.') {
+                    condition.add(AuditColumn::EventType.starts_with(&event_type))
+                } else {
+                    condition.add(AuditColumn::EventType.eq(event_type))
+                };
+            }
+            select = select.filter(condition);
+        }
+
+        if let Some(text) = query.text.filter(|text| !text.is_empty()) {
+            // SQLite LIKE is case-insensitive for ASCII
+            select = select.filter(AuditColumn::Description.contains(&text));
+        }
+
+        if let Some(since) = query.since.as_deref() {
+            select = select.filter(AuditColumn::CreatedAt.gte(normalize_timestamp(since)?));
+        }
+
+        if let Some(until) = query.until.as_deref() {
+            select = select.filter(AuditColumn::CreatedAt.lte(normalize_timestamp(until)?));
+        }
+
+        let total = select
+            .clone()
+            .count(&self.db)
+            .await
+            .map_err(|e| format!("Failed to count group audit log: {}", e))?;
+
+        let limit = query
+            .limit
+            .unwrap_or(DEFAULT_PAGE_SIZE)
+            .clamp(1, MAX_PAGE_SIZE);
+        let offset = query.offset.unwrap_or(0);
+
+        let models = select
+            .order_by_desc(AuditColumn::CreatedAt)
+            .order_by_desc(AuditColumn::Id)
+            .offset(offset as u64)
+            .limit(limit as u64)
+            .all(&self.db)
+            .await
+            .map_err(|e| format!("Failed to load group audit log: {}", e))?;
+
+        let entries: Vec<GroupAuditEntry> = models.into_iter().map(to_entry).collect();
+
+        Ok(GroupAuditPage {
+            has_more: (offset as u64) + (entries.len() as u64) < total,
+            total: total as u32,
+            entries,
+        })
+    }
+
+    pub async fn clear(&self, group_id: &str) -> Result<(), String> {
+        AuditEntity::delete_many()
+            .filter(AuditColumn::GroupId.eq(group_id))
+            .exec(&self.db)
+            .await
+            .map_err(|e| format!("Failed to clear group audit log: {}", e))?;
+        Ok(())
+    }
+}
+
+fn to_entry(model: AuditModel) -> GroupAuditEntry {
+    GroupAuditEntry {
+        id: model.id,
+        group_id: model.group_id,
+        actor_id: model.actor_id,
+        actor_display_name: model.actor_display_name,
+        target_id: model.target_id,
+        event_type: model.event_type,
+        description: model.description,
+        data: model.data,
+        created_at: model.created_at,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::db::memory_db;
+
+    fn entry(
+        id: &str,
+        actor: &str,
+        target: &str,
+        event_type: &str,
+        minute: u32,
+    ) -> GroupAuditLogEntry {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "created_at": format!("2026-03-01T12:{:02}:00.000+09:00", minute),
+            "groupId": "grp_mock",
+            "actorId": actor,
+            "actorDisplayName": actor,
+            "targetId": target,
+            "eventType": event_type,
+            "description": format!("{} did {} to {}", actor, event_type, target),
+            "data": { "minute": minute },
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_insert_skips_mirrored_entries() {
+        let store = GroupAuditStore::with_connection(memory_db().await)
+            .await
+            .unwrap();
+        let first = vec![
+            entry("gaud_1", "usr_mod", "usr_a", "group.user.ban", 1),
+            entry("gaud_2", "usr_mod", "usr_b", "group.member.remove", 2),
+        ];
+        assert_eq!(store.insert(first.clone()).await.unwrap(), 2);
+
+        let overlap = vec![
+            first[1].clone(),
+            entry(
+                "gaud_3",
+                "usr_owner",
+                "usr_a",
+                "group.member.role.assign",
+                3,
+            ),
+        ];
+        assert_eq!(store.insert(overlap).await.unwrap(), 1);
+
+        // Normalized to UTC so range filters compare correctly
+        assert_eq!(
+            store.latest_timestamp("grp_mock").await.unwrap().as_deref(),
+            Some("2026-03-01T03:03:00.000Z")
+        );
+        assert_eq!(store.latest_timestamp("grp_other").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_query_filters_and_pages() {
+        let store = GroupAuditStore::with_connection(memory_db().await)
+            .await
+            .unwrap();
+        store
+            .insert(vec![
+                entry("gaud_1", "usr_mod", "usr_a", "group.user.ban", 1),
+                entry("gaud_2", "usr_mod", "usr_b", "group.member.remove", 2),
+                entry(
+                    "gaud_3",
+                    "usr_owner",
+                    "usr_a",
+                    "group.member.role.assign",
+                    3,
+                ),
+                entry("gaud_4", "usr_mod", "usr_a", "group.user.unban", 4),
+            ])
+            .await
+            .unwrap();
+        let query = |f: fn(&mut GroupAuditQuery)| {
+            let mut query = GroupAuditQuery {
+                group_id: "grp_mock".to_string(),
+                ..GroupAuditQuery::default()
+            };
+            f(&mut query);
+            query
+        };
+
+        let by_actor = store
+            .query(query(|q| {
+                q.actor_id = Some("usr_mod".to_string());
+                q.limit = Some(2);
+            }))
+            .await
+            .unwrap();
+        assert_eq!(by_actor.total, 3);
+        assert!(by_actor.has_more);
+        // Newest first
+        assert_eq!(by_actor.entries[0].id, "gaud_4");
+        assert_eq!(by_actor.entries[0].data.as_deref(), Some(r#"{"minute":4}"#));
+
+        let member_events = store
+            .query(query(|q| {
+                q.target_id = Some("usr_a".to_string());
+                q.event_types = Some(vec!["group.member.".to_string()]);
+            }))
+            .await
+            .unwrap();
+        assert_eq!(member_events.total, 1);
+        assert_eq!(member_events.entries[0].id, "gaud_3");
+
+        let bans = store
+            .query(query(|q| {
+                q.event_types = Some(vec!["group.user.ban".to_string()]);
+                q.text = Some("USR_A".to_string());
+            }))
+            .await
+            .unwrap();
+        assert_eq!(bans.total, 1);
+
+        let window = store
+            .query(query(|q| {
+                q.since = Some("2026-03-01T03:02:00Z".to_string());
+                q.until = Some("2026-03-01T12:03:00+09:00".to_string());
+            }))
+            .await
+            .unwrap();
+        let ids: Vec<_> = window.entries.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids, vec!["gaud_3", "gaud_2"]);
+
+        store.clear("grp_mock").await.unwrap();
+        assert_eq!(store.query(query(|_| {})).await.unwrap().total, 0);
+    }
+}
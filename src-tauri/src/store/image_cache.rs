@@ -1,7 +1,8 @@
 use crate::http_common::{
-    INITIAL_BACKOFF as INITIAL_BACKOFF_MS, MAX_BACKOFF as MAX_BACKOFF_MS, MAX_DOWNLOADS_PER_SECOND,
-    MAX_REQUEST_RETRIES, USER_AGENT_STRING,
+    INITIAL_BACKOFF as INITIAL_BACKOFF_MS, MAX_BACKOFF as MAX_BACKOFF_MS, MAX_REQUEST_RETRIES,
+    USER_AGENT_STRING,
 };
+use crate::rate_limiter::{EndpointClass, RateLimiter};
 use reqwest::{
     Client,
     header::{COOKIE, USER_AGENT},
@@ -10,7 +11,7 @@ use sha2::{Digest, Sha256};
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use std::time::{Duration, Instant};
+use std::time::Duration;
 use tokio::fs;
 use tokio::io::AsyncWriteExt;
 use tokio::sync::Mutex;
@@ -23,51 +24,13 @@ pub struct ImageCacheStore {
     base_dir: PathBuf,
     client: Client,
     in_flight: Arc<Mutex<HashSet<String>>>,
-    rate_limiter: Arc<Mutex<RateLimiter>>,
-}
-
-struct RateLimiter {
-    last_reset: Instant,
-    tokens: u32,
-    max_tokens: u32,
-}
-
-impl RateLimiter {
-    fn new(max_tokens: u32) -> Self {
-        Self {
-            last_reset: Instant::now(),
-            tokens: max_tokens,
-            max_tokens,
-        }
-    }
-
-    async fn acquire(&mut self) {
-        loop {
-            // Refill tokens if duration has passed
-            let now = Instant::now();
-            if now.duration_since(self.last_reset) >= Duration::from_secs(1) {
-                self.tokens = self.max_tokens;
-                self.last_reset = now;
-            }
-
-            // If we have tokens, consume one and return
-            if self.tokens > 0 {
-                self.tokens -= 1;
-                return;
-            }
-
-            // Otherwise, sleep until the next refill
-            let time_until_refill =
-                Duration::from_secs(1).saturating_sub(now.duration_since(self.last_reset));
-            if time_until_refill > Duration::from_millis(0) {
-                sleep(time_until_refill).await;
-            }
-        }
-    }
+    rate_limiter: RateLimiter,
 }
 
 impl ImageCacheStore {
-    pub async fn new() -> Result<Self, String> {
+    /// `rate_limiter` should be the one used by `VRChatClient` so downloads and API calls
+    /// share the same budget and server-requested pauses
+    pub async fn new(rate_limiter: RateLimiter) -> Result<Self, String> {
         // Use per-user local data directory (this is %LOCALAPPDATA% on Windows)
         let base_dir = dirs::data_local_dir()
             .ok_or("Failed to resolve local data directory")?
@@ -90,7 +53,7 @@ impl ImageCacheStore {
             base_dir: cache_dir,
             client,
             in_flight: Arc::new(Mutex::new(HashSet::new())),
-            rate_limiter: Arc::new(Mutex::new(RateLimiter::new(MAX_DOWNLOADS_PER_SECOND))),
+            rate_limiter,
         })
     }
 
@@ -142,10 +105,7 @@ impl ImageCacheStore {
         }
 
         // Rate limiter, wait for a token before acquiring the in-flight lock
-        {
-            let mut limiter = self.rate_limiter.lock().await;
-            limiter.acquire().await;
-        }
+        self.rate_limiter.acquire(EndpointClass::Files).await;
 
         // Deduplicate, if another download is in progress for this URL, wait for it to complete
         {
@@ -247,14 +207,18 @@ impl ImageCacheStore {
         let mut attempt = 0;
         let mut backoff = INITIAL_BACKOFF;
 
+        // Only pass cookies if URL is from VRChat domains to prevent leaking credentials
+        let is_vrchat_domain = url.starts_with("https://api.vrchat.cloud/")
+            || url.starts_with("https://files.vrchat.cloud/")
+            || url.starts_with("https://assets.vrchat.com/")
+            || url.starts_with("https://d348imysud55la.cloudfront.net/");
+
         loop {
-            let mut request = self.client.get(url).header(USER_AGENT, USER_AGENT_STRING);
+            if attempt > 0 {
+                self.rate_limiter.acquire(EndpointClass::Files).await;
+            }
 
-            // Only pass cookies if URL is from VRChat domains to prevent leaking credentials
-            let is_vrchat_domain = url.starts_with("https://api.vrchat.cloud/")
-                || url.starts_with("https://files.vrchat.cloud/")
-                || url.starts_with("https://assets.vrchat.com/")
-                || url.starts_with("https://d348imysud55la.cloudfront.net/");
+            let mut request = self.client.get(url).header(USER_AGENT, USER_AGENT_STRING);
 
             if is_vrchat_domain {
                 if let Some(ref cookies) = auth_cookies {
@@ -286,7 +250,12 @@ impl ImageCacheStore {
                         }
 
                         let wait = Self::retry_after_seconds(&resp).unwrap_or(backoff);
-                        sleep(wait).await;
+                        if status.as_u16() == 429 && is_vrchat_domain {
+                            // VRChat asked us to slow down, pause API calls as well
+                            self.rate_limiter.pause_for(wait);
+                        } else {
+                            sleep(wait).await;
+                        }
                         attempt += 1;
                         backoff = (backoff * 2).min(MAX_BACKOFF);
                         continue;
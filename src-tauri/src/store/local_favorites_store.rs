@@ -0,0 +1,1034 @@
+use chrono::{SecondsFormat, Utc};
+use sea_orm::{
+    ActiveModelTrait,
+    ActiveValue::{NotSet, Set},
+    ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect, Schema, Statement, TransactionError, TransactionTrait,
+    sea_query::{Expr, Index},
+};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::HashMap;
+
+use crate::vrchat_api::types::FavoriteType;
+
+/// What a local collection holds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum LocalFavoriteKind {
+    World,
+    Avatar,
+    User,
+}
+
+impl LocalFavoriteKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::World => "world",
+            Self::Avatar => "avatar",
+            Self::User => "user",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "world" => Some(Self::World),
+            "avatar" => Some(Self::Avatar),
+            "user" | "friend" => Some(Self::User),
+            _ => None,
+        }
+    }
+
+    /// VRChat favorite type an entry is promoted to. Users can only be favorited as friends.
+    pub fn favorite_type(&self) -> FavoriteType {
+        match self {
+            Self::World => FavoriteType::World,
+            Self::Avatar => FavoriteType::Avatar,
+            Self::User => FavoriteType::Friend,
+        }
+    }
+}
+
+/// Import/export file format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum LocalFavoritesFormat {
+    Json,
+    Csv,
+}
+
+/// A named, unlimited collection of worlds, avatars or users
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct LocalCollection {
+    pub id: i32,
+    pub name: String,
+    pub kind: LocalFavoriteKind,
+    pub description: Option<String>,
+    pub entry_count: u32,
+    /// RFC 3339 timestamps in UTC
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct LocalFavoriteEntry {
+    pub id: i32,
+    pub collection_id: i32,
+    /// ID of the world, avatar or user
+    pub target_id: String,
+    /// Display name at the time it was saved
+    pub name: Option<String>,
+    pub notes: Option<String>,
+    pub tags: Vec<String>,
+    /// Favorite record ID once the entry was promoted to a VRChat favorite
+    pub vrchat_favorite_id: Option<String>,
+    pub added_at: String,
+    pub updated_at: String,
+}
+
+/// Entry fields set by the user; adding an existing target updates it instead
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+pub struct LocalFavoriteInput {
+    pub target_id: String,
+    pub name: Option<String>,
+    pub notes: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+pub struct LocalFavoritesImport {
+    pub collections_created: u32,
+    pub entries_added: u32,
+    pub entries_updated: u32,
+}
+
+/// JSON export layout, one object per collection
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CollectionExport {
+    name: String,
+    kind: LocalFavoriteKind,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    entries: Vec<EntryExport>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct EntryExport {
+    target_id: String,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    notes: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default)]
+    added_at: Option<String>,
+}
+
+/// CSV export layout, one row per entry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CsvRow {
+    collection: String,
+    kind: String,
+    target_id: String,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    notes: Option<String>,
+    /// Tags joined with `;`
+    #[serde(default)]
+    tags: Option<String>,
+    #[serde(default)]
+    added_at: Option<String>,
+}
+
+const CSV_TAG_SEPARATOR: char = ';';
+
+mod collection_entity {
+    use sea_orm::ActiveModelBehavior;
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "local_favorite_collections")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub name: String,
+        #[sea_orm(indexed)]
+        pub kind: String,
+        pub description: Option<String>,
+        pub created_at: String,
+        pub updated_at: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+mod entry_entity {
+    use sea_orm::ActiveModelBehavior;
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "local_favorite_entries")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub collection_id: i32,
+        #[sea_orm(indexed)]
+        pub target_id: String,
+        pub name: Option<String>,
+        pub notes: Option<String>,
+        /// JSON array of tags
+        pub tags: String,
+        pub vrchat_favorite_id: Option<String>,
+        pub added_at: String,
+        pub updated_at: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+use collection_entity::{
+    ActiveModel as CollectionActiveModel, Column as CollectionColumn, Entity as CollectionEntity,
+    Model as CollectionModel,
+};
+use entry_entity::{
+    ActiveModel as EntryActiveModel, Column as EntryColumn, Entity as EntryEntity,
+    Model as EntryModel,
+};
+
+/// Local favorites without VRChat's group limits, kept in SQLite
+#[derive(Clone)]
+pub struct LocalFavoritesStore {
+    db: DatabaseConnection,
+}
+
+impl LocalFavoritesStore {
+    pub async fn new() -> Result<Self, String> {
+        let db = crate::store::connect_db("local_favorites").await?;
+        Self::with_connection(db).await
+    }
+
+    pub async fn with_connection(db: DatabaseConnection) -> Result<Self, String> {
+        let store = Self { db };
+        store.init_schema().await?;
+
+        Ok(store)
+    }
+
+    async fn init_schema(&self) -> Result<(), String> {
+        let backend = self.db.get_database_backend();
+        let schema = Schema::new(backend);
+
+        let statements = [
+            schema
+                .create_table_from_entity(CollectionEntity)
+                .if_not_exists()
+                .to_owned(),
+            schema
+                .create_table_from_entity(EntryEntity)
+                .if_not_exists()
+                .to_owned(),
+        ];
+        for create_table in statements {
+            let statement: Statement = backend.build(&create_table);
+            self.db
+                .execute(statement)
+                .await
+                .map_err(|e| format!("Failed to initialize local favorites tables: {}", e))?;
+        }
+
+        // A target is in a collection at most once, which `upsert_entry` relies on
+        let unique_target = Index::create()
+            .name("idx-local_favorite_entries-collection_id-target_id")
+            .table(EntryEntity)
+            .col(EntryColumn::CollectionId)
+            .col(EntryColumn::TargetId)
+            .unique()
+            .to_owned();
+        let indexes = schema
+            .create_index_from_entity(CollectionEntity)
+            .into_iter()
+            .chain(schema.create_index_from_entity(EntryEntity))
+            .chain([unique_target]);
+        for mut index in indexes {
+            let statement = backend.build(index.if_not_exists());
+            self.db
+                .execute(statement)
+                .await
+                .map_err(|e| format!("Failed to create local favorites index: {}", e))?;
+        }
+
+        Ok(())
+    }
+
+    // Collections
+
+    pub async fn list_collections(
+        &self,
+        kind: Option<LocalFavoriteKind>,
+    ) -> Result<Vec<LocalCollection>, String> {
+        let mut select = CollectionEntity::find();
+        if let Some(kind) = kind {
+            select = select.filter(CollectionColumn::Kind.eq(kind.as_str()));
+        }
+
+        let models = select
+            .order_by_asc(CollectionColumn::Name)
+            .all(&self.db)
+            .await
+            .map_err(|e| format!("Failed to load local collections: {}", e))?;
+
+        let counts = self.entry_counts().await?;
+        Ok(models
+            .into_iter()
+            .filter_map(|model| {
+                let count = counts.get(&model.id).copied().unwrap_or(0);
+                to_collection(model, count)
+            })
+            .collect())
+    }
+
+    pub async fn get_collection(&self, id: i32) -> Result<Option<LocalCollection>, String> {
+        let Some(model) = self.find_collection(id).await? else {
+            return Ok(None);
+        };
+        let count = self.entry_count(id).await?;
+
+        Ok(to_collection(model, count))
+    }
+
+    pub async fn create_collection(
+        &self,
+        name: &str,
+        kind: LocalFavoriteKind,
+        description: Option<String>,
+    ) -> Result<LocalCollection, String> {
+        insert_collection(&self.db, name, kind, description).await
+    }
+
+    pub async fn update_collection(
+        &self,
+        id: i32,
+        name: &str,
+        description: Option<String>,
+    ) -> Result<LocalCollection, String> {
+        let model = self
+            .find_collection(id)
+            .await?
+            .ok_or_else(|| format!("Local collection {} not found", id))?;
+        let name = validate_name(name)?;
+        let kind = LocalFavoriteKind::parse(&model.kind).unwrap_or(LocalFavoriteKind::World);
+
+        if let Some(existing) = find_collection_by_name(&self.db, &name, kind).await?
+            && existing.id != id
+        {
+            return Err(format!(
+                "A {} collection named '{}' already exists",
+                kind.as_str(),
+                name
+            ));
+        }
+
+        let mut active: CollectionActiveModel = model.into();
+        active.name = Set(name);
+        active.description = Set(description.filter(|d| !d.trim().is_empty()));
+        active.updated_at = Set(now());
+        let model = active
+            .update(&self.db)
+            .await
+            .map_err(|e| format!("Failed to update local collection: {}", e))?;
+
+        let count = self.entry_count(id).await?;
+        to_collection(model, count).ok_or_else(|| "Failed to read updated collection".to_string())
+    }
+
+    /// Delete a collection together with its entries
+    pub async fn delete_collection(&self, id: i32) -> Result<(), String> {
+        self.db
+            .transaction::<_, _, DbErr>(|txn| {
+                Box::pin(async move {
+                    EntryEntity::delete_many()
+                        .filter(EntryColumn::CollectionId.eq(id))
+                        .exec(txn)
+                        .await?;
+                    CollectionEntity::delete_by_id(id).exec(txn).await?;
+                    Ok(())
+                })
+            })
+            .await
+            .map_err(|e| format!("Failed to delete local collection: {}", e))
+    }
+
+    // Entries
+
+    /// Entries of a collection, oldest first, optionally only those carrying `tag`
+    pub async fn list_entries(
+        &self,
+        collection_id: i32,
+        tag: Option<&str>,
+    ) -> Result<Vec<LocalFavoriteEntry>, String> {
+        let models = EntryEntity::find()
+            .filter(EntryColumn::CollectionId.eq(collection_id))
+            .order_by_asc(EntryColumn::AddedAt)
+            .order_by_asc(EntryColumn::Id)
+            .all(&self.db)
+            .await
+            .map_err(|e| format!("Failed to load local favorites: {}", e))?;
+
+        Ok(models
+            .into_iter()
+            .map(to_entry)
+            .filter(|entry| tag.is_none_or(|tag| entry.tags.iter().any(|t| t == tag)))
+            .collect())
+    }
+
+    pub async fn get_entry(&self, id: i32) -> Result<Option<LocalFavoriteEntry>, String> {
+        let model = EntryEntity::find_by_id(id)
+            .one(&self.db)
+            .await
+            .map_err(|e| format!("Failed to load local favorite: {}", e))?;
+
+        Ok(model.map(to_entry))
+    }
+
+    /// Add an entry, or update name, notes and tags when the target is already in the collection
+    pub async fn add_entry(
+        &self,
+        collection_id: i32,
+        input: LocalFavoriteInput,
+    ) -> Result<LocalFavoriteEntry, String> {
+        self.find_collection(collection_id)
+            .await?
+            .ok_or_else(|| format!("Local collection {} not found", collection_id))?;
+
+        let (entry, _) = upsert_entry(&self.db, collection_id, input, None).await?;
+        touch_collection(&self.db, collection_id).await?;
+
+        Ok(entry)
+    }
+
+    pub async fn update_entry(
+        &self,
+        id: i32,
+        notes: Option<String>,
+        tags: Vec<String>,
+    ) -> Result<LocalFavoriteEntry, String> {
+        let model = EntryEntity::find_by_id(id)
+            .one(&self.db)
+            .await
+            .map_err(|e| format!("Failed to load local favorite: {}", e))?
+            .ok_or_else(|| format!("Local favorite {} not found", id))?;
+
+        let mut active: EntryActiveModel = model.into();
+        active.notes = Set(notes.filter(|n| !n.trim().is_empty()));
+        active.tags = Set(tags_to_column(&tags));
+        active.updated_at = Set(now());
+
+        let model = active
+            .update(&self.db)
+            .await
+            .map_err(|e| format!("Failed to update local favorite: {}", e))?;
+
+        Ok(to_entry(model))
+    }
+
+    pub async fn remove_entry(&self, id: i32) -> Result<(), String> {
+        EntryEntity::delete_by_id(id)
+            .exec(&self.db)
+            .await
+            .map_err(|e| format!("Failed to remove local favorite: {}", e))?;
+        Ok(())
+    }
+
+    /// Remember the VRChat favorite an entry was promoted to
+    pub async fn set_vrchat_favorite(&self, id: i32, favorite_id: &str) -> Result<(), String> {
+        let model = EntryEntity::find_by_id(id)
+            .one(&self.db)
+            .await
+            .map_err(|e| format!("Failed to load local favorite: {}", e))?
+            .ok_or_else(|| format!("Local favorite {} not found", id))?;
+
+        let mut active: EntryActiveModel = model.into();
+        active.vrchat_favorite_id = Set(Some(favorite_id.to_string()));
+        active.updated_at = Set(now());
+        active
+            .update(&self.db)
+            .await
+            .map_err(|e| format!("Failed to update local favorite: {}", e))?;
+
+        Ok(())
+    }
+
+    // Import / Export
+
+    /// Export the given collections, or all of them when `collection_ids` is empty
+    pub async fn export(
+        &self,
+        format: LocalFavoritesFormat,
+        collection_ids: &[i32],
+    ) -> Result<String, String> {
+        let mut collections = self.list_collections(None).await?;
+        if !collection_ids.is_empty() {
+            collections.retain(|c| collection_ids.contains(&c.id));
+        }
+
+        let mut exports = Vec::with_capacity(collections.len());
+        for collection in collections {
+            let entries = self.list_entries(collection.id, None).await?;
+            exports.push(CollectionExport {
+                name: collection.name,
+                kind: collection.kind,
+                description: collection.description,
+                entries: entries
+                    .into_iter()
+                    .map(|entry| EntryExport {
+                        target_id: entry.target_id,
+                        name: entry.name,
+                        notes: entry.notes,
+                        tags: entry.tags,
+                        added_at: Some(entry.added_at),
+                    })
+                    .collect(),
+            });
+        }
+
+        match format {
+            LocalFavoritesFormat::Json => serde_json::to_string_pretty(&exports)
+                .map_err(|e| format!("Failed to serialize local favorites: {}", e)),
+            LocalFavoritesFormat::Csv => write_csv(exports),
+        }
+    }
+
+    /// Merge an export into the store. Collections are matched by name and kind,
+    /// entries by target ID; existing entries get the imported name, notes and tags.
+    pub async fn import(
+        &self,
+        format: LocalFavoritesFormat,
+        data: &str,
+    ) -> Result<LocalFavoritesImport, String> {
+        let collections = match format {
+            LocalFavoritesFormat::Json => serde_json::from_str::<Vec<CollectionExport>>(data)
+                .map_err(|e| format!("Invalid local favorites JSON: {}", e))?,
+            LocalFavoritesFormat::Csv => read_csv(data)?,
+        };
+
+        // All or nothing, so a bad row doesn't leave half an import behind
+        self.db
+            .transaction::<_, _, DbErr>(|txn| {
+                Box::pin(async move {
+                    import_collections(txn, collections)
+                        .await
+                        .map_err(DbErr::Custom)
+                })
+            })
+            .await
+            .map_err(|e| match e {
+                TransactionError::Transaction(DbErr::Custom(message)) => message,
+                e => format!("Failed to import local favorites: {}", e),
+            })
+    }
+
+    // Helpers
+
+    async fn find_collection(&self, id: i32) -> Result<Option<CollectionModel>, String> {
+        CollectionEntity::find_by_id(id)
+            .one(&self.db)
+            .await
+            .map_err(|e| format!("Failed to load local collection: {}", e))
+    }
+
+    async fn entry_count(&self, collection_id: i32) -> Result<u32, String> {
+        let count = EntryEntity::find()
+            .filter(EntryColumn::CollectionId.eq(collection_id))
+            .count(&self.db)
+            .await
+            .map_err(|e| format!("Failed to count local favorites: {}", e))?;
+        Ok(count as u32)
+    }
+
+    /// Entry count of every non-empty collection in one query
+    async fn entry_counts(&self) -> Result<HashMap<i32, u32>, String> {
+        let rows: Vec<(i32, i64)> = EntryEntity::find()
+            .select_only()
+            .column(EntryColumn::CollectionId)
+            .column_as(Expr::col(EntryColumn::Id).count(), "entry_count")
+            .group_by(EntryColumn::CollectionId)
+            .into_tuple()
+            .all(&self.db)
+            .await
+            .map_err(|e| format!("Failed to count local favorites: {}", e))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(collection_id, count)| (collection_id, count as u32))
+            .collect())
+    }
+}
+
+/// Body of `import`, run inside its transaction
+async fn import_collections<C: ConnectionTrait>(
+    db: &C,
+    collections: Vec<CollectionExport>,
+) -> Result<LocalFavoritesImport, String> {
+    let mut summary = LocalFavoritesImport::default();
+    for export in collections {
+        let collection_id = match find_collection_by_name(db, &export.name, export.kind).await? {
+            Some(model) => model.id,
+            None => {
+                summary.collections_created += 1;
+                insert_collection(db, &export.name, export.kind, export.description)
+                    .await?
+                    .id
+            }
+        };
+
+        for entry in export.entries {
+            let input = LocalFavoriteInput {
+                target_id: entry.target_id,
+                name: entry.name,
+                notes: entry.notes,
+                tags: entry.tags,
+            };
+            let (_, created) = upsert_entry(db, collection_id, input, entry.added_at).await?;
+            if created {
+                summary.entries_added += 1;
+            } else {
+                summary.entries_updated += 1;
+            }
+        }
+
+        touch_collection(db, collection_id).await?;
+    }
+
+    Ok(summary)
+}
+
+async fn insert_collection<C: ConnectionTrait>(
+    db: &C,
+    name: &str,
+    kind: LocalFavoriteKind,
+    description: Option<String>,
+) -> Result<LocalCollection, String> {
+    let name = validate_name(name)?;
+    if find_collection_by_name(db, &name, kind).await?.is_some() {
+        return Err(format!(
+            "A {} collection named '{}' already exists",
+            kind.as_str(),
+            name
+        ));
+    }
+
+    let timestamp = now();
+    let model = CollectionActiveModel {
+        id: NotSet,
+        name: Set(name),
+        kind: Set(kind.as_str().to_string()),
+        description: Set(description.filter(|d| !d.trim().is_empty())),
+        created_at: Set(timestamp.clone()),
+        updated_at: Set(timestamp),
+    }
+    .insert(db)
+    .await
+    .map_err(|e| format!("Failed to create local collection: {}", e))?;
+
+    to_collection(model, 0).ok_or_else(|| "Failed to read created collection".to_string())
+}
+
+async fn upsert_entry<C: ConnectionTrait>(
+    db: &C,
+    collection_id: i32,
+    input: LocalFavoriteInput,
+    added_at: Option<String>,
+) -> Result<(LocalFavoriteEntry, bool), String> {
+    let target_id = input.target_id.trim().to_string();
+    if target_id.is_empty() {
+        return Err("Local favorite target ID cannot be empty".to_string());
+    }
+
+    let existing = EntryEntity::find()
+        .filter(EntryColumn::CollectionId.eq(collection_id))
+        .filter(EntryColumn::TargetId.eq(target_id.as_str()))
+        .one(db)
+        .await
+        .map_err(|e| format!("Failed to load local favorite: {}", e))?;
+
+    let timestamp = now();
+    let name = input.name.filter(|n| !n.trim().is_empty());
+    let notes = input.notes.filter(|n| !n.trim().is_empty());
+    let tags = tags_to_column(&input.tags);
+
+    let (result, created) = match existing {
+        Some(model) => {
+            let mut active: EntryActiveModel = model.clone().into();
+            active.name = Set(name.or(model.name));
+            active.notes = Set(notes);
+            active.tags = Set(tags);
+            active.updated_at = Set(timestamp);
+            (active.update(db).await, false)
+        }
+        None => {
+            let model = EntryActiveModel {
+                id: NotSet,
+                collection_id: Set(collection_id),
+                target_id: Set(target_id),
+                name: Set(name),
+                notes: Set(notes),
+                tags: Set(tags),
+                vrchat_favorite_id: Set(None),
+                added_at: Set(added_at.unwrap_or_else(|| timestamp.clone())),
+                updated_at: Set(timestamp),
+            };
+            (model.insert(db).await, true)
+        }
+    };
+
+    let model = result.map_err(|e| format!("Failed to save local favorite: {}", e))?;
+    Ok((to_entry(model), created))
+}
+
+async fn find_collection_by_name<C: ConnectionTrait>(
+    db: &C,
+    name: &str,
+    kind: LocalFavoriteKind,
+) -> Result<Option<CollectionModel>, String> {
+    CollectionEntity::find()
+        .filter(CollectionColumn::Name.eq(name.trim()))
+        .filter(CollectionColumn::Kind.eq(kind.as_str()))
+        .one(db)
+        .await
+        .map_err(|e| format!("Failed to load local collection: {}", e))
+}
+
+async fn touch_collection<C: ConnectionTrait>(db: &C, id: i32) -> Result<(), String> {
+    CollectionEntity::update_many()
+        .col_expr(CollectionColumn::UpdatedAt, now().into())
+        .filter(CollectionColumn::Id.eq(id))
+        .exec(db)
+        .await
+        .map_err(|e| format!("Failed to update local collection: {}", e))?;
+    Ok(())
+}
+fn now() -> String {
+    Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+fn validate_name(name: &str) -> Result<String, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Collection name cannot be empty".to_string());
+    }
+    Ok(name.to_string())
+}
+
+fn clean_tags(tags: &[String]) -> Vec<String> {
+    let mut cleaned: Vec<String> = Vec::new();
+    for tag in tags.iter().map(|t| t.trim()).filter(|t| !t.is_empty()) {
+        if !cleaned.iter().any(|t| t == tag) {
+            cleaned.push(tag.to_string());
+        }
+    }
+    cleaned
+}
+
+fn tags_to_column(tags: &[String]) -> String {
+    serde_json::to_string(&clean_tags(tags)).unwrap_or_else(|_| "[]".to_string())
+}
+
+fn to_collection(model: CollectionModel, entry_count: u32) -> Option<LocalCollection> {
+    let kind = LocalFavoriteKind::parse(&model.kind)?;
+
+    Some(LocalCollection {
+        id: model.id,
+        name: model.name,
+        kind,
+        description: model.description,
+        entry_count,
+        created_at: model.created_at,
+        updated_at: model.updated_at,
+    })
+}
+
+fn to_entry(model: EntryModel) -> LocalFavoriteEntry {
+    LocalFavoriteEntry {
+        id: model.id,
+        collection_id: model.collection_id,
+        target_id: model.target_id,
+        name: model.name,
+        notes: model.notes,
+        tags: serde_json::from_str(&model.tags).unwrap_or_default(),
+        vrchat_favorite_id: model.vrchat_favorite_id,
+        added_at: model.added_at,
+        updated_at: model.updated_at,
+    }
+}
+
+fn write_csv(collections: Vec<CollectionExport>) -> Result<String, String> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    let separator = format!("{} ", CSV_TAG_SEPARATOR);
+
+    for collection in collections {
+        for entry in collection.entries {
+            writer
+                .serialize(CsvRow {
+                    collection: collection.name.clone(),
+                    kind: collection.kind.as_str().to_string(),
+                    target_id: entry.target_id,
+                    name: entry.name,
+                    notes: entry.notes,
+                    tags: Some(entry.tags.join(&separator)).filter(|t| !t.is_empty()),
+                    added_at: entry.added_at,
+                })
+                .map_err(|e| format!("Failed to write local favorites CSV: {}", e))?;
+        }
+    }
+
+    let bytes = writer
+        .into_inner()
+        .map_err(|e| format!("Failed to write local favorites CSV: {}", e))?;
+    String::from_utf8(bytes).map_err(|e| format!("Failed to write local favorites CSV: {}", e))
+}
+
+/// Group CSV rows back into collections, keeping the order they first appear in
+fn read_csv(data: &str) -> Result<Vec<CollectionExport>, String> {
+    let mut reader = csv::Reader::from_reader(data.as_bytes());
+    let mut collections: Vec<CollectionExport> = Vec::new();
+    let mut positions: HashMap<(String, LocalFavoriteKind), usize> = HashMap::new();
+
+    for (index, row) in reader.deserialize::<CsvRow>().enumerate() {
+        // Header is line 1
+        let line = index + 2;
+        let row =
+            row.map_err(|e| format!("Invalid local favorites CSV on line {}: {}", line, e))?;
+        let kind = LocalFavoriteKind::parse(row.kind.trim())
+            .ok_or_else(|| format!("Unknown kind '{}' on line {}", row.kind, line))?;
+
+        let key = (row.collection.trim().to_string(), kind);
+        let position = *positions.entry(key.clone()).or_insert_with(|| {
+            collections.push(CollectionExport {
+                name: key.0.clone(),
+                kind,
+                description: None,
+                entries: Vec::new(),
+            });
+            collections.len() - 1
+        });
+
+        collections[position].entries.push(EntryExport {
+            target_id: row.target_id,
+            name: row.name,
+            notes: row.notes,
+            tags: row
+                .tags
+                .map(|tags| tags.split(CSV_TAG_SEPARATOR).map(String::from).collect())
+                .unwrap_or_default(),
+            added_at: row.added_at.filter(|a| !a.trim().is_empty()),
+        });
+    }
+
+    Ok(collections)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::db::memory_db;
+
+    fn input(target_id: &str, notes: Option<&str>, tags: &[&str]) -> LocalFavoriteInput {
+        LocalFavoriteInput {
+            target_id: target_id.to_string(),
+            name: Some(format!("Name of {}", target_id)),
+            notes: notes.map(String::from),
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_entries_are_upserted_and_filtered_by_tag() {
+        let store = LocalFavoritesStore::with_connection(memory_db().await)
+            .await
+            .unwrap();
+        let collection = store
+            .create_collection("Horror", LocalFavoriteKind::World, None)
+            .await
+            .unwrap();
+        assert!(
+            store
+                .create_collection(" Horror ", LocalFavoriteKind::World, None)
+                .await
+                .is_err()
+        );
+
+        store
+            .add_entry(
+                collection.id,
+                input("wrld_a", Some("scary"), &["quest", " pc ", "quest"]),
+            )
+            .await
+            .unwrap();
+        store
+            .add_entry(collection.id, input("wrld_b", None, &["pc"]))
+            .await
+            .unwrap();
+        let updated = store
+            .add_entry(collection.id, input("wrld_a", Some("very scary"), &["pc"]))
+            .await
+            .unwrap();
+        assert_eq!(updated.notes.as_deref(), Some("very scary"));
+
+        let collection = store.get_collection(collection.id).await.unwrap().unwrap();
+        assert_eq!(collection.entry_count, 2);
+        store
+            .create_collection("Empty", LocalFavoriteKind::World, None)
+            .await
+            .unwrap();
+        let counts: Vec<(String, u32)> = store
+            .list_collections(None)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|c| (c.name, c.entry_count))
+            .collect();
+        assert_eq!(
+            counts,
+            [("Empty".to_string(), 0), ("Horror".to_string(), 2)]
+        );
+
+        let pc = store.list_entries(collection.id, Some("pc")).await.unwrap();
+        assert_eq!(pc.len(), 2);
+        assert!(
+            store
+                .list_entries(collection.id, Some("quest"))
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        store.delete_collection(collection.id).await.unwrap();
+        assert!(store.get_entry(updated.id).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_target_is_unique_per_collection() {
+        let store = LocalFavoritesStore::with_connection(memory_db().await)
+            .await
+            .unwrap();
+        let first = store
+            .create_collection("First", LocalFavoriteKind::World, None)
+            .await
+            .unwrap();
+        let second = store
+            .create_collection("Second", LocalFavoriteKind::World, None)
+            .await
+            .unwrap();
+        store
+            .add_entry(first.id, input("wrld_a", None, &[]))
+            .await
+            .unwrap();
+        store
+            .add_entry(second.id, input("wrld_a", None, &[]))
+            .await
+            .unwrap();
+
+        let duplicate = EntryActiveModel {
+            id: NotSet,
+            collection_id: Set(first.id),
+            target_id: Set("wrld_a".to_string()),
+            name: Set(None),
+            notes: Set(None),
+            tags: Set(tags_to_column(&[])),
+            vrchat_favorite_id: Set(None),
+            added_at: Set(now()),
+            updated_at: Set(now()),
+        };
+        assert!(duplicate.insert(&store.db).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_csv_and_json_round_trip() {
+        let store = LocalFavoritesStore::with_connection(memory_db().await)
+            .await
+            .unwrap();
+        let worlds = store
+            .create_collection("Chill", LocalFavoriteKind::World, Some("comfy".to_string()))
+            .await
+            .unwrap();
+        let avatars = store
+            .create_collection("Chill", LocalFavoriteKind::Avatar, None)
+            .await
+            .unwrap();
+        store
+            .add_entry(
+                worlds.id,
+                input("wrld_a", Some("has, a comma\nand a newline"), &["a", "b"]),
+            )
+            .await
+            .unwrap();
+        store
+            .add_entry(avatars.id, input("avtr_a", None, &[]))
+            .await
+            .unwrap();
+
+        for format in [LocalFavoritesFormat::Csv, LocalFavoritesFormat::Json] {
+            let exported = store.export(format, &[]).await.unwrap();
+
+            let target = LocalFavoritesStore::with_connection(memory_db().await)
+                .await
+                .unwrap();
+            let summary = target.import(format, &exported).await.unwrap();
+            assert_eq!(summary.collections_created, 2);
+            assert_eq!(summary.entries_added, 2);
+
+            let collections = target
+                .list_collections(Some(LocalFavoriteKind::World))
+                .await
+                .unwrap();
+            assert_eq!(collections.len(), 1);
+            let entries = target.list_entries(collections[0].id, None).await.unwrap();
+            assert_eq!(
+                entries[0].notes.as_deref(),
+                Some("has, a comma\nand a newline")
+            );
+            assert_eq!(entries[0].tags, ["a", "b"]);
+
+            let again = target.import(format, &exported).await.unwrap();
+            assert_eq!(again.collections_created, 0);
+            assert_eq!(again.entries_updated, 2);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_failed_import_leaves_nothing_behind() {
+        let store = LocalFavoritesStore::with_connection(memory_db().await)
+            .await
+            .unwrap();
+        let data = serde_json::json!([
+            {
+                "name": "Good",
+                "kind": "world",
+                "entries": [{ "target_id": "wrld_a" }],
+            },
+            {
+                "name": "Bad",
+                "kind": "avatar",
+                "entries": [{ "target_id": "avtr_a" }, { "target_id": "  " }],
+            },
+        ])
+        .to_string();
+
+        let err = store
+            .import(LocalFavoritesFormat::Json, &data)
+            .await
+            .unwrap_err();
+        assert!(err.contains("target ID"), "got {}", err);
+        assert!(store.list_collections(None).await.unwrap().is_empty());
+    }
+}
@@ -1,11 +1,32 @@
 pub mod account_store;
+pub mod alert_rule_store;
+pub mod favorites_store;
+pub mod feed_store;
+pub mod group_audit_store;
 pub mod image_cache;
+pub mod local_favorites_store;
+pub mod moderation_store;
+pub mod notification_store;
 pub mod settings_store;
+pub mod status_rule_store;
 pub mod user_store;
+pub mod world_store;
 pub mod db;
 
 pub use account_store::{AccountStore, StoredAccount};
+pub use alert_rule_store::{AlertAction, AlertRule, AlertRuleInput, AlertRuleStore, AlertTrigger};
+pub use favorites_store::{FavoriteAnnotation, FavoritesStore};
+pub use feed_store::{FeedEntry, FeedEventType, FeedPage, FeedQuery, FeedStore};
+pub use group_audit_store::{GroupAuditEntry, GroupAuditPage, GroupAuditQuery, GroupAuditStore};
 pub use image_cache::ImageCacheStore;
-pub use settings_store::{AppSettings, SettingsStore};
+pub use local_favorites_store::{
+    LocalCollection, LocalFavoriteEntry, LocalFavoriteInput, LocalFavoriteKind,
+    LocalFavoritesFormat, LocalFavoritesImport, LocalFavoritesStore,
+};
+pub use moderation_store::ModerationStore;
+pub use notification_store::{InboxNotification, InboxResponse, NotificationStore};
+pub use settings_store::{AppSettings, SettingsStore, StatusHistoryEntry, StatusPreset};
+pub use status_rule_store::{StatusRule, StatusRuleInput, StatusRuleKind, StatusRuleStore};
 pub use user_store::UserStore;
-pub use db::connect_db;
+pub use world_store::WorldStore;
+pub use db::{connect_db, ensure_column};
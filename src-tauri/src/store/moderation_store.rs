@@ -0,0 +1,188 @@
+use crate::vrchat_api::types::{PlayerModeration, PlayerModerationType, UserModerationState};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// In-memory cache of the current user's player moderations, keyed by target user
+#[derive(Clone)]
+pub struct ModerationStore {
+    moderations: Arc<RwLock<HashMap<String, Vec<PlayerModeration>>>>,
+    loaded: Arc<RwLock<bool>>,
+}
+
+impl ModerationStore {
+    pub fn new() -> Self {
+        Self {
+            moderations: Arc::new(RwLock::new(HashMap::new())),
+            loaded: Arc::new(RwLock::new(false)),
+        }
+    }
+
+    /// Whether the full list has been fetched since the last clear
+    pub async fn is_loaded(&self) -> bool {
+        *self.loaded.read().await
+    }
+
+    /// Replace the cache with a freshly fetched list
+    pub async fn set_all(&self, list: Vec<PlayerModeration>) {
+        let mut moderations = self.moderations.write().await;
+        moderations.clear();
+        for moderation in list {
+            moderations
+                .entry(moderation.target_user_id.clone())
+                .or_default()
+                .push(moderation);
+        }
+        let count = moderations.len();
+        drop(moderations);
+
+        *self.loaded.write().await = true;
+
+        log::info!("ModerationStore: Cached moderations for {} users", count);
+    }
+
+    /// Insert or replace a moderation. Opposite kinds (mute/unmute, hide/show avatar,
+    /// interact on/off) replace each other, as they do on VRChat's side
+    pub async fn upsert(&self, moderation: PlayerModeration) {
+        let opposite = opposite_kind(moderation.kind);
+        let mut moderations = self.moderations.write().await;
+        let entries = moderations
+            .entry(moderation.target_user_id.clone())
+            .or_default();
+
+        entries.retain(|m| m.kind != moderation.kind && Some(m.kind) != opposite);
+        entries.push(moderation);
+    }
+
+    pub async fn remove(&self, user_id: &str, kind: PlayerModerationType) {
+        let mut moderations = self.moderations.write().await;
+        if let Some(entries) = moderations.get_mut(user_id) {
+            entries.retain(|m| m.kind != kind);
+            if entries.is_empty() {
+                moderations.remove(user_id);
+            }
+        }
+    }
+
+    pub async fn get_for_user(&self, user_id: &str) -> UserModerationState {
+        let moderations = self.moderations.read().await;
+        let entries = moderations.get(user_id).cloned().unwrap_or_default();
+
+        UserModerationState::new(user_id, entries)
+    }
+
+    pub async fn is_blocked(&self, user_id: &str) -> bool {
+        self.has(user_id, PlayerModerationType::Block).await
+    }
+
+    pub async fn has(&self, user_id: &str, kind: PlayerModerationType) -> bool {
+        let moderations = self.moderations.read().await;
+        moderations
+            .get(user_id)
+            .map(|entries| entries.iter().any(|m| m.kind == kind))
+            .unwrap_or(false)
+    }
+
+    /// All cached moderations, optionally of one kind, oldest first
+    pub async fn list(&self, kind: Option<PlayerModerationType>) -> Vec<PlayerModeration> {
+        let moderations = self.moderations.read().await;
+        let mut list: Vec<PlayerModeration> = moderations
+            .values()
+            .flatten()
+            .filter(|m| kind.is_none_or(|kind| m.kind == kind))
+            .cloned()
+            .collect();
+
+        list.sort_by(|a, b| a.created.cmp(&b.created).then_with(|| a.id.cmp(&b.id)));
+        list
+    }
+
+    /// Blocked users as pretty-printed JSON
+    pub async fn export_block_list(&self) -> Result<String, String> {
+        let blocked = self.list(Some(PlayerModerationType::Block)).await;
+        serde_json::to_string_pretty(&blocked)
+            .map_err(|e| format!("Failed to serialize block list: {}", e))
+    }
+
+    pub async fn clear(&self) {
+        self.moderations.write().await.clear();
+        *self.loaded.write().await = false;
+
+        log::info!("ModerationStore: Cleared all data");
+    }
+}
+
+impl Default for ModerationStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn opposite_kind(kind: PlayerModerationType) -> Option<PlayerModerationType> {
+    match kind {
+        PlayerModerationType::Mute => Some(PlayerModerationType::Unmute),
+        PlayerModerationType::Unmute => Some(PlayerModerationType::Mute),
+        PlayerModerationType::HideAvatar => Some(PlayerModerationType::ShowAvatar),
+        PlayerModerationType::ShowAvatar => Some(PlayerModerationType::HideAvatar),
+        PlayerModerationType::InteractOn => Some(PlayerModerationType::InteractOff),
+        PlayerModerationType::InteractOff => Some(PlayerModerationType::InteractOn),
+        PlayerModerationType::Block | PlayerModerationType::Unknown => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn moderation(id: &str, target: &str, kind: PlayerModerationType) -> PlayerModeration {
+        PlayerModeration {
+            id: id.to_string(),
+            kind,
+            source_user_id: "usr_me".to_string(),
+            source_display_name: None,
+            target_user_id: target.to_string(),
+            target_display_name: Some(format!("{} name", target)),
+            created: Some(format!("2024-01-01T00:00:0{}Z", &id[id.len() - 1..])),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_opposite_moderations_replace_each_other() {
+        let store = ModerationStore::new();
+        store
+            .set_all(vec![
+                moderation("pmod_1", "usr_a", PlayerModerationType::Mute),
+                moderation("pmod_2", "usr_a", PlayerModerationType::Block),
+            ])
+            .await;
+
+        store
+            .upsert(moderation("pmod_3", "usr_a", PlayerModerationType::Unmute))
+            .await;
+
+        let state = store.get_for_user("usr_a").await;
+        assert!(state.blocked);
+        assert!(!state.muted);
+        assert_eq!(state.moderations.len(), 2);
+
+        store.remove("usr_a", PlayerModerationType::Block).await;
+        assert!(!store.is_blocked("usr_a").await);
+    }
+
+    #[tokio::test]
+    async fn test_export_block_list_only_contains_blocks() {
+        let store = ModerationStore::new();
+        store
+            .set_all(vec![
+                moderation("pmod_2", "usr_b", PlayerModerationType::Block),
+                moderation("pmod_1", "usr_a", PlayerModerationType::Block),
+                moderation("pmod_3", "usr_c", PlayerModerationType::HideAvatar),
+            ])
+            .await;
+
+        let exported: Vec<PlayerModeration> =
+            serde_json::from_str(&store.export_block_list().await.unwrap()).unwrap();
+        let targets: Vec<&str> = exported.iter().map(|m| m.target_user_id.as_str()).collect();
+        assert_eq!(targets, ["usr_a", "usr_b"]);
+    }
+}
@@ -0,0 +1,582 @@
+use chrono::{SecondsFormat, Utc};
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, Schema, Statement,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use specta::Type;
+use std::collections::HashSet;
+
+use crate::websocket::types::{
+    NotificationPayload, NotificationV2Payload, NotificationV2Response, NotificationV2UpdatePayload,
+};
+
+/// An action offered by a v2 notification, e.g. accept or decline
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+pub struct InboxResponse {
+    pub kind: String,
+    pub data: Option<String>,
+    pub icon: Option<String>,
+    pub text: Option<String>,
+}
+
+impl InboxResponse {
+    fn from_v2(response: &NotificationV2Response) -> Option<Self> {
+        Some(Self {
+            kind: response.kind.clone()?,
+            data: response.data.clone(),
+            icon: response.icon.clone(),
+            text: response.text.clone(),
+        })
+    }
+
+    pub fn to_v2(&self) -> NotificationV2Response {
+        NotificationV2Response {
+            kind: Some(self.kind.clone()),
+            data: self.data.clone(),
+            icon: self.icon.clone(),
+            text: self.text.clone(),
+            ..NotificationV2Response::default()
+        }
+    }
+}
+
+/// A notification kept in the local inbox, from either notification API version
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+pub struct InboxNotification {
+    pub id: String,
+    /// Logged-in account that received the notification
+    pub account_id: String,
+    /// 1 for `/auth/user/notifications`, 2 for `/notifications`
+    pub version: i32,
+    pub kind: String,
+    pub category: Option<String>,
+    pub sender_user_id: Option<String>,
+    pub sender_username: Option<String>,
+    pub title: Option<String>,
+    pub message: Option<String>,
+    /// Raw JSON `details` (v1) or `data` (v2)
+    pub details: Option<String>,
+    pub responses: Vec<InboxResponse>,
+    pub image_url: Option<String>,
+    pub link: Option<String>,
+    pub link_text: Option<String>,
+    pub seen: bool,
+    pub hidden: bool,
+    pub expires_at: Option<String>,
+    /// RFC 3339 timestamp
+    pub created_at: String,
+}
+
+impl InboxNotification {
+    pub fn from_v1(account_id: &str, notification: &NotificationPayload) -> Option<Self> {
+        let created_at = notification
+            .created_at
+            .clone()
+            .or_else(|| string_field(&notification.extra, "created_at"));
+
+        Some(Self {
+            id: notification.id.clone()?,
+            account_id: account_id.to_string(),
+            version: 1,
+            kind: notification.kind.clone()?,
+            category: notification.category.clone(),
+            sender_user_id: notification.sender_user_id.clone(),
+            sender_username: notification.sender_username.clone(),
+            title: None,
+            message: notification.message.clone(),
+            details: notification.details.as_ref().and_then(json_text),
+            responses: Vec::new(),
+            image_url: notification.image_url.clone(),
+            link: notification.link.clone(),
+            link_text: notification.link_text.clone(),
+            seen: notification.seen.unwrap_or(false),
+            hidden: false,
+            expires_at: notification.expires_at.clone(),
+            created_at: created_at.unwrap_or_else(now),
+        })
+    }
+
+    pub fn from_v2(account_id: &str, notification: &NotificationV2Payload) -> Self {
+        Self {
+            id: notification.id.clone(),
+            account_id: account_id.to_string(),
+            version: 2,
+            kind: notification.kind.clone(),
+            category: Some(notification.category.clone()),
+            sender_user_id: notification.sender_user_id.clone(),
+            sender_username: notification.sender_username.clone(),
+            title: notification.title.clone(),
+            message: notification.message.clone(),
+            details: notification.extra.get("data").and_then(json_text),
+            responses: notification
+                .responses
+                .iter()
+                .filter_map(InboxResponse::from_v2)
+                .collect(),
+            image_url: notification.image_url.clone(),
+            link: notification.link.clone(),
+            link_text: notification.link_text.clone(),
+            seen: notification.seen.unwrap_or(false),
+            hidden: false,
+            expires_at: notification.expires_at.clone(),
+            created_at: notification.created_at.clone().unwrap_or_else(now),
+        }
+    }
+}
+
+mod notification_entity {
+    use sea_orm::ActiveModelBehavior;
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "notification_inbox")]
+    pub struct Model {
+        #[sea_orm(primary_key, auto_increment = false)]
+        pub account_id: String,
+        #[sea_orm(primary_key, auto_increment = false)]
+        pub id: String,
+        pub version: i32,
+        pub kind: String,
+        pub category: Option<String>,
+        pub sender_user_id: Option<String>,
+        pub sender_username: Option<String>,
+        pub title: Option<String>,
+        pub message: Option<String>,
+        pub details: Option<String>,
+        pub responses: String,
+        pub image_url: Option<String>,
+        pub link: Option<String>,
+        pub link_text: Option<String>,
+        pub seen: bool,
+        pub hidden: bool,
+        pub expires_at: Option<String>,
+        #[sea_orm(indexed)]
+        pub created_at: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+use notification_entity::{
+    ActiveModel as NotificationActiveModel, Column as NotificationColumn,
+    Entity as NotificationEntity, Model as NotificationModel,
+};
+
+/// Persistent notification inbox, filled from the REST API and kept in sync by the pipeline
+#[derive(Clone)]
+pub struct NotificationStore {
+    db: DatabaseConnection,
+}
+
+impl NotificationStore {
+    pub async fn new() -> Result<Self, String> {
+        let db = crate::store::connect_db("notifications").await?;
+        Self::with_connection(db).await
+    }
+
+    pub async fn with_connection(db: DatabaseConnection) -> Result<Self, String> {
+        let store = Self { db };
+        store.init_schema().await?;
+
+        Ok(store)
+    }
+
+    async fn init_schema(&self) -> Result<(), String> {
+        let backend = self.db.get_database_backend();
+        let schema = Schema::new(backend);
+
+        let create_table = schema
+            .create_table_from_entity(NotificationEntity)
+            .if_not_exists()
+            .to_owned();
+
+        let statement: Statement = backend.build(&create_table);
+        self.db
+            .execute(statement)
+            .await
+            .map_err(|e| format!("Failed to initialize notification inbox table: {}", e))?;
+
+        for mut index in schema.create_index_from_entity(NotificationEntity) {
+            let statement = backend.build(index.if_not_exists());
+            self.db
+                .execute(statement)
+                .await
+                .map_err(|e| format!("Failed to create notification inbox index: {}", e))?;
+        }
+
+        Ok(())
+    }
+
+    /// Insert or refresh a notification. A locally hidden notification stays hidden.
+    pub async fn upsert(&self, notification: InboxNotification) -> Result<(), String> {
+        let existing = self
+            .find_model(&notification.account_id, &notification.id)
+            .await?;
+        let hidden = notification.hidden || existing.as_ref().is_some_and(|model| model.hidden);
+        let model = to_active_model(InboxNotification {
+            hidden,
+            ..notification
+        });
+
+        let result = if existing.is_some() {
+            NotificationEntity::update(model)
+                .exec(&self.db)
+                .await
+                .map(|_| ())
+        } else {
+            NotificationEntity::insert(model)
+                .exec(&self.db)
+                .await
+                .map(|_| ())
+        };
+
+        result.map_err(|e| format!("Failed to save notification: {}", e))
+    }
+
+    /// Replace the visible inbox of one API version with a fresh server listing.
+    /// Visible entries the server no longer returns were handled elsewhere and are dropped.
+    pub async fn sync(
+        &self,
+        account_id: &str,
+        version: i32,
+        notifications: Vec<InboxNotification>,
+    ) -> Result<(), String> {
+        let fresh: HashSet<String> = notifications.iter().map(|n| n.id.clone()).collect();
+
+        for notification in notifications {
+            self.upsert(notification).await?;
+        }
+
+        let stale: Vec<String> = self
+            .list(account_id, false)
+            .await?
+            .into_iter()
+            .filter(|n| n.version == version && !fresh.contains(&n.id))
+            .map(|n| n.id)
+            .collect();
+
+        self.remove(account_id, &stale).await
+    }
+
+    /// Newest first
+    pub async fn list(
+        &self,
+        account_id: &str,
+        include_hidden: bool,
+    ) -> Result<Vec<InboxNotification>, String> {
+        let mut select =
+            NotificationEntity::find().filter(NotificationColumn::AccountId.eq(account_id));
+
+        if !include_hidden {
+            select = select.filter(NotificationColumn::Hidden.eq(false));
+        }
+
+        let models = select
+            .order_by_desc(NotificationColumn::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(|e| format!("Failed to load notifications: {}", e))?;
+
+        Ok(models.into_iter().map(to_notification).collect())
+    }
+
+    pub async fn get(
+        &self,
+        account_id: &str,
+        id: &str,
+    ) -> Result<Option<InboxNotification>, String> {
+        Ok(self.find_model(account_id, id).await?.map(to_notification))
+    }
+
+    pub async fn mark_seen(&self, account_id: &str, id: &str) -> Result<(), String> {
+        self.update_flag(account_id, id, NotificationColumn::Seen)
+            .await
+    }
+
+    pub async fn hide(&self, account_id: &str, id: &str) -> Result<(), String> {
+        self.update_flag(account_id, id, NotificationColumn::Hidden)
+            .await
+    }
+
+    pub async fn remove(&self, account_id: &str, ids: &[String]) -> Result<(), String> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        NotificationEntity::delete_many()
+            .filter(NotificationColumn::AccountId.eq(account_id))
+            .filter(NotificationColumn::Id.is_in(ids.iter().map(String::as_str)))
+            .exec(&self.db)
+            .await
+            .map_err(|e| format!("Failed to delete notifications: {}", e))?;
+        Ok(())
+    }
+
+    /// Drop every notification of an account, or only those of one API version
+    pub async fn clear(&self, account_id: &str, version: Option<i32>) -> Result<(), String> {
+        let mut delete =
+            NotificationEntity::delete_many().filter(NotificationColumn::AccountId.eq(account_id));
+
+        if let Some(version) = version {
+            delete = delete.filter(NotificationColumn::Version.eq(version));
+        }
+
+        delete
+            .exec(&self.db)
+            .await
+            .map_err(|e| format!("Failed to clear notifications: {}", e))?;
+        Ok(())
+    }
+
+    /// Apply a `notification-v2-update` patch to a stored notification
+    pub async fn apply_v2_update(
+        &self,
+        account_id: &str,
+        update: &NotificationV2UpdatePayload,
+    ) -> Result<(), String> {
+        let Some(mut notification) = self.get(account_id, &update.id).await? else {
+            return Ok(());
+        };
+
+        for (key, value) in &update.updates {
+            match key.as_str() {
+                "seen" => notification.seen = value.as_bool().unwrap_or(notification.seen),
+                "title" => notification.title = value.as_str().map(str::to_string),
+                "message" => notification.message = value.as_str().map(str::to_string),
+                "imageUrl" => notification.image_url = value.as_str().map(str::to_string),
+                "link" => notification.link = value.as_str().map(str::to_string),
+                "linkText" => notification.link_text = value.as_str().map(str::to_string),
+                "expiresAt" => notification.expires_at = value.as_str().map(str::to_string),
+                "data" => notification.details = json_text(value),
+                "responses" => {
+                    let responses: Vec<NotificationV2Response> =
+                        serde_json::from_value(value.clone()).unwrap_or_default();
+                    notification.responses = responses
+                        .iter()
+                        .filter_map(InboxResponse::from_v2)
+                        .collect();
+                }
+                _ => {}
+            }
+        }
+
+        self.upsert(notification).await
+    }
+
+    async fn find_model(
+        &self,
+        account_id: &str,
+        id: &str,
+    ) -> Result<Option<NotificationModel>, String> {
+        NotificationEntity::find_by_id((account_id.to_string(), id.to_string()))
+            .one(&self.db)
+            .await
+            .map_err(|e| format!("Failed to load notification: {}", e))
+    }
+
+    async fn update_flag(
+        &self,
+        account_id: &str,
+        id: &str,
+        column: NotificationColumn,
+    ) -> Result<(), String> {
+        NotificationEntity::update_many()
+            .col_expr(column, sea_orm::sea_query::Expr::value(true))
+            .filter(NotificationColumn::AccountId.eq(account_id))
+            .filter(NotificationColumn::Id.eq(id))
+            .exec(&self.db)
+            .await
+            .map_err(|e| format!("Failed to update notification: {}", e))?;
+        Ok(())
+    }
+}
+
+fn now() -> String {
+    Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+/// VRChat sometimes sends structured fields as already encoded JSON strings
+fn json_text(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::String(raw) if raw.is_empty() => None,
+        Value::String(raw) => Some(raw.clone()),
+        other => Some(other.to_string()),
+    }
+}
+
+fn string_field(extra: &std::collections::BTreeMap<String, Value>, key: &str) -> Option<String> {
+    extra.get(key).and_then(Value::as_str).map(str::to_string)
+}
+
+fn to_active_model(notification: InboxNotification) -> NotificationActiveModel {
+    NotificationActiveModel {
+        account_id: Set(notification.account_id),
+        id: Set(notification.id),
+        version: Set(notification.version),
+        kind: Set(notification.kind),
+        category: Set(notification.category),
+        sender_user_id: Set(notification.sender_user_id),
+        sender_username: Set(notification.sender_username),
+        title: Set(notification.title),
+        message: Set(notification.message),
+        details: Set(notification.details),
+        responses: Set(serde_json::to_string(&notification.responses).unwrap_or_default()),
+        image_url: Set(notification.image_url),
+        link: Set(notification.link),
+        link_text: Set(notification.link_text),
+        seen: Set(notification.seen),
+        hidden: Set(notification.hidden),
+        expires_at: Set(notification.expires_at),
+        created_at: Set(notification.created_at),
+    }
+}
+
+fn to_notification(model: NotificationModel) -> InboxNotification {
+    InboxNotification {
+        id: model.id,
+        account_id: model.account_id,
+        version: model.version,
+        kind: model.kind,
+        category: model.category,
+        sender_user_id: model.sender_user_id,
+        sender_username: model.sender_username,
+        title: model.title,
+        message: model.message,
+        details: model.details,
+        responses: serde_json::from_str(&model.responses).unwrap_or_default(),
+        image_url: model.image_url,
+        link: model.link,
+        link_text: model.link_text,
+        seen: model.seen,
+        hidden: model.hidden,
+        expires_at: model.expires_at,
+        created_at: model.created_at,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::db::memory_db;
+    use serde_json::json;
+
+    fn v1(id: &str, created_at: &str) -> InboxNotification {
+        let payload: NotificationPayload = serde_json::from_value(json!({
+            "id": id,
+            "type": "friendRequest",
+            "senderUserId": "usr_sender",
+            "message": "",
+            "details": "{}",
+            "seen": false,
+            "created_at": created_at,
+        }))
+        .unwrap();
+        InboxNotification::from_v1("usr_me", &payload).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_updates_keep_inbox_consistent() {
+        let store = NotificationStore::with_connection(memory_db().await)
+            .await
+            .unwrap();
+        store
+            .upsert(v1("not_a", "2024-01-01T00:00:00.000Z"))
+            .await
+            .unwrap();
+        store
+            .upsert(v1("not_b", "2024-01-02T00:00:00.000Z"))
+            .await
+            .unwrap();
+
+        let v2: NotificationV2Payload = serde_json::from_value(json!({
+            "id": "not_v2",
+            "version": 2,
+            "type": "group.invite",
+            "category": "social.group",
+            "isSystem": false,
+            "ignoreDND": false,
+            "receiverUserId": "usr_me",
+            "title": "Join us",
+            "responses": [{ "type": "accept", "data": "grp_1", "text": "Accept" }],
+            "data": { "groupId": "grp_1" },
+            "createdAt": "2024-01-03T00:00:00.000Z",
+        }))
+        .unwrap();
+        store
+            .upsert(InboxNotification::from_v2("usr_me", &v2))
+            .await
+            .unwrap();
+
+        let inbox = store.list("usr_me", false).await.unwrap();
+        let ids: Vec<_> = inbox.iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(ids, vec!["not_v2", "not_b", "not_a"]);
+        assert_eq!(inbox[0].responses[0].kind, "accept");
+        assert_eq!(inbox[0].details.as_deref(), Some(r#"{"groupId":"grp_1"}"#));
+
+        store.mark_seen("usr_me", "not_a").await.unwrap();
+        store.hide("usr_me", "not_b").await.unwrap();
+        let update: NotificationV2UpdatePayload = serde_json::from_value(json!({
+            "id": "not_v2",
+            "version": 2,
+            "updates": { "seen": true, "title": "Updated", "responses": [] },
+        }))
+        .unwrap();
+        store.apply_v2_update("usr_me", &update).await.unwrap();
+
+        let visible = store.list("usr_me", false).await.unwrap();
+        assert_eq!(visible.len(), 2);
+        assert!(visible.iter().all(|n| n.seen));
+        assert_eq!(visible[0].title.as_deref(), Some("Updated"));
+        assert!(visible[0].responses.is_empty());
+
+        // A refresh does not resurrect a hidden notification
+        store
+            .upsert(v1("not_b", "2024-01-02T00:00:00.000Z"))
+            .await
+            .unwrap();
+        assert!(store.get("usr_me", "not_b").await.unwrap().unwrap().hidden);
+
+        store
+            .remove("usr_me", &["not_v2".to_string()])
+            .await
+            .unwrap();
+        store.clear("usr_me", Some(1)).await.unwrap();
+        assert!(store.list("usr_me", true).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_sync_drops_notifications_handled_elsewhere() {
+        let store = NotificationStore::with_connection(memory_db().await)
+            .await
+            .unwrap();
+        store
+            .upsert(v1("not_old", "2024-01-01T00:00:00.000Z"))
+            .await
+            .unwrap();
+        store
+            .upsert(v1("not_hidden", "2024-01-01T00:00:00.000Z"))
+            .await
+            .unwrap();
+        store.hide("usr_me", "not_hidden").await.unwrap();
+
+        store
+            .sync("usr_me", 1, vec![v1("not_new", "2024-01-05T00:00:00.000Z")])
+            .await
+            .unwrap();
+
+        let ids: Vec<_> = store
+            .list("usr_me", true)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|n| n.id)
+            .collect();
+        assert_eq!(ids, vec!["not_new", "not_hidden"]);
+    }
+}
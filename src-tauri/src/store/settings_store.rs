@@ -1,11 +1,18 @@
+use chrono::{SecondsFormat, Utc};
 use sea_orm::sea_query::OnConflict;
 use sea_orm::{
-    ActiveValue::Set, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter,
-    Schema, Statement,
+    ActiveValue::{NotSet, Set},
+    ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder, QuerySelect, Schema, Statement,
 };
 use serde::{Deserialize, Serialize};
 use specta::Type;
 
+use crate::vrchat_api::types::{UpdateUserRequest, UserStatus};
+
+const DEFAULT_HISTORY_LIMIT: u32 = 50;
+const MAX_HISTORY_LIMIT: u32 = 500;
+
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 pub struct AppSettings {
     pub developer_mode: bool,
@@ -19,6 +26,44 @@ impl Default for AppSettings {
     }
 }
 
+/// A named status the user can switch to in one click
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+pub struct StatusPreset {
+    pub name: String,
+    pub status: UserStatus,
+    pub status_description: String,
+}
+
+impl StatusPreset {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("Preset name must not be empty".to_string());
+        }
+        let max = UpdateUserRequest::MAX_STATUS_DESCRIPTION_LENGTH;
+        if self.status_description.chars().count() > max {
+            return Err(format!(
+                "Status description must be at most {} characters",
+                max
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// A status the user had, set from this app or from anywhere else
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct StatusHistoryEntry {
+    pub id: i32,
+    pub account_id: String,
+    /// `None` for entries seeded from VRChat's `statusHistory`, which only keeps descriptions
+    pub status: Option<UserStatus>,
+    pub status_description: String,
+    /// Preset the status was applied from, if any
+    pub preset_name: Option<String>,
+    /// RFC 3339 timestamp in UTC
+    pub created_at: String,
+}
+
 mod settings_entity {
     use sea_orm::ActiveModelBehavior;
     use sea_orm::entity::prelude::*;
@@ -41,6 +86,83 @@ use settings_entity::{
     ActiveModel as SettingsActiveModel, Column as SettingsColumn, Entity as SettingsEntity,
 };
 
+mod status_preset_entity {
+    use sea_orm::ActiveModelBehavior;
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "status_presets")]
+    pub struct Model {
+        #[sea_orm(primary_key, auto_increment = false)]
+        pub name: String,
+        pub status: String,
+        pub status_description: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+use status_preset_entity::{
+    ActiveModel as StatusPresetActiveModel, Column as StatusPresetColumn,
+    Entity as StatusPresetEntity, Model as StatusPresetModel,
+};
+
+mod status_history_entity {
+    use sea_orm::ActiveModelBehavior;
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "status_history")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        #[sea_orm(indexed)]
+        pub account_id: String,
+        pub status: String,
+        pub status_description: String,
+        pub preset_name: Option<String>,
+        #[sea_orm(indexed)]
+        pub created_at: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+use status_history_entity::{
+    ActiveModel as StatusHistoryActiveModel, Column as StatusHistoryColumn,
+    Entity as StatusHistoryEntity, Model as StatusHistoryModel,
+};
+
+impl From<StatusPresetModel> for StatusPreset {
+    fn from(model: StatusPresetModel) -> Self {
+        Self {
+            name: model.name,
+            status: UserStatus::parse(&model.status).unwrap_or_default(),
+            status_description: model.status_description,
+        }
+    }
+}
+
+impl From<StatusHistoryModel> for StatusHistoryEntry {
+    fn from(model: StatusHistoryModel) -> Self {
+        Self {
+            id: model.id,
+            account_id: model.account_id,
+            status: UserStatus::parse(&model.status),
+            status_description: model.status_description,
+            preset_name: model.preset_name,
+            created_at: model.created_at,
+        }
+    }
+}
+
+#[derive(Clone)]
 pub struct SettingsStore {
     db: DatabaseConnection,
 }
@@ -48,6 +170,10 @@ pub struct SettingsStore {
 impl SettingsStore {
     pub async fn new() -> Result<Self, String> {
         let db = crate::store::connect_db("settings").await?;
+        Self::with_connection(db).await
+    }
+
+    pub async fn with_connection(db: DatabaseConnection) -> Result<Self, String> {
         let store = Self { db };
         store.init_schema().await?;
 
@@ -69,6 +195,36 @@ impl SettingsStore {
             .await
             .map_err(|e| format!("Failed to initialize settings table: {}", e))?;
 
+        let create_table = schema
+            .create_table_from_entity(StatusPresetEntity)
+            .if_not_exists()
+            .to_owned();
+
+        let statement: Statement = backend.build(&create_table);
+        self.db
+            .execute(statement)
+            .await
+            .map_err(|e| format!("Failed to initialize status preset table: {}", e))?;
+
+        let create_table = schema
+            .create_table_from_entity(StatusHistoryEntity)
+            .if_not_exists()
+            .to_owned();
+
+        let statement: Statement = backend.build(&create_table);
+        self.db
+            .execute(statement)
+            .await
+            .map_err(|e| format!("Failed to initialize status history table: {}", e))?;
+
+        for mut index in schema.create_index_from_entity(StatusHistoryEntity) {
+            let statement = backend.build(index.if_not_exists());
+            self.db
+                .execute(statement)
+                .await
+                .map_err(|e| format!("Failed to create status history index: {}", e))?;
+        }
+
         Ok(())
     }
 
@@ -132,4 +288,343 @@ impl SettingsStore {
         self.set_setting("developer_mode", if enabled { "true" } else { "false" })
             .await
     }
+
+    // Status Presets
+
+    pub async fn get_status_presets(&self) -> Result<Vec<StatusPreset>, String> {
+        let rows = StatusPresetEntity::find()
+            .order_by_asc(StatusPresetColumn::Name)
+            .all(&self.db)
+            .await
+            .map_err(|e| format!("Failed to load status presets: {}", e))?;
+
+        Ok(rows.into_iter().map(StatusPreset::from).collect())
+    }
+
+    pub async fn get_status_preset(&self, name: &str) -> Result<Option<StatusPreset>, String> {
+        let name = name.trim();
+        let row = StatusPresetEntity::find_by_id(name.to_string())
+            .one(&self.db)
+            .await
+            .map_err(|e| format!("Failed to load status preset '{}': {}", name, e))?;
+
+        Ok(row.map(StatusPreset::from))
+    }
+
+    /// Insert or replace the preset with the same name
+    pub async fn save_status_preset(&self, preset: StatusPreset) -> Result<(), String> {
+        let name = preset.name.trim().to_string();
+        let active_model = StatusPresetActiveModel {
+            name: Set(name.clone()),
+            status: Set(preset.status.to_string()),
+            status_description: Set(preset.status_description),
+        };
+
+        StatusPresetEntity::insert(active_model)
+            .on_conflict(
+                OnConflict::column(StatusPresetColumn::Name)
+                    .update_columns([
+                        StatusPresetColumn::Status,
+                        StatusPresetColumn::StatusDescription,
+                    ])
+                    .to_owned(),
+            )
+            .exec(&self.db)
+            .await
+            .map_err(|e| format!("Failed to save status preset '{}': {}", name, e))?;
+
+        Ok(())
+    }
+
+    /// Returns whether a preset was removed
+    pub async fn delete_status_preset(&self, name: &str) -> Result<bool, String> {
+        let name = name.trim();
+        let result = StatusPresetEntity::delete_by_id(name.to_string())
+            .exec(&self.db)
+            .await
+            .map_err(|e| format!("Failed to delete status preset '{}': {}", name, e))?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    // Status History
+
+    pub async fn record_status(
+        &self,
+        account_id: &str,
+        status: UserStatus,
+        status_description: &str,
+        preset_name: Option<&str>,
+    ) -> Result<(), String> {
+        let active_model = StatusHistoryActiveModel {
+            id: NotSet,
+            account_id: Set(account_id.to_string()),
+            status: Set(status.to_string()),
+            status_description: Set(status_description.to_string()),
+            preset_name: Set(preset_name.map(str::to_string)),
+            created_at: Set(Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true)),
+        };
+
+        StatusHistoryEntity::insert(active_model)
+            .exec(&self.db)
+            .await
+            .map_err(|e| format!("Failed to record status: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Record a status seen outside this app, unless it matches the newest entry.
+    /// Returns whether an entry was added.
+    pub async fn record_status_change(
+        &self,
+        account_id: &str,
+        status: UserStatus,
+        status_description: &str,
+    ) -> Result<bool, String> {
+        let latest = StatusHistoryEntity::find()
+            .filter(StatusHistoryColumn::AccountId.eq(account_id))
+            .order_by_desc(StatusHistoryColumn::CreatedAt)
+            .order_by_desc(StatusHistoryColumn::Id)
+            .one(&self.db)
+            .await
+            .map_err(|e| format!("Failed to load status history: {}", e))?;
+
+        let unchanged = latest.is_some_and(|latest| {
+            latest.status == status.to_string() && latest.status_description == status_description
+        });
+        if unchanged {
+            return Ok(false);
+        }
+
+        self.record_status(account_id, status, status_description, None)
+            .await?;
+        Ok(true)
+    }
+
+    /// Start an account's empty history from VRChat's `statusHistory`, newest first.
+    /// Returns whether anything was seeded.
+    pub async fn seed_status_history(
+        &self,
+        account_id: &str,
+        descriptions: &[String],
+    ) -> Result<bool, String> {
+        if descriptions.is_empty() {
+            return Ok(false);
+        }
+
+        let existing = StatusHistoryEntity::find()
+            .filter(StatusHistoryColumn::AccountId.eq(account_id))
+            .count(&self.db)
+            .await
+            .map_err(|e| format!("Failed to load status history: {}", e))?;
+        if existing > 0 {
+            return Ok(false);
+        }
+
+        // Oldest first, so the newest description gets the highest ID
+        let created_at = Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true);
+        let models = descriptions
+            .iter()
+            .rev()
+            .map(|description| StatusHistoryActiveModel {
+                id: NotSet,
+                account_id: Set(account_id.to_string()),
+                status: Set(String::new()),
+                status_description: Set(description.clone()),
+                preset_name: Set(None),
+                created_at: Set(created_at.clone()),
+            });
+
+        StatusHistoryEntity::insert_many(models)
+            .exec_without_returning(&self.db)
+            .await
+            .map_err(|e| format!("Failed to seed status history: {}", e))?;
+
+        Ok(true)
+    }
+
+    /// Newest first
+    pub async fn get_status_history(
+        &self,
+        account_id: &str,
+        limit: Option<u32>,
+    ) -> Result<Vec<StatusHistoryEntry>, String> {
+        let limit = limit
+            .unwrap_or(DEFAULT_HISTORY_LIMIT)
+            .clamp(1, MAX_HISTORY_LIMIT);
+        let rows = StatusHistoryEntity::find()
+            .filter(StatusHistoryColumn::AccountId.eq(account_id))
+            .order_by_desc(StatusHistoryColumn::CreatedAt)
+            .order_by_desc(StatusHistoryColumn::Id)
+            .limit(limit as u64)
+            .all(&self.db)
+            .await
+            .map_err(|e| format!("Failed to load status history: {}", e))?;
+
+        Ok(rows.into_iter().map(StatusHistoryEntry::from).collect())
+    }
+
+    pub async fn clear_status_history(&self, account_id: &str) -> Result<(), String> {
+        StatusHistoryEntity::delete_many()
+            .filter(StatusHistoryColumn::AccountId.eq(account_id))
+            .exec(&self.db)
+            .await
+            .map_err(|e| format!("Failed to clear status history: {}", e))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::db::memory_db;
+
+    fn preset(name: &str, status: UserStatus, description: &str) -> StatusPreset {
+        StatusPreset {
+            name: name.to_string(),
+            status,
+            status_description: description.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_status_presets_round_trip() {
+        let store = SettingsStore::with_connection(memory_db().await)
+            .await
+            .unwrap();
+        store
+            .save_status_preset(preset("Work", UserStatus::Busy, "In a meeting"))
+            .await
+            .unwrap();
+        store
+            .save_status_preset(preset("Chill", UserStatus::JoinMe, "Come hang out"))
+            .await
+            .unwrap();
+        store
+            .save_status_preset(preset("Work", UserStatus::AskMe, "Working"))
+            .await
+            .unwrap();
+
+        let presets = store.get_status_presets().await.unwrap();
+        let names: Vec<_> = presets.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["Chill", "Work"]);
+        assert_eq!(
+            store.get_status_preset("Work").await.unwrap(),
+            Some(preset("Work", UserStatus::AskMe, "Working"))
+        );
+
+        assert_eq!(
+            store.get_status_preset(" Work ").await.unwrap(),
+            Some(preset("Work", UserStatus::AskMe, "Working"))
+        );
+        assert!(store.delete_status_preset("Work ").await.unwrap());
+        assert!(!store.delete_status_preset("Work").await.unwrap());
+        assert!(store.get_status_preset("Work").await.unwrap().is_none());
+
+        assert!(preset(" ", UserStatus::Active, "").validate().is_err());
+        assert!(
+            preset("Long", UserStatus::Active, &"x".repeat(33))
+                .validate()
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_status_history_is_per_account_and_newest_first() {
+        let store = SettingsStore::with_connection(memory_db().await)
+            .await
+            .unwrap();
+        store
+            .record_status("usr_a", UserStatus::Active, "first", None)
+            .await
+            .unwrap();
+        store
+            .record_status("usr_a", UserStatus::Busy, "second", Some("Work"))
+            .await
+            .unwrap();
+        store
+            .record_status("usr_b", UserStatus::JoinMe, "other", None)
+            .await
+            .unwrap();
+
+        let history = store.get_status_history("usr_a", None).await.unwrap();
+        let descriptions: Vec<_> = history
+            .iter()
+            .map(|e| e.status_description.as_str())
+            .collect();
+        assert_eq!(descriptions, vec!["second", "first"]);
+        assert_eq!(history[0].status, Some(UserStatus::Busy));
+        assert_eq!(history[0].preset_name.as_deref(), Some("Work"));
+
+        store.clear_status_history("usr_a").await.unwrap();
+        assert!(
+            store
+                .get_status_history("usr_a", None)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            store.get_status_history("usr_b", None).await.unwrap().len(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_status_changes_are_recorded_once() {
+        let store = SettingsStore::with_connection(memory_db().await)
+            .await
+            .unwrap();
+        assert!(
+            store
+                .record_status_change("usr_a", UserStatus::Busy, "working")
+                .await
+                .unwrap()
+        );
+        assert!(
+            !store
+                .record_status_change("usr_a", UserStatus::Busy, "working")
+                .await
+                .unwrap()
+        );
+        assert!(
+            store
+                .record_status_change("usr_a", UserStatus::Busy, "lunch")
+                .await
+                .unwrap()
+        );
+
+        let history = store.get_status_history("usr_a", None).await.unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].status_description, "lunch");
+        assert!(history[0].preset_name.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_status_history_is_seeded_only_when_empty() {
+        let store = SettingsStore::with_connection(memory_db().await)
+            .await
+            .unwrap();
+        let descriptions = vec!["newest".to_string(), "older".to_string()];
+        assert!(
+            store
+                .seed_status_history("usr_a", &descriptions)
+                .await
+                .unwrap()
+        );
+        assert!(
+            !store
+                .seed_status_history("usr_a", &descriptions)
+                .await
+                .unwrap()
+        );
+
+        let history = store.get_status_history("usr_a", None).await.unwrap();
+        let seeded: Vec<_> = history
+            .iter()
+            .map(|e| (e.status, e.status_description.as_str()))
+            .collect();
+        assert_eq!(seeded, vec![(None, "newest"), (None, "older")]);
+    }
 }
@@ -0,0 +1,401 @@
+use chrono::{DateTime, NaiveTime, SecondsFormat, Utc};
+use sea_orm::{
+    ActiveValue::{NotSet, Set},
+    ConnectionTrait, DatabaseConnection, EntityTrait, QueryOrder, Schema, Statement,
+};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::vrchat_api::types::{UpdateUserRequest, UserStatus};
+
+/// Longest `revert_after` delay, a day
+pub const MAX_REVERT_MINUTES: u32 = 24 * 60;
+
+/// What makes a status rule fire
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum StatusRuleKind {
+    /// Every day at `time`
+    AtTime,
+    /// `minutes` after the status became `from_status`
+    RevertAfter,
+    /// While the current user is in an invite or invite+ instance
+    PrivateInstance,
+}
+
+impl StatusRuleKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::AtTime => "at_time",
+            Self::RevertAfter => "revert_after",
+            Self::PrivateInstance => "private_instance",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "at_time" => Some(Self::AtTime),
+            "revert_after" => Some(Self::RevertAfter),
+            "private_instance" => Some(Self::PrivateInstance),
+            _ => None,
+        }
+    }
+}
+
+/// A stored automatic status change
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+pub struct StatusRule {
+    pub id: i32,
+    pub name: String,
+    pub enabled: bool,
+    pub kind: StatusRuleKind,
+    /// Status the rule switches to
+    pub status: UserStatus,
+    /// `None` keeps the current description
+    pub status_description: Option<String>,
+    /// Local time of day as `HH:MM`, only for `at_time`
+    pub time: Option<String>,
+    /// Status that is reverted, only for `revert_after`
+    pub from_status: Option<UserStatus>,
+    /// Only for `revert_after`
+    pub minutes: Option<u32>,
+    /// RFC 3339 timestamp in UTC of the last change this rule made
+    pub last_applied_at: Option<String>,
+}
+
+impl StatusRule {
+    /// `time` of an `at_time` rule
+    pub fn time_of_day(&self) -> Option<NaiveTime> {
+        self.time.as_deref().and_then(parse_time_of_day)
+    }
+}
+
+/// Rule fields set by the user; `id` updates an existing rule instead of adding one
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct StatusRuleInput {
+    pub id: Option<i32>,
+    pub name: String,
+    pub enabled: bool,
+    pub kind: StatusRuleKind,
+    pub status: UserStatus,
+    pub status_description: Option<String>,
+    pub time: Option<String>,
+    pub from_status: Option<UserStatus>,
+    pub minutes: Option<u32>,
+}
+
+impl StatusRuleInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("Rule name must not be empty".to_string());
+        }
+
+        let max = UpdateUserRequest::MAX_STATUS_DESCRIPTION_LENGTH;
+        if let Some(description) = &self.status_description {
+            if description.chars().count() > max {
+                return Err(format!(
+                    "Status description must be at most {} characters",
+                    max
+                ));
+            }
+        }
+
+        match self.kind {
+            StatusRuleKind::AtTime => {
+                if self.time.as_deref().and_then(parse_time_of_day).is_none() {
+                    return Err("Time must be given as HH:MM".to_string());
+                }
+            }
+            StatusRuleKind::RevertAfter => {
+                if self.from_status.is_none() {
+                    return Err("The status to revert from is required".to_string());
+                }
+                if !self
+                    .minutes
+                    .is_some_and(|minutes| (1..=MAX_REVERT_MINUTES).contains(&minutes))
+                {
+                    return Err(format!(
+                        "Minutes must be between 1 and {}",
+                        MAX_REVERT_MINUTES
+                    ));
+                }
+            }
+            StatusRuleKind::PrivateInstance => {}
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_time_of_day(value: &str) -> Option<NaiveTime> {
+    NaiveTime::parse_from_str(value.trim(), "%H:%M").ok()
+}
+
+mod rule_entity {
+    use sea_orm::ActiveModelBehavior;
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "status_rules")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub name: String,
+        pub enabled: bool,
+        pub kind: String,
+        pub status: String,
+        pub status_description: Option<String>,
+        pub time: Option<String>,
+        pub from_status: Option<String>,
+        pub minutes: Option<i32>,
+        pub last_applied_at: Option<String>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+use rule_entity::{
+    ActiveModel as RuleActiveModel, Column as RuleColumn, Entity as RuleEntity, Model as RuleModel,
+};
+
+impl From<RuleModel> for StatusRule {
+    fn from(model: RuleModel) -> Self {
+        Self {
+            id: model.id,
+            name: model.name,
+            enabled: model.enabled,
+            kind: StatusRuleKind::parse(&model.kind).unwrap_or(StatusRuleKind::AtTime),
+            status: UserStatus::parse(&model.status).unwrap_or_default(),
+            status_description: model.status_description,
+            time: model.time,
+            from_status: model.from_status.as_deref().and_then(UserStatus::parse),
+            minutes: model.minutes.map(|minutes| minutes.max(0) as u32),
+            last_applied_at: model.last_applied_at,
+        }
+    }
+}
+
+/// Rules driving the status scheduler
+#[derive(Clone)]
+pub struct StatusRuleStore {
+    db: DatabaseConnection,
+}
+
+impl StatusRuleStore {
+    pub async fn new() -> Result<Self, String> {
+        let db = crate::store::connect_db("status rules").await?;
+        Self::with_connection(db).await
+    }
+
+    pub async fn with_connection(db: DatabaseConnection) -> Result<Self, String> {
+        let store = Self { db };
+        store.init_schema().await?;
+
+        Ok(store)
+    }
+
+    async fn init_schema(&self) -> Result<(), String> {
+        let backend = self.db.get_database_backend();
+        let schema = Schema::new(backend);
+
+        let create_table = schema
+            .create_table_from_entity(RuleEntity)
+            .if_not_exists()
+            .to_owned();
+
+        let statement: Statement = backend.build(&create_table);
+        self.db
+            .execute(statement)
+            .await
+            .map_err(|e| format!("Failed to initialize status rule table: {}", e))?;
+
+        Ok(())
+    }
+
+    /// In evaluation order, oldest first
+    pub async fn get_rules(&self) -> Result<Vec<StatusRule>, String> {
+        let rows = RuleEntity::find()
+            .order_by_asc(RuleColumn::Id)
+            .all(&self.db)
+            .await
+            .map_err(|e| format!("Failed to load status rules: {}", e))?;
+
+        Ok(rows.into_iter().map(StatusRule::from).collect())
+    }
+
+    pub async fn get_rule(&self, id: i32) -> Result<Option<StatusRule>, String> {
+        let row = RuleEntity::find_by_id(id)
+            .one(&self.db)
+            .await
+            .map_err(|e| format!("Failed to load status rule {}: {}", id, e))?;
+
+        Ok(row.map(StatusRule::from))
+    }
+
+    pub async fn save_rule(&self, input: StatusRuleInput) -> Result<StatusRule, String> {
+        input.validate()?;
+
+        let existing = match input.id {
+            Some(id) => Some(
+                RuleEntity::find_by_id(id)
+                    .one(&self.db)
+                    .await
+                    .map_err(|e| format!("Failed to load status rule {}: {}", id, e))?
+                    .ok_or_else(|| format!("Status rule {} does not exist", id))?,
+            ),
+            None => None,
+        };
+
+        let kind = input.kind;
+        let mut active_model = RuleActiveModel {
+            id: NotSet,
+            name: Set(input.name.trim().to_string()),
+            enabled: Set(input.enabled),
+            kind: Set(kind.as_str().to_string()),
+            status: Set(input.status.to_string()),
+            status_description: Set(input.status_description),
+            time: Set(input
+                .time
+                .filter(|_| kind == StatusRuleKind::AtTime)
+                .map(|time| time.trim().to_string())),
+            from_status: Set(input
+                .from_status
+                .filter(|_| kind == StatusRuleKind::RevertAfter)
+                .map(|status| status.to_string())),
+            minutes: Set(input
+                .minutes
+                .filter(|_| kind == StatusRuleKind::RevertAfter)
+                .map(|minutes| minutes as i32)),
+            last_applied_at: NotSet,
+        };
+
+        let model = match existing {
+            Some(existing) => {
+                active_model.id = Set(existing.id);
+                RuleEntity::update(active_model)
+                    .exec(&self.db)
+                    .await
+                    .map_err(|e| format!("Failed to save status rule {}: {}", existing.id, e))?
+            }
+            None => {
+                let id = RuleEntity::insert(active_model)
+                    .exec(&self.db)
+                    .await
+                    .map_err(|e| format!("Failed to save status rule: {}", e))?
+                    .last_insert_id;
+                RuleEntity::find_by_id(id)
+                    .one(&self.db)
+                    .await
+                    .map_err(|e| format!("Failed to load status rule {}: {}", id, e))?
+                    .ok_or_else(|| format!("Status rule {} does not exist", id))?
+            }
+        };
+
+        Ok(StatusRule::from(model))
+    }
+
+    /// Returns whether a rule was removed
+    pub async fn delete_rule(&self, id: i32) -> Result<bool, String> {
+        let result = RuleEntity::delete_by_id(id)
+            .exec(&self.db)
+            .await
+            .map_err(|e| format!("Failed to delete status rule {}: {}", id, e))?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    pub async fn mark_applied(&self, id: i32, at: DateTime<Utc>) -> Result<(), String> {
+        let active_model = RuleActiveModel {
+            id: Set(id),
+            name: NotSet,
+            enabled: NotSet,
+            kind: NotSet,
+            status: NotSet,
+            status_description: NotSet,
+            time: NotSet,
+            from_status: NotSet,
+            minutes: NotSet,
+            last_applied_at: Set(Some(at.to_rfc3339_opts(SecondsFormat::Millis, true))),
+        };
+
+        RuleEntity::update(active_model)
+            .exec(&self.db)
+            .await
+            .map_err(|e| format!("Failed to update status rule {}: {}", id, e))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::db::memory_db;
+
+    fn busy_at(time: &str) -> StatusRuleInput {
+        StatusRuleInput {
+            id: None,
+            name: "Evening".to_string(),
+            enabled: true,
+            kind: StatusRuleKind::AtTime,
+            status: UserStatus::Busy,
+            status_description: Some("Working".to_string()),
+            time: Some(time.to_string()),
+            from_status: Some(UserStatus::Active),
+            minutes: Some(30),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rule_crud_and_validation() {
+        let store = StatusRuleStore::with_connection(memory_db().await)
+            .await
+            .unwrap();
+
+        let rule = store.save_rule(busy_at("21:30")).await.unwrap();
+        assert_eq!(rule.kind, StatusRuleKind::AtTime);
+        assert_eq!(rule.time_of_day(), NaiveTime::from_hms_opt(21, 30, 0));
+        // Fields of other kinds are dropped
+        assert_eq!((rule.from_status, rule.minutes), (None, None));
+
+        let err = store.save_rule(busy_at("9pm")).await.unwrap_err();
+        assert!(err.contains("HH:MM"));
+
+        let mut revert = busy_at("");
+        revert.kind = StatusRuleKind::RevertAfter;
+        revert.minutes = Some(0);
+        assert!(store.save_rule(revert.clone()).await.is_err());
+        revert.minutes = Some(45);
+        let revert = store.save_rule(revert).await.unwrap();
+        assert_eq!(revert.time, None);
+
+        store.mark_applied(rule.id, Utc::now()).await.unwrap();
+        let mut update = busy_at("22:00");
+        update.id = Some(rule.id);
+        update.enabled = false;
+        let updated = store.save_rule(update).await.unwrap();
+        assert!(!updated.enabled);
+        assert_eq!(updated.time.as_deref(), Some("22:00"));
+        assert!(updated.last_applied_at.is_some());
+
+        let mut missing = busy_at("22:00");
+        missing.id = Some(999);
+        assert!(store.save_rule(missing).await.is_err());
+
+        let ids: Vec<i32> = store
+            .get_rules()
+            .await
+            .unwrap()
+            .iter()
+            .map(|r| r.id)
+            .collect();
+        assert_eq!(ids, vec![rule.id, revert.id]);
+
+        assert!(store.delete_rule(rule.id).await.unwrap());
+        assert!(!store.delete_rule(rule.id).await.unwrap());
+        assert!(store.get_rule(rule.id).await.unwrap().is_none());
+    }
+}
@@ -1,4 +1,6 @@
-use crate::vrchat_api::types::{LimitedUserFriend, User, UserStatus};
+use crate::vrchat_api::types::{
+    FriendRequestStatus, FriendStatus, LimitedUserFriend, Location, User, UserStatus,
+};
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -52,7 +54,7 @@ pub struct CachedUser {
     pub bio: Option<String>,
     pub status: Option<UserStatus>,
     pub status_description: Option<String>,
-    pub location: Option<String>,
+    pub location: Option<Location>,
     pub platform: Option<Platform>,
     pub relationship: UserRelationship,
     pub full_user: Option<User>,
@@ -129,8 +131,10 @@ impl CachedUser {
     }
 
     pub fn is_online(&self) -> bool {
-        self.location.as_ref().map_or(false, |loc| {
-            loc != "offline" && loc != "private" && !loc.is_empty()
+        self.location.as_ref().map_or(false, |loc| match loc {
+            Location::Offline | Location::Private => false,
+            Location::Unknown(raw) => !raw.is_empty(),
+            _ => true,
         })
     }
 
@@ -164,6 +168,28 @@ pub struct CurrentUserPipelineUpdate {
     pub tags: Vec<String>,
 }
 
+impl From<&User> for CurrentUserPipelineUpdate {
+    fn from(user: &User) -> Self {
+        Self {
+            id: user.id.clone(),
+            display_name: user.display_name.clone(),
+            username: user.username.clone(),
+            status: user.status.to_string(),
+            status_description: user.status_description.clone(),
+            bio: user.bio.clone(),
+            user_icon: user.user_icon.clone(),
+            profile_pic_override: user.profile_pic_override.clone(),
+            profile_pic_override_thumbnail: user.profile_pic_override_thumbnail.clone(),
+            current_avatar: user.current_avatar.clone(),
+            current_avatar_asset_url: None,
+            current_avatar_image_url: user.current_avatar_image_url.clone(),
+            current_avatar_thumbnail_image_url: user.current_avatar_thumbnail_image_url.clone(),
+            fallback_avatar: user.fallback_avatar.clone(),
+            tags: user.tags.clone(),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct UserStore {
     users: Arc<RwLock<HashMap<String, CachedUser>>>,
@@ -193,6 +219,36 @@ impl UserStore {
         log::info!("UserStore: Set current user to {}", user_id);
     }
 
+    /// Patch the cached current user with the response of a profile edit. Presence fields
+    /// such as the location keep coming from the pipeline, so only profile fields are copied.
+    pub async fn patch_current_user_profile(&self, user: &User) {
+        let mut users = self.users.write().await;
+        let Some(entry) = users.get_mut(&user.id) else {
+            drop(users);
+            self.set_current_user(user.clone()).await;
+            return;
+        };
+
+        entry.bio = Some(user.bio.clone());
+        entry.status = Some(user.status);
+        entry.status_description = Some(user.status_description.clone());
+        entry.user_icon = user.user_icon.clone();
+        entry.last_updated = std::time::Instant::now();
+
+        match entry.full_user.as_mut() {
+            Some(full_user) => {
+                full_user.bio = user.bio.clone();
+                full_user.bio_links = user.bio_links.clone();
+                full_user.pronouns = user.pronouns.clone();
+                full_user.tags = user.tags.clone();
+                full_user.status = user.status;
+                full_user.status_description = user.status_description.clone();
+                full_user.user_icon = user.user_icon.clone();
+            }
+            None => entry.full_user = Some(user.clone()),
+        }
+    }
+
     pub async fn get_current_user(&self) -> Option<User> {
         let current_id = self.current_user_id.read().await;
         let user_id = current_id.as_ref()?.clone();
@@ -322,11 +378,11 @@ impl UserStore {
         let mut users = self.users.write().await;
 
         if let Some(user) = users.get_mut(user_id) {
-            user.location = Some("offline".to_string());
+            user.location = Some(Location::Offline);
             user.last_updated = std::time::Instant::now();
 
             if let Some(ref mut friend_data) = user.friend_data {
-                friend_data.location = Some("offline".to_string());
+                friend_data.location = Some(Location::Offline);
                 friend_data.platform = String::new();
             }
 
@@ -338,7 +394,7 @@ impl UserStore {
     pub async fn update_user_location(
         &self,
         user_id: &str,
-        location: String,
+        location: Location,
         platform: Option<String>,
     ) {
         let mut users = self.users.write().await;
@@ -381,12 +437,61 @@ impl UserStore {
             user.platform = None;
             user.last_updated = std::time::Instant::now();
 
+            if let Some(full_user) = user.full_user.as_mut() {
+                full_user.is_friend = Some(false);
+                full_user.friend_request_status = FriendRequestStatus::None;
+            }
+
             log::info!("UserStore: Removed friend {}", user_id);
         } else {
             log::debug!("UserStore: Attempted to remove unknown friend {}", user_id);
         }
     }
 
+    /// Mark a cached user as a friend right after a friend request was accepted,
+    /// before the `friend-add` pipeline event delivers their friend data
+    pub async fn mark_friend(&self, user_id: &str) {
+        let mut users = self.users.write().await;
+
+        if let Some(user) = users.get_mut(user_id) {
+            user.relationship = UserRelationship::Friend;
+            user.last_updated = std::time::Instant::now();
+
+            if let Some(full_user) = user.full_user.as_mut() {
+                full_user.is_friend = Some(true);
+                full_user.friend_request_status = FriendRequestStatus::Completed;
+            }
+
+            log::info!("UserStore: Marked {} as friend", user_id);
+        }
+    }
+
+    /// Record a pending (or withdrawn) friend request on a cached user
+    pub async fn set_friend_request_status(&self, user_id: &str, status: FriendRequestStatus) {
+        let mut users = self.users.write().await;
+
+        if let Some(full_user) = users
+            .get_mut(user_id)
+            .and_then(|user| user.full_user.as_mut())
+        {
+            full_user.friend_request_status = status;
+        }
+    }
+
+    /// Reconcile a cached user with a `/friendStatus` response
+    pub async fn apply_friend_status(&self, user_id: &str, status: &FriendStatus) {
+        let was_friend = self.is_friend(user_id).await;
+
+        if status.is_friend {
+            self.mark_friend(user_id).await;
+        } else if was_friend {
+            self.remove_friend(user_id).await;
+        }
+
+        self.set_friend_request_status(user_id, status.request_status())
+            .await;
+    }
+
     /// Patch the cached current-user record with data streamed from the websocket.
     pub async fn apply_current_user_update(&self, patch: CurrentUserPipelineUpdate) {
         use std::time::Instant;
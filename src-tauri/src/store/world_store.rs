@@ -0,0 +1,252 @@
+use chrono::Utc;
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    Schema, Statement, sea_query::OnConflict,
+};
+use std::time::Duration;
+
+use crate::vrchat_api::types::LimitedWorld;
+
+/// How long cached world details are served before they are fetched again
+pub const DEFAULT_WORLD_TTL: Duration = Duration::from_secs(60 * 60);
+
+mod world_entity {
+    use sea_orm::ActiveModelBehavior;
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "world_cache")]
+    pub struct Model {
+        #[sea_orm(primary_key, auto_increment = false)]
+        pub world_id: String,
+        /// `LimitedWorld` as JSON
+        pub data: String,
+        /// Unix timestamp in seconds
+        #[sea_orm(indexed)]
+        pub fetched_at: i64,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+use world_entity::{
+    ActiveModel as WorldActiveModel, Column as WorldColumn, Entity as WorldEntity,
+    Model as WorldModel,
+};
+
+/// Persistent cache of world details, so repeated lookups (e.g. friend locations)
+/// do not hit `/worlds/{id}` every time
+#[derive(Clone)]
+pub struct WorldStore {
+    db: DatabaseConnection,
+    ttl: Duration,
+}
+
+impl WorldStore {
+    pub async fn new() -> Result<Self, String> {
+        let db = crate::store::connect_db("world cache").await?;
+        Self::with_connection(db, DEFAULT_WORLD_TTL).await
+    }
+
+    pub async fn with_connection(db: DatabaseConnection, ttl: Duration) -> Result<Self, String> {
+        let store = Self { db, ttl };
+        store.init_schema().await?;
+
+        Ok(store)
+    }
+
+    async fn init_schema(&self) -> Result<(), String> {
+        let backend = self.db.get_database_backend();
+        let schema = Schema::new(backend);
+
+        let create_table = schema
+            .create_table_from_entity(WorldEntity)
+            .if_not_exists()
+            .to_owned();
+
+        let statement: Statement = backend.build(&create_table);
+        self.db
+            .execute(statement)
+            .await
+            .map_err(|e| format!("Failed to initialize world cache table: {}", e))?;
+
+        for mut index in schema.create_index_from_entity(WorldEntity) {
+            let statement = backend.build(index.if_not_exists());
+            self.db
+                .execute(statement)
+                .await
+                .map_err(|e| format!("Failed to create world cache index: {}", e))?;
+        }
+
+        Ok(())
+    }
+
+    /// Cached world if it is younger than the TTL
+    pub async fn get(&self, world_id: &str) -> Result<Option<LimitedWorld>, String> {
+        let Some(model) = self.find(world_id).await? else {
+            return Ok(None);
+        };
+
+        if !self.is_fresh(&model) {
+            return Ok(None);
+        }
+
+        Ok(to_world(model))
+    }
+
+    /// Cached world regardless of age, e.g. as a fallback when the API is unreachable
+    pub async fn get_stale(&self, world_id: &str) -> Result<Option<LimitedWorld>, String> {
+        Ok(self.find(world_id).await?.and_then(to_world))
+    }
+
+    /// Fresh cached worlds among `world_ids`; missing and expired ones are left out
+    pub async fn get_many(&self, world_ids: &[String]) -> Result<Vec<LimitedWorld>, String> {
+        if world_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let models = WorldEntity::find()
+            .filter(WorldColumn::WorldId.is_in(world_ids.iter().cloned()))
+            .all(&self.db)
+            .await
+            .map_err(|e| format!("Failed to load cached worlds: {}", e))?;
+
+        Ok(models
+            .into_iter()
+            .filter(|model| self.is_fresh(model))
+            .filter_map(to_world)
+            .collect())
+    }
+
+    pub async fn put(&self, world: &LimitedWorld) -> Result<(), String> {
+        self.put_many(std::slice::from_ref(world)).await
+    }
+
+    pub async fn put_many(&self, worlds: &[LimitedWorld]) -> Result<(), String> {
+        if worlds.is_empty() {
+            return Ok(());
+        }
+
+        let fetched_at = Utc::now().timestamp();
+        let mut models = Vec::with_capacity(worlds.len());
+        for world in worlds {
+            let data = serde_json::to_string(world)
+                .map_err(|e| format!("Failed to serialize world {}: {}", world.id, e))?;
+            models.push(WorldActiveModel {
+                world_id: Set(world.id.clone()),
+                data: Set(data),
+                fetched_at: Set(fetched_at),
+            });
+        }
+
+        WorldEntity::insert_many(models)
+            .on_conflict(
+                OnConflict::column(WorldColumn::WorldId)
+                    .update_columns([WorldColumn::Data, WorldColumn::FetchedAt])
+                    .to_owned(),
+            )
+            .exec(&self.db)
+            .await
+            .map_err(|e| format!("Failed to cache worlds: {}", e))?;
+
+        Ok(())
+    }
+
+    pub async fn remove(&self, world_id: &str) -> Result<(), String> {
+        WorldEntity::delete_by_id(world_id.to_string())
+            .exec(&self.db)
+            .await
+            .map_err(|e| format!("Failed to remove cached world: {}", e))?;
+        Ok(())
+    }
+
+    /// Drop entries older than the TTL, returning how many were removed
+    pub async fn purge_expired(&self) -> Result<u64, String> {
+        let cutoff = Utc::now().timestamp() - self.ttl.as_secs() as i64;
+        let result = WorldEntity::delete_many()
+            .filter(WorldColumn::FetchedAt.lt(cutoff))
+            .exec(&self.db)
+            .await
+            .map_err(|e| format!("Failed to purge world cache: {}", e))?;
+
+        Ok(result.rows_affected)
+    }
+
+    pub async fn clear(&self) -> Result<(), String> {
+        WorldEntity::delete_many()
+            .exec(&self.db)
+            .await
+            .map_err(|e| format!("Failed to clear world cache: {}", e))?;
+        Ok(())
+    }
+
+    async fn find(&self, world_id: &str) -> Result<Option<WorldModel>, String> {
+        WorldEntity::find_by_id(world_id.to_string())
+            .one(&self.db)
+            .await
+            .map_err(|e| format!("Failed to load cached world: {}", e))
+    }
+
+    fn is_fresh(&self, model: &WorldModel) -> bool {
+        let age = Utc::now().timestamp() - model.fetched_at;
+        age >= 0 && (age as u64) < self.ttl.as_secs()
+    }
+}
+
+fn to_world(model: WorldModel) -> Option<LimitedWorld> {
+    match serde_json::from_str(&model.data) {
+        Ok(world) => Some(world),
+        Err(e) => {
+            log::warn!(
+                "Discarding unreadable cached world {}: {}",
+                model.world_id,
+                e
+            );
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::db::memory_db;
+
+    fn world(id: &str, name: &str) -> LimitedWorld {
+        serde_json::from_value(serde_json::json!({ "id": id, "name": name })).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_put_replaces_and_get_many_filters() {
+        let store = WorldStore::with_connection(memory_db().await, DEFAULT_WORLD_TTL)
+            .await
+            .unwrap();
+        store
+            .put_many(&[world("wrld_a", "A"), world("wrld_b", "B")])
+            .await
+            .unwrap();
+        store.put(&world("wrld_a", "A v2")).await.unwrap();
+
+        let cached = store.get("wrld_a").await.unwrap().unwrap();
+        assert_eq!(cached.name, "A v2");
+
+        let ids = vec!["wrld_b".to_string(), "wrld_missing".to_string()];
+        let many = store.get_many(&ids).await.unwrap();
+        assert_eq!(many.len(), 1);
+        assert_eq!(many[0].id, "wrld_b");
+    }
+
+    #[tokio::test]
+    async fn test_expired_entries_are_not_served() {
+        let store = WorldStore::with_connection(memory_db().await, Duration::ZERO)
+            .await
+            .unwrap();
+        store.put(&world("wrld_a", "A")).await.unwrap();
+
+        assert!(store.get("wrld_a").await.unwrap().is_none());
+        assert_eq!(store.get_stale("wrld_a").await.unwrap().unwrap().name, "A");
+    }
+}
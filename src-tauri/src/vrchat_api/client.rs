@@ -1,18 +1,20 @@
 use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use futures_util::{Stream, StreamExt, stream};
 use reqwest::header::HeaderMap;
 use reqwest::{Client, Request, RequestBuilder, Response};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
-use tokio::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tokio::sync::{Mutex, broadcast};
 use tokio::time::{Duration, sleep};
 
-use crate::http_common::{INITIAL_BACKOFF, MAX_BACKOFF, MAX_REQUEST_RETRIES};
+use crate::rate_limiter::{EndpointClass, RateLimiter};
 use crate::vrchat_api::{
+    config::VRChatClientConfig,
     error::{VRCError, VRCResult},
     types::*,
 };
-
-const API_BASE_URL: &str = "https://api.vrchat.cloud/api/1";
+use crate::websocket::types::{NotificationPayload, NotificationV2Payload, NotificationV2Response};
 
 // Cookie Management
 #[derive(Debug, Clone, Default)]
@@ -63,25 +65,80 @@ impl CookieStore {
     }
 }
 
+/// Broadcast when VRChat rejects the session cookies of an established session
+#[derive(Debug, Clone)]
+pub struct SessionExpiredNotice {
+    pub message: String,
+}
+
 // VRChat HTTP Client for VRChat API requests
 #[derive(Clone)]
 pub struct VRChatClient {
     http_client: Client,
+    config: Arc<VRChatClientConfig>,
     cookies: Arc<Mutex<CookieStore>>,
+    rate_limiter: RateLimiter,
+    session_active: Arc<AtomicBool>,
+    session_expired_tx: broadcast::Sender<SessionExpiredNotice>,
 }
 
 impl VRChatClient {
     /// Create a new VRChat API client
-    pub fn new() -> VRCResult<Self> {
-        let http_client = Client::builder()
+    pub fn new(config: VRChatClientConfig) -> VRCResult<Self> {
+        config
+            .rate_limits
+            .validate()
+            .map_err(VRCError::invalid_input)?;
+
+        let mut builder = Client::builder()
             .cookie_store(false)
+            .connect_timeout(config.connect_timeout)
+            .timeout(config.request_timeout);
+
+        if let Some(proxy_url) = &config.proxy_url {
+            let proxy = reqwest::Proxy::all(proxy_url)
+                .map_err(|e| VRCError::invalid_input(format!("Invalid proxy URL: {}", e)))?;
+            builder = builder.proxy(proxy);
+        }
+
+        let http_client = builder
             .build()
             .map_err(|e| VRCError::network(format!("Failed to create HTTP client: {}", e)))?;
 
-        Ok(Self {
+        Ok(Self::with_http_client(config, http_client))
+    }
+
+    /// Create a client on top of a caller-provided HTTP transport.
+    /// The transport must not keep its own cookie store, cookies are managed by the client.
+    /// Unlike [`Self::new`], the rate limits are not validated.
+    pub fn with_http_client(config: VRChatClientConfig, http_client: Client) -> Self {
+        let (session_expired_tx, _) = broadcast::channel(4);
+        let rate_limiter = RateLimiter::new(config.rate_limits.clone());
+
+        Self {
             http_client,
+            config: Arc::new(config),
             cookies: Arc::new(Mutex::new(CookieStore::default())),
-        })
+            rate_limiter,
+            session_active: Arc::new(AtomicBool::new(false)),
+            session_expired_tx,
+        }
+    }
+
+    /// Connection settings this client was created with
+    pub fn config(&self) -> &VRChatClientConfig {
+        &self.config
+    }
+
+    /// Rate limiter throttling this client, share it with other VRChat traffic
+    pub fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    /// Subscribe to session expiry notices. A notice is sent once per established
+    /// session, the first time a request carrying its cookies is rejected with 401.
+    pub fn subscribe_session_expired(&self) -> broadcast::Receiver<SessionExpiredNotice> {
+        self.session_expired_tx.subscribe()
     }
 
     // Authentication Methods
@@ -94,7 +151,7 @@ impl VRChatClient {
         let response = self
             .execute_request(
                 self.http_client
-                    .get(&format!("{}/auth/user", API_BASE_URL))
+                    .get(self.api_url("/auth/user"))
                     .headers(headers),
             )
             .await?;
@@ -112,11 +169,7 @@ impl VRChatClient {
         }
 
         if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Login failed".to_string());
-            return Err(VRCError::auth(error_text));
+            return Err(Self::api_error(response, "Login failed").await);
         }
 
         let body = response.text().await?;
@@ -129,6 +182,7 @@ impl VRChatClient {
         }
 
         let user: User = serde_json::from_str(&body)?;
+        self.session_active.store(true, Ordering::SeqCst);
         Ok(LoginResult::Success { user })
     }
 
@@ -148,11 +202,9 @@ impl VRChatClient {
         let response = self
             .execute_request(
                 self.http_client
-                    .post(&format!(
-                        "{}/auth/twofactorauth/{}/verify",
-                        API_BASE_URL,
-                        method.endpoint()
-                    ))
+                    .post(
+                        self.api_url(&format!("/auth/twofactorauth/{}/verify", method.endpoint())),
+                    )
                     .headers(headers)
                     .json(&request_body),
             )
@@ -171,16 +223,16 @@ impl VRChatClient {
         }
 
         if !status.is_success() && status.as_u16() != 400 {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Verification failed".to_string());
-            return Err(VRCError::auth(error_text));
+            return Err(Self::api_error(response, "Verification failed").await);
         }
 
         let body = response.text().await?;
         let verify_response: TwoFactorVerifyResponse = serde_json::from_str(&body)?;
 
+        if verify_response.verified {
+            self.session_active.store(true, Ordering::SeqCst);
+        }
+
         Ok(verify_response.verified)
     }
 
@@ -196,7 +248,7 @@ impl VRChatClient {
         let response = self
             .execute_request(
                 self.http_client
-                    .get(&format!("{}/auth/user", API_BASE_URL))
+                    .get(self.api_url("/auth/user"))
                     .headers(headers),
             )
             .await?;
@@ -204,11 +256,7 @@ impl VRChatClient {
         let status = response.status();
 
         if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Failed to get user".to_string());
-            return Err(VRCError::http(status.as_u16(), error_text));
+            return Err(Self::api_error(response, "Failed to get user").await);
         }
 
         let body = response.text().await?;
@@ -218,35 +266,43 @@ impl VRChatClient {
     }
 
     /// Update the user's status and status description
-    pub async fn update_status(&self, request: &UpdateStatusRequest) -> VRCResult<User> {
-        // First get current user to get their userId
-        let current_user = self.get_current_user().await?;
-
-        let cookie_header = {
-            let cookies = self.cookies.lock().await;
-            cookies.to_header_value()
+    pub async fn update_status(
+        &self,
+        current_user: &User,
+        request: &UpdateStatusRequest,
+    ) -> VRCResult<User> {
+        let request = UpdateUserRequest {
+            status: Some(request.status),
+            status_description: Some(request.status_description.clone()),
+            ..Default::default()
         };
+        self.update_current_user(current_user, &request).await
+    }
 
-        let cookie = cookie_header.ok_or_else(|| VRCError::auth("Not authenticated"))?;
-        let headers = self.build_headers(None, None, Some(&cookie));
+    /// Edit the current user's profile. `current_user` supplies the ID and the tags that
+    /// language changes are merged into, so no lookup is needed first.
+    pub async fn update_current_user(
+        &self,
+        current_user: &User,
+        request: &UpdateUserRequest,
+    ) -> VRCResult<User> {
+        request.validate().map_err(VRCError::invalid_input)?;
 
+        let mut body = request.clone();
+        body.tags = request.merged_tags(&current_user.tags);
+
+        let headers = self.session_headers().await?;
         let response = self
             .execute_request(
                 self.http_client
-                    .put(&format!("{}/users/{}", API_BASE_URL, current_user.id))
+                    .put(self.api_url(&format!("/users/{}", current_user.id)))
                     .headers(headers)
-                    .json(request),
+                    .json(&body),
             )
             .await?;
 
-        let status = response.status();
-
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Failed to update status".to_string());
-            return Err(VRCError::http(status.as_u16(), error_text));
+        if !response.status().is_success() {
+            return Err(Self::api_error(response, "Failed to update profile").await);
         }
 
         let user: User = response.json().await?;
@@ -266,7 +322,7 @@ impl VRChatClient {
             let _ = self
                 .execute_request(
                     self.http_client
-                        .put(&format!("{}/logout", API_BASE_URL))
+                        .put(self.api_url("/logout"))
                         .headers(headers),
                 )
                 .await;
@@ -274,6 +330,7 @@ impl VRChatClient {
 
         let mut cookies = self.cookies.lock().await;
         cookies.clear();
+        self.session_active.store(false, Ordering::SeqCst);
 
         Ok(())
     }
@@ -309,7 +366,8 @@ impl VRChatClient {
             cookies.to_header_value()
         };
 
-        let cookie = cookie_header.ok_or_else(|| VRCError::auth("Not authenticated"))?;
+        let cookie =
+            cookie_header.ok_or_else(|| VRCError::missing_credentials("Not authenticated"))?;
         let mut results = Vec::new();
         let mut offset = 0usize;
         const PAGE_SIZE: usize = 100;
@@ -319,19 +377,16 @@ impl VRChatClient {
             let response = self
                 .execute_request(
                     self.http_client
-                        .get(&format!(
-                            "{}/auth/user/friends?offline={}&n={}&offset={}",
-                            API_BASE_URL, offline, PAGE_SIZE, offset
-                        ))
+                        .get(self.api_url(&format!(
+                            "/auth/user/friends?offline={}&n={}&offset={}",
+                            offline, PAGE_SIZE, offset
+                        )))
                         .headers(headers),
                 )
                 .await?;
 
             if !response.status().is_success() {
-                return Err(VRCError::http(
-                    response.status().as_u16(),
-                    "Failed to fetch friends",
-                ));
+                return Err(Self::api_error(response, "Failed to fetch friends").await);
             }
 
             let page: Vec<LimitedUserFriend> = response.json().await?;
@@ -346,41 +401,107 @@ impl VRChatClient {
                 break;
             }
 
-            sleep(Duration::from_secs(1)).await;
             offset += PAGE_SIZE;
         }
 
         Ok(results)
     }
 
-    /// Fetch all worlds uploaded by the authenticated user
+    /// Fetch all worlds uploaded by the authenticated user, with missing statistics backfilled
     pub async fn get_uploaded_worlds(&self) -> VRCResult<Vec<LimitedWorld>> {
+        let mut worlds = self.list_uploaded_worlds().await?;
+        self.backfill_world_stats(&mut worlds, &HashSet::new(), |_| {})
+            .await;
+        Ok(worlds)
+    }
+
+    /// Fill in statistics missing from `worlds` from `/worlds/{id}`, except for the IDs in
+    /// `known` (e.g. already filled from a cache). `on_progress` is called once with the whole
+    /// list, then once per finished detail request. Returns the details that were fetched.
+    pub async fn backfill_world_stats(
+        &self,
+        worlds: &mut [LimitedWorld],
+        known: &HashSet<String>,
+        mut on_progress: impl FnMut(UploadedWorldsProgress),
+    ) -> Vec<LimitedWorld> {
+        let pending: Vec<String> = worlds
+            .iter()
+            .filter(|world| world.is_missing_stats() && !known.contains(&world.id))
+            .map(|world| world.id.clone())
+            .collect();
+        let total = pending.len() as u32;
+        on_progress(UploadedWorldsProgress {
+            worlds: worlds.to_vec(),
+            completed: 0,
+            total,
+        });
+
+        let positions: HashMap<String, usize> = worlds
+            .iter()
+            .enumerate()
+            .map(|(index, world)| (world.id.clone(), index))
+            .collect();
+        let mut fetched = Vec::new();
+        let mut completed = 0;
+        let mut results = self.world_details_stream(pending);
+
+        while let Some((world_id, result)) = results.next().await {
+            completed += 1;
+            let mut updated = Vec::new();
+
+            match result {
+                Ok(details) => {
+                    if let Some(&index) = positions.get(&world_id) {
+                        worlds[index].merge_stats(&details);
+                        updated.push(worlds[index].clone());
+                    }
+                    fetched.push(details);
+                }
+                Err(err) => {
+                    log::warn!(
+                        "Failed to load additional details for world {}: {}",
+                        world_id,
+                        err
+                    );
+                }
+            }
+
+            on_progress(UploadedWorldsProgress {
+                worlds: updated,
+                completed,
+                total,
+            });
+        }
+
+        fetched
+    }
+
+    /// Fetch all worlds uploaded by the authenticated user as the list endpoint returns them
+    pub async fn list_uploaded_worlds(&self) -> VRCResult<Vec<LimitedWorld>> {
         let cookie_header = {
             let cookies = self.cookies.lock().await;
             cookies.to_header_value()
         };
 
-        let cookie = cookie_header.ok_or_else(|| VRCError::auth("Not authenticated"))?;
+        let cookie =
+            cookie_header.ok_or_else(|| VRCError::missing_credentials("Not authenticated"))?;
         let mut worlds = Vec::new();
         let mut offset: usize = 0;
         const PAGE_SIZE: usize = 100;
 
         loop {
             let headers = self.build_headers(None, None, Some(&cookie));
-            let url = format!(
-                "{}/worlds?user=me&n={}&offset={}&order=descending&sort=updated",
-                API_BASE_URL, PAGE_SIZE, offset
-            );
+            let url = self.api_url(&format!(
+                "/worlds?user=me&n={}&offset={}&order=descending&sort=updated",
+                PAGE_SIZE, offset
+            ));
 
             let response = self
                 .execute_request(self.http_client.get(&url).headers(headers))
                 .await?;
 
             if !response.status().is_success() {
-                return Err(VRCError::http(
-                    response.status().as_u16(),
-                    "Failed to fetch uploaded worlds",
-                ));
+                return Err(Self::api_error(response, "Failed to fetch uploaded worlds").await);
             }
 
             let mut page: Vec<LimitedWorld> = response.json().await?;
@@ -390,53 +511,6 @@ impl VRChatClient {
                 break;
             }
 
-            // Backfill statistics that might be missing from the list endpoint
-            for idx in 0..page.len() {
-                if page[idx].visits.is_none()
-                    || page[idx].favorites.is_none()
-                    || page[idx].popularity.is_none()
-                    || page[idx].occupants.is_none()
-                    || page[idx].capacity.is_none()
-                    || page[idx].recommended_capacity.is_none()
-                {
-                    match self.get_world_details(&page[idx].id).await {
-                        Ok(details) => {
-                            if details.visits.is_some() {
-                                page[idx].visits = details.visits;
-                            }
-                            if details.favorites.is_some() {
-                                page[idx].favorites = details.favorites;
-                            }
-                            if details.popularity.is_some() {
-                                page[idx].popularity = details.popularity;
-                            }
-                            if details.occupants.is_some() {
-                                page[idx].occupants = details.occupants;
-                            }
-                            if details.capacity.is_some() {
-                                page[idx].capacity = details.capacity;
-                            }
-                            if details.recommended_capacity.is_some() {
-                                page[idx].recommended_capacity = details.recommended_capacity;
-                            }
-                            if details.heat.is_some() {
-                                page[idx].heat = details.heat;
-                            }
-                            if details.organization.is_some() {
-                                page[idx].organization = details.organization;
-                            }
-                        }
-                        Err(err) => {
-                            log::warn!(
-                                "Failed to load additional details for world {}: {}",
-                                page[idx].id,
-                                err
-                            );
-                        }
-                    }
-                }
-            }
-
             offset += count;
             worlds.append(&mut page);
 
@@ -448,6 +522,22 @@ impl VRChatClient {
         Ok(worlds)
     }
 
+    /// Fetch details for many worlds, `backfill_concurrency` at a time. Every request still
+    /// goes through the rate limiter; results arrive in completion order.
+    fn world_details_stream(
+        &self,
+        world_ids: Vec<String>,
+    ) -> impl Stream<Item = (String, VRCResult<LimitedWorld>)> + '_ {
+        let concurrency = self.config.backfill_concurrency.max(1);
+
+        stream::iter(world_ids)
+            .map(move |world_id| async move {
+                let result = self.get_world_details(&world_id).await;
+                (world_id, result)
+            })
+            .buffer_unordered(concurrency)
+    }
+
     /// Fetch all avatars uploaded by the authenticated user
     pub async fn get_uploaded_avatars(&self) -> VRCResult<Vec<LimitedAvatar>> {
         let cookie_header = {
@@ -455,27 +545,25 @@ impl VRChatClient {
             cookies.to_header_value()
         };
 
-        let cookie = cookie_header.ok_or_else(|| VRCError::auth("Not authenticated"))?;
+        let cookie =
+            cookie_header.ok_or_else(|| VRCError::missing_credentials("Not authenticated"))?;
         let mut avatars = Vec::new();
         let mut offset: usize = 0;
         const PAGE_SIZE: usize = 100;
 
         loop {
             let headers = self.build_headers(None, None, Some(&cookie));
-            let url = format!(
-                "{}/avatars?user=me&releaseStatus=all&sort=updated&order=descending&n={}&offset={}",
-                API_BASE_URL, PAGE_SIZE, offset
-            );
+            let url = self.api_url(&format!(
+                "/avatars?user=me&releaseStatus=all&sort=updated&order=descending&n={}&offset={}",
+                PAGE_SIZE, offset
+            ));
 
             let response = self
                 .execute_request(self.http_client.get(&url).headers(headers))
                 .await?;
 
             if !response.status().is_success() {
-                return Err(VRCError::http(
-                    response.status().as_u16(),
-                    "Failed to fetch uploaded avatars",
-                ));
+                return Err(Self::api_error(response, "Failed to fetch uploaded avatars").await);
             }
 
             let mut page: Vec<LimitedAvatar> = response.json().await?;
@@ -496,7 +584,98 @@ impl VRChatClient {
         Ok(avatars)
     }
 
-    // TODO: Have to analyses consequences of rate limits when fetching world details in a loop
+    /// Fetch any avatar by ID; private avatars are only visible to their author
+    pub async fn get_avatar(&self, avatar_id: &str) -> VRCResult<LimitedAvatar> {
+        let headers = self.session_headers().await?;
+
+        let response = self
+            .execute_request(
+                self.http_client
+                    .get(self.api_url(&format!("/avatars/{}", avatar_id)))
+                    .headers(headers),
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(Self::api_error(
+                response,
+                &format!("Failed to fetch avatar {}", avatar_id),
+            )
+            .await);
+        }
+
+        let avatar: LimitedAvatar = response.json().await?;
+        Ok(avatar)
+    }
+
+    /// Fetch favorited avatars, optionally limited to one favorite group (e.g. `avatars1`)
+    pub async fn get_favorited_avatars(
+        &self,
+        group: Option<&str>,
+    ) -> VRCResult<Vec<LimitedAvatar>> {
+        let filter = group.map(|g| format!("&tag={}", g)).unwrap_or_default();
+
+        self.fetch_pages(
+            |n, offset| format!("/avatars/favorites?n={}&offset={}{}", n, offset, filter),
+            "Failed to fetch favorited avatars",
+        )
+        .await
+    }
+
+    /// Switch into an avatar; returns the current user wearing it
+    pub async fn select_avatar(&self, avatar_id: &str) -> VRCResult<User> {
+        self.put_avatar_selection(avatar_id, "select").await
+    }
+
+    /// Set the avatar shown to users who cannot load the current one
+    pub async fn select_fallback_avatar(&self, avatar_id: &str) -> VRCResult<User> {
+        self.put_avatar_selection(avatar_id, "selectFallback").await
+    }
+
+    async fn put_avatar_selection(&self, avatar_id: &str, action: &str) -> VRCResult<User> {
+        let headers = self.session_headers().await?;
+
+        let response = self
+            .execute_request(
+                self.http_client
+                    .put(self.api_url(&format!("/avatars/{}/{}", avatar_id, action)))
+                    .headers(headers),
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(Self::api_error(
+                response,
+                &format!("Failed to select avatar {}", avatar_id),
+            )
+            .await);
+        }
+
+        let user: User = response.json().await?;
+        Ok(user)
+    }
+
+    /// Search worlds; returns a single page as selected by `params.n` and `params.offset`
+    pub async fn search_worlds(&self, params: &WorldSearchParams) -> VRCResult<Vec<LimitedWorld>> {
+        let headers = self.session_headers().await?;
+
+        let response = self
+            .execute_request(
+                self.http_client
+                    .get(self.api_url(params.source.path()))
+                    .headers(headers)
+                    .query(&params.query_pairs()),
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(Self::api_error(response, "Failed to search worlds").await);
+        }
+
+        let worlds: Vec<LimitedWorld> = response.json().await?;
+        Ok(worlds)
+    }
+
     /// Fetch additional details for a specific world
     pub async fn get_world_details(&self, world_id: &str) -> VRCResult<LimitedWorld> {
         let cookie_header = {
@@ -504,22 +683,22 @@ impl VRChatClient {
             cookies.to_header_value()
         };
 
-        let cookie = cookie_header.ok_or_else(|| VRCError::auth("Not authenticated"))?;
+        let cookie =
+            cookie_header.ok_or_else(|| VRCError::missing_credentials("Not authenticated"))?;
         let headers = self.build_headers(None, None, Some(&cookie));
 
         let response = self
             .execute_request(
                 self.http_client
-                    .get(&format!("{}/worlds/{}", API_BASE_URL, world_id))
+                    .get(self.api_url(&format!("/worlds/{}", world_id)))
                     .headers(headers),
             )
             .await?;
 
         if !response.status().is_success() {
-            return Err(VRCError::http(
-                response.status().as_u16(),
-                format!("Failed to fetch world {}", world_id),
-            ));
+            return Err(
+                Self::api_error(response, &format!("Failed to fetch world {}", world_id)).await,
+            );
         }
 
         let world: LimitedWorld = response.json().await?;
@@ -533,13 +712,14 @@ impl VRChatClient {
             cookies.to_header_value()
         };
 
-        let cookie = cookie_header.ok_or_else(|| VRCError::auth("Not authenticated"))?;
+        let cookie =
+            cookie_header.ok_or_else(|| VRCError::missing_credentials("Not authenticated"))?;
         let headers = self.build_headers(None, None, Some(&cookie));
 
         let response = self
             .execute_request(
                 self.http_client
-                    .get(&format!("{}/users/{}", API_BASE_URL, user_id))
+                    .get(self.api_url(&format!("/users/{}", user_id)))
                     .headers(headers),
             )
             .await?;
@@ -547,20 +727,15 @@ impl VRChatClient {
         let status = response.status();
 
         if !status.is_success() {
-            let error_body = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unable to read error response".to_string());
+            let error =
+                Self::api_error(response, &format!("Failed to fetch user {}", user_id)).await;
             log::error!(
                 "Failed to fetch user {}: HTTP {} - {}",
                 user_id,
                 status.as_u16(),
-                error_body
+                error
             );
-            return Err(VRCError::http(
-                status.as_u16(),
-                format!("Failed to fetch user {}: {}", user_id, error_body),
-            ));
+            return Err(error);
         }
 
         let body = response.text().await?;
@@ -574,111 +749,1272 @@ impl VRChatClient {
         Ok(user)
     }
 
-    // Session Management
+    /// Search users by display name; returns a single page
+    pub async fn search_users(
+        &self,
+        query: &str,
+        n: u32,
+        offset: u32,
+    ) -> VRCResult<Vec<LimitedUser>> {
+        const MAX_PAGE_SIZE: u32 = 100;
+
+        let query = query.trim();
+        if query.is_empty() {
+            return Err(VRCError::invalid_input("Search query must not be empty"));
+        }
 
-    /// Check if the client has a valid session
-    pub async fn has_valid_session(&self) -> bool {
-        let cookies = self.cookies.lock().await;
-        cookies.has_auth()
+        let headers = self.session_headers().await?;
+        let response = self
+            .execute_request(
+                self.http_client
+                    .get(self.api_url("/users"))
+                    .headers(headers)
+                    .query(&[
+                        ("search", query.to_string()),
+                        ("n", n.clamp(1, MAX_PAGE_SIZE).to_string()),
+                        ("offset", offset.to_string()),
+                    ]),
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(Self::api_error(response, "Failed to search users").await);
+        }
+
+        let users: Vec<LimitedUser> = response.json().await?;
+        Ok(users)
     }
 
-    /// Export cookies for storage
-    pub async fn export_cookies(&self) -> (Option<String>, Option<String>) {
-        let cookies = self.cookies.lock().await;
-        (
-            cookies.auth_cookie.clone(),
-            cookies.two_factor_cookie.clone(),
+    /// Friends the current user shares with `user_id`
+    pub async fn get_mutual_friends(&self, user_id: &str) -> VRCResult<Vec<LimitedUser>> {
+        self.fetch_pages(
+            |n, offset| {
+                format!(
+                    "/users/{}/mutuals/friends?n={}&offset={}",
+                    user_id, n, offset
+                )
+            },
+            &format!("Failed to fetch mutual friends of {}", user_id),
         )
+        .await
     }
 
-    /// Import previously stored cookies
-    pub async fn import_cookies(&self, auth: Option<String>, two_factor: Option<String>) {
-        let mut cookies = self.cookies.lock().await;
-        cookies.auth_cookie = auth;
-        cookies.two_factor_cookie = two_factor;
+    /// The group shown on a user's profile, `None` if they don't represent one
+    pub async fn get_represented_group(
+        &self,
+        user_id: &str,
+    ) -> VRCResult<Option<RepresentedGroup>> {
+        let group: RepresentedGroup = self
+            .get_json(
+                &format!("/users/{}/groups/represented", user_id),
+                &format!("Failed to fetch represented group of {}", user_id),
+            )
+            .await?;
+
+        Ok(group.group_id.is_some().then_some(group))
     }
 
-    /// Clear all stored cookies
-    pub async fn clear_cookies(&self) {
-        let mut cookies = self.cookies.lock().await;
-        cookies.clear();
+    /// Public worlds uploaded by a user, most recently updated first
+    pub async fn get_public_worlds(&self, user_id: &str) -> VRCResult<Vec<LimitedWorld>> {
+        self.fetch_pages(
+            |n, offset| {
+                format!(
+                    "/worlds?userId={}&releaseStatus=public&sort=updated&order=descending&n={}&offset={}",
+                    user_id, n, offset
+                )
+            },
+            &format!("Failed to fetch worlds of {}", user_id),
+        )
+        .await
     }
 
-    // Private Helper Methods
+    /// Public avatars uploaded by a user, most recently updated first
+    pub async fn get_public_avatars(&self, user_id: &str) -> VRCResult<Vec<LimitedAvatar>> {
+        self.fetch_pages(
+            |n, offset| {
+                format!(
+                    "/avatars?userId={}&releaseStatus=public&sort=updated&order=descending&n={}&offset={}",
+                    user_id, n, offset
+                )
+            },
+            &format!("Failed to fetch avatars of {}", user_id),
+        )
+        .await
+    }
 
-    fn create_basic_auth(email: &str, password: &str) -> String {
-        let credentials = format!("{}:{}", email, password);
-        let encoded = BASE64.encode(credentials.as_bytes());
-        format!("Basic {}", encoded)
+    /// Fetch a user together with everything shown on their profile. Only the user lookup is
+    /// required; other sections that fail are left empty and listed in `unavailable`.
+    pub async fn get_user_profile(&self, user_id: &str) -> VRCResult<UserProfile> {
+        fn section<T: Default>(
+            result: VRCResult<T>,
+            name: &str,
+            user_id: &str,
+            unavailable: &mut Vec<String>,
+        ) -> T {
+            result.unwrap_or_else(|e| {
+                log::warn!("Failed to load {} of {}: {}", name, user_id, e);
+                unavailable.push(name.to_string());
+                T::default()
+            })
+        }
+
+        let (user, mutual_friends, groups, represented_group, worlds, avatars) = tokio::join!(
+            self.get_user_by_id(user_id),
+            self.get_mutual_friends(user_id),
+            self.get_user_groups(user_id),
+            self.get_represented_group(user_id),
+            self.get_public_worlds(user_id),
+            self.get_public_avatars(user_id),
+        );
+
+        let user = user?;
+        let mut unavailable = Vec::new();
+
+        Ok(UserProfile {
+            user,
+            mutual_friends: section(mutual_friends, "mutualFriends", user_id, &mut unavailable),
+            groups: section(groups, "groups", user_id, &mut unavailable),
+            represented_group: section(
+                represented_group,
+                "representedGroup",
+                user_id,
+                &mut unavailable,
+            ),
+            worlds: section(worlds, "worlds", user_id, &mut unavailable),
+            avatars: section(avatars, "avatars", user_id, &mut unavailable),
+            unavailable,
+        })
     }
 
-    fn build_headers(
-        &self,
-        auth: Option<&str>,
-        _referer: Option<&str>,
-        cookie: Option<&str>,
-    ) -> HeaderMap {
-        crate::http_common::build_api_headers(auth, cookie)
+    // Instances
+
+    /// Fetch an instance by its full location (`worldId:instanceId`)
+    pub async fn get_instance(&self, location: &str) -> VRCResult<Instance> {
+        Self::check_instance_location(location)?;
+        let headers = self.session_headers().await?;
+
+        let response = self
+            .execute_request(
+                self.http_client
+                    .get(self.api_url(&format!("/instances/{}", location)))
+                    .headers(headers),
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(Self::api_error(
+                response,
+                &format!("Failed to fetch instance {}", location),
+            )
+            .await);
+        }
+
+        let instance: Instance = response.json().await?;
+        Ok(instance)
     }
 
-    async fn execute_request(&self, builder: RequestBuilder) -> VRCResult<Response> {
-        let request = builder
-            .build()
-            .map_err(|e| VRCError::network(format!("Failed to build request: {}", e)))?;
-        self.send_with_retry(request).await
+    /// Fetch the short and secure names used for `vrchat.com/i/...` links
+    pub async fn get_instance_short_name(&self, location: &str) -> VRCResult<InstanceShortName> {
+        Self::check_instance_location(location)?;
+        let headers = self.session_headers().await?;
+
+        let response = self
+            .execute_request(
+                self.http_client
+                    .get(self.api_url(&format!("/instances/{}/shortName", location)))
+                    .headers(headers),
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(Self::api_error(
+                response,
+                &format!("Failed to fetch short name for {}", location),
+            )
+            .await);
+        }
+
+        let short_name: InstanceShortName = response.json().await?;
+        Ok(short_name)
     }
 
-    async fn send_with_retry(&self, request: Request) -> VRCResult<Response> {
-        let mut attempt: u8 = 0;
-        let mut backoff = Duration::from_millis(INITIAL_BACKOFF);
+    /// Create a new instance of a world
+    pub async fn create_instance(&self, request: &CreateInstanceRequest) -> VRCResult<Instance> {
+        let headers = self.session_headers().await?;
 
-        loop {
-            let req = request
-                .try_clone()
-                .ok_or_else(|| VRCError::network("Failed to clone request for retry attempts"))?;
+        let response = self
+            .execute_request(
+                self.http_client
+                    .post(self.api_url("/instances"))
+                    .headers(headers)
+                    .json(request),
+            )
+            .await?;
 
-            match self.http_client.execute(req).await {
-                Ok(response) => {
-                    let status = response.status();
+        if !response.status().is_success() {
+            return Err(Self::api_error(response, "Failed to create instance").await);
+        }
 
-                    if status.as_u16() == 429 {
-                        if attempt >= MAX_REQUEST_RETRIES {
-                            return Err(VRCError::rate_limit(
-                                "Too many requests. Please wait before trying again.",
-                            ));
-                        }
+        let instance: Instance = response.json().await?;
+        Ok(instance)
+    }
 
-                        let wait = Self::extract_retry_after(&response).unwrap_or(backoff);
-                        drop(response);
-                        sleep(wait).await;
-                        attempt += 1;
-                        backoff = (backoff * 2).min(Duration::from_millis(MAX_BACKOFF));
-                        continue;
-                    }
+    // Friend Requests
 
-                    if status.is_server_error() {
-                        if attempt >= MAX_REQUEST_RETRIES {
-                            return Err(VRCError::http(
-                                status.as_u16(),
-                                status
-                                    .canonical_reason()
-                                    .unwrap_or("Server error")
-                                    .to_string(),
-                            ));
-                        }
+    pub async fn send_friend_request(&self, user_id: &str) -> VRCResult<SentNotification> {
+        let headers = self.session_headers().await?;
 
-                        let wait = Self::extract_retry_after(&response).unwrap_or(backoff);
-                        drop(response);
-                        sleep(wait).await;
-                        attempt += 1;
-                        backoff = (backoff * 2).min(Duration::from_millis(MAX_BACKOFF));
-                        continue;
+        let response = self
+            .execute_request(
+                self.http_client
+                    .post(self.api_url(&format!("/user/{}/friendRequest", user_id)))
+                    .headers(headers),
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(Self::api_error(
+                response,
+                &format!("Failed to send friend request to {}", user_id),
+            )
+            .await);
+        }
+
+        let notification: SentNotification = response.json().await?;
+        Ok(notification)
+    }
+
+    /// Withdraw an outgoing friend request
+    pub async fn cancel_friend_request(&self, user_id: &str) -> VRCResult<()> {
+        self.send_action(
+            reqwest::Method::DELETE,
+            &format!("/user/{}/friendRequest", user_id),
+            None,
+            &format!("Failed to cancel friend request to {}", user_id),
+        )
+        .await
+    }
+
+    pub async fn get_friend_status(&self, user_id: &str) -> VRCResult<FriendStatus> {
+        let headers = self.session_headers().await?;
+
+        let response = self
+            .execute_request(
+                self.http_client
+                    .get(self.api_url(&format!("/user/{}/friendStatus", user_id)))
+                    .headers(headers),
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(Self::api_error(
+                response,
+                &format!("Failed to fetch friend status for {}", user_id),
+            )
+            .await);
+        }
+
+        let status: FriendStatus = response.json().await?;
+        Ok(status)
+    }
+
+    pub async fn unfriend(&self, user_id: &str) -> VRCResult<()> {
+        self.send_action(
+            reqwest::Method::DELETE,
+            &format!("/auth/user/friends/{}", user_id),
+            None,
+            &format!("Failed to unfriend {}", user_id),
+        )
+        .await
+    }
+
+    // Favorites
+
+    pub async fn get_favorites(&self, kind: Option<FavoriteType>) -> VRCResult<Vec<Favorite>> {
+        let filter = kind.map(|k| format!("&type={}", k)).unwrap_or_default();
+
+        self.fetch_pages(
+            |n, offset| format!("/favorites?n={}&offset={}{}", n, offset, filter),
+            "Failed to fetch favorites",
+        )
+        .await
+    }
+
+    pub async fn add_favorite(&self, request: &AddFavoriteRequest) -> VRCResult<Favorite> {
+        let headers = self.session_headers().await?;
+
+        let response = self
+            .execute_request(
+                self.http_client
+                    .post(self.api_url("/favorites"))
+                    .headers(headers)
+                    .json(request),
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(Self::api_error(
+                response,
+                &format!("Failed to favorite {}", request.favorite_id),
+            )
+            .await);
+        }
+
+        let favorite: Favorite = response.json().await?;
+        Ok(favorite)
+    }
+
+    /// Remove a favorite by its record ID (`fvrt_...`), not the favorited object ID
+    pub async fn remove_favorite(&self, favorite_id: &str) -> VRCResult<()> {
+        self.send_action(
+            reqwest::Method::DELETE,
+            &format!("/favorites/{}", favorite_id),
+            None,
+            &format!("Failed to remove favorite {}", favorite_id),
+        )
+        .await
+    }
+
+    pub async fn get_favorite_groups(&self) -> VRCResult<Vec<FavoriteGroup>> {
+        self.fetch_pages(
+            |n, offset| format!("/favorite/groups?n={}&offset={}", n, offset),
+            "Failed to fetch favorite groups",
+        )
+        .await
+    }
+
+    /// Rename a favorite group or change its visibility
+    pub async fn update_favorite_group(
+        &self,
+        kind: FavoriteType,
+        name: &str,
+        user_id: &str,
+        request: &UpdateFavoriteGroupRequest,
+    ) -> VRCResult<()> {
+        self.send_action(
+            reqwest::Method::PUT,
+            &format!("/favorite/group/{}/{}/{}", kind, name, user_id),
+            Some(serde_json::to_value(request)?),
+            &format!("Failed to update favorite group {}", name),
+        )
+        .await
+    }
+
+    /// Remove every favorite from a group, the group itself stays
+    pub async fn clear_favorite_group(
+        &self,
+        kind: FavoriteType,
+        name: &str,
+        user_id: &str,
+    ) -> VRCResult<()> {
+        self.send_action(
+            reqwest::Method::DELETE,
+            &format!("/favorite/group/{}/{}/{}", kind, name, user_id),
+            None,
+            &format!("Failed to clear favorite group {}", name),
+        )
+        .await
+    }
+
+    pub async fn get_favorite_limits(&self) -> VRCResult<FavoriteLimits> {
+        let headers = self.session_headers().await?;
+
+        let response = self
+            .execute_request(
+                self.http_client
+                    .get(self.api_url("/auth/user/favoritelimits"))
+                    .headers(headers),
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(Self::api_error(response, "Failed to fetch favorite limits").await);
+        }
+
+        let limits: FavoriteLimits = response.json().await?;
+        Ok(limits)
+    }
+
+    // Player Moderations
+
+    pub async fn get_player_moderations(&self) -> VRCResult<Vec<PlayerModeration>> {
+        let headers = self.session_headers().await?;
+
+        let response = self
+            .execute_request(
+                self.http_client
+                    .get(self.api_url("/auth/user/playermoderations"))
+                    .headers(headers),
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(Self::api_error(response, "Failed to fetch player moderations").await);
+        }
+
+        let moderations: Vec<PlayerModeration> = response.json().await?;
+        Ok(moderations)
+    }
+
+    pub async fn moderate_user(
+        &self,
+        user_id: &str,
+        kind: PlayerModerationType,
+    ) -> VRCResult<PlayerModeration> {
+        Self::check_moderation_kind(kind)?;
+        let headers = self.session_headers().await?;
+        let body = ModerateUserRequest {
+            moderated: user_id.to_string(),
+            kind,
+        };
+
+        let response = self
+            .execute_request(
+                self.http_client
+                    .post(self.api_url("/auth/user/playermoderations"))
+                    .headers(headers)
+                    .json(&body),
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(Self::api_error(
+                response,
+                &format!("Failed to apply {} to {}", kind, user_id),
+            )
+            .await);
+        }
+
+        let moderation: PlayerModeration = response.json().await?;
+        Ok(moderation)
+    }
+
+    /// Remove a moderation of the given type from a user
+    pub async fn unmoderate_user(
+        &self,
+        user_id: &str,
+        kind: PlayerModerationType,
+    ) -> VRCResult<()> {
+        Self::check_moderation_kind(kind)?;
+        let body = ModerateUserRequest {
+            moderated: user_id.to_string(),
+            kind,
+        };
+
+        self.send_action(
+            reqwest::Method::PUT,
+            "/auth/user/unplayermoderate",
+            Some(serde_json::to_value(&body)?),
+            &format!("Failed to remove {} from {}", kind, user_id),
+        )
+        .await
+    }
+
+    // Groups
+
+    /// Groups a user is a member of; pass the current user's ID for "my groups"
+    pub async fn get_user_groups(&self, user_id: &str) -> VRCResult<Vec<UserGroup>> {
+        self.get_json(
+            &format!("/users/{}/groups", user_id),
+            &format!("Failed to fetch groups of {}", user_id),
+        )
+        .await
+    }
+
+    /// Fetch a group including its roles
+    pub async fn get_group(&self, group_id: &str) -> VRCResult<Group> {
+        self.get_json(
+            &format!("/groups/{}?includeRoles=true", group_id),
+            &format!("Failed to fetch group {}", group_id),
+        )
+        .await
+    }
+
+    /// List group members; returns a single page, newest members first
+    pub async fn get_group_members(
+        &self,
+        group_id: &str,
+        n: u32,
+        offset: u32,
+    ) -> VRCResult<Vec<GroupMember>> {
+        self.get_json(
+            &format!(
+                "/groups/{}/members?n={}&offset={}&sort=joinedAt:desc",
+                group_id, n, offset
+            ),
+            &format!("Failed to fetch members of group {}", group_id),
+        )
+        .await
+    }
+
+    pub async fn get_group_roles(&self, group_id: &str) -> VRCResult<Vec<GroupRole>> {
+        self.get_json(
+            &format!("/groups/{}/roles", group_id),
+            &format!("Failed to fetch roles of group {}", group_id),
+        )
+        .await
+    }
+
+    /// Latest announcement of a group, `None` if it never posted one
+    pub async fn get_group_announcement(
+        &self,
+        group_id: &str,
+    ) -> VRCResult<Option<GroupAnnouncement>> {
+        let announcement: GroupAnnouncement = self
+            .get_json(
+                &format!("/groups/{}/announcements", group_id),
+                &format!("Failed to fetch announcement of group {}", group_id),
+            )
+            .await?;
+
+        Ok(announcement.id.is_some().then_some(announcement))
+    }
+
+    /// List group posts; returns a single page, newest first
+    pub async fn get_group_posts(
+        &self,
+        group_id: &str,
+        n: u32,
+        offset: u32,
+    ) -> VRCResult<Vec<GroupPost>> {
+        let page: GroupPostPage = self
+            .get_json(
+                &format!("/groups/{}/posts?n={}&offset={}", group_id, n, offset),
+                &format!("Failed to fetch posts of group {}", group_id),
+            )
+            .await?;
+
+        Ok(page.posts)
+    }
+
+    /// Group instances the current user can see
+    pub async fn get_group_instances(&self, group_id: &str) -> VRCResult<Vec<GroupInstance>> {
+        self.get_json(
+            &format!("/groups/{}/instances", group_id),
+            &format!("Failed to fetch instances of group {}", group_id),
+        )
+        .await
+    }
+
+    // Group Moderation
+
+    pub async fn ban_group_member(&self, group_id: &str, user_id: &str) -> VRCResult<GroupMember> {
+        let headers = self.session_headers().await?;
+
+        let response = self
+            .execute_request(
+                self.http_client
+                    .post(self.api_url(&format!("/groups/{}/bans", group_id)))
+                    .headers(headers)
+                    .json(&serde_json::json!({ "userId": user_id })),
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(Self::api_error(
+                response,
+                &format!("Failed to ban {} from group {}", user_id, group_id),
+            )
+            .await);
+        }
+
+        let member: GroupMember = response.json().await?;
+        Ok(member)
+    }
+
+    pub async fn unban_group_member(&self, group_id: &str, user_id: &str) -> VRCResult<()> {
+        self.send_action(
+            reqwest::Method::DELETE,
+            &format!("/groups/{}/bans/{}", group_id, user_id),
+            None,
+            &format!("Failed to unban {} from group {}", user_id, group_id),
+        )
+        .await
+    }
+
+    /// List banned members; returns a single page
+    pub async fn get_group_bans(
+        &self,
+        group_id: &str,
+        n: u32,
+        offset: u32,
+    ) -> VRCResult<Vec<GroupMember>> {
+        self.get_json(
+            &format!("/groups/{}/bans?n={}&offset={}", group_id, n, offset),
+            &format!("Failed to fetch bans of group {}", group_id),
+        )
+        .await
+    }
+
+    /// Remove a member from the group; unlike a ban they can rejoin
+    pub async fn kick_group_member(&self, group_id: &str, user_id: &str) -> VRCResult<()> {
+        self.send_action(
+            reqwest::Method::DELETE,
+            &format!("/groups/{}/members/{}", group_id, user_id),
+            None,
+            &format!("Failed to kick {} from group {}", user_id, group_id),
+        )
+        .await
+    }
+
+    /// List pending join requests; returns a single page
+    pub async fn get_group_join_requests(
+        &self,
+        group_id: &str,
+        n: u32,
+        offset: u32,
+    ) -> VRCResult<Vec<GroupMember>> {
+        self.get_json(
+            &format!("/groups/{}/requests?n={}&offset={}", group_id, n, offset),
+            &format!("Failed to fetch join requests of group {}", group_id),
+        )
+        .await
+    }
+
+    /// Approve or reject a join request; `block` stops a rejected user from asking again
+    pub async fn respond_group_join_request(
+        &self,
+        group_id: &str,
+        user_id: &str,
+        action: GroupJoinRequestAction,
+        block: bool,
+    ) -> VRCResult<()> {
+        if block && action != GroupJoinRequestAction::Reject {
+            return Err(VRCError::invalid_input(
+                "Only rejected join requests can be blocked",
+            ));
+        }
+
+        let body = RespondGroupJoinRequest {
+            action,
+            block: block.then_some(true),
+        };
+
+        self.send_action(
+            reqwest::Method::PUT,
+            &format!("/groups/{}/requests/{}", group_id, user_id),
+            Some(serde_json::to_value(&body)?),
+            &format!("Failed to answer join request of {}", user_id),
+        )
+        .await
+    }
+
+    /// Give a member a role; returns the member's role IDs afterwards
+    pub async fn add_group_member_role(
+        &self,
+        group_id: &str,
+        user_id: &str,
+        role_id: &str,
+    ) -> VRCResult<Vec<String>> {
+        self.update_group_member_role(reqwest::Method::PUT, group_id, user_id, role_id)
+            .await
+    }
+
+    /// Take a role from a member; returns the member's role IDs afterwards
+    pub async fn remove_group_member_role(
+        &self,
+        group_id: &str,
+        user_id: &str,
+        role_id: &str,
+    ) -> VRCResult<Vec<String>> {
+        self.update_group_member_role(reqwest::Method::DELETE, group_id, user_id, role_id)
+            .await
+    }
+
+    async fn update_group_member_role(
+        &self,
+        method: reqwest::Method,
+        group_id: &str,
+        user_id: &str,
+        role_id: &str,
+    ) -> VRCResult<Vec<String>> {
+        let headers = self.session_headers().await?;
+
+        let response = self
+            .execute_request(
+                self.http_client
+                    .request(
+                        method,
+                        self.api_url(&format!(
+                            "/groups/{}/members/{}/roles/{}",
+                            group_id, user_id, role_id
+                        )),
+                    )
+                    .headers(headers),
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(Self::api_error(
+                response,
+                &format!("Failed to update role {} of {}", role_id, user_id),
+            )
+            .await);
+        }
+
+        let role_ids: Vec<String> = response.json().await?;
+        Ok(role_ids)
+    }
+
+    /// Fetch one page of the audit log, newest first, optionally only entries after `since`
+    pub async fn get_group_audit_logs(
+        &self,
+        group_id: &str,
+        n: u32,
+        offset: u32,
+        since: Option<&str>,
+    ) -> VRCResult<GroupAuditLogPage> {
+        let headers = self.session_headers().await?;
+        let mut query = vec![("n", n.to_string()), ("offset", offset.to_string())];
+        if let Some(since) = since {
+            query.push(("startDate", since.to_string()));
+        }
+
+        let response = self
+            .execute_request(
+                self.http_client
+                    .get(self.api_url(&format!("/groups/{}/auditLogs", group_id)))
+                    .headers(headers)
+                    .query(&query),
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(Self::api_error(
+                response,
+                &format!("Failed to fetch audit log of group {}", group_id),
+            )
+            .await);
+        }
+
+        let page: GroupAuditLogPage = response.json().await?;
+        Ok(page)
+    }
+
+    /// Fetch every audit log page, optionally only entries after `since`
+    pub async fn get_all_group_audit_logs(
+        &self,
+        group_id: &str,
+        since: Option<&str>,
+    ) -> VRCResult<Vec<GroupAuditLogEntry>> {
+        const PAGE_SIZE: u32 = 100;
+
+        let mut entries = Vec::new();
+        let mut offset = 0;
+
+        loop {
+            let page = self
+                .get_group_audit_logs(group_id, PAGE_SIZE, offset, since)
+                .await?;
+            let count = page.results.len() as u32;
+            entries.extend(page.results);
+
+            if !page.has_next || count == 0 {
+                break;
+            }
+            offset += count;
+        }
+
+        Ok(entries)
+    }
+
+    // Invites
+
+    /// Invite a user to an instance, optionally with one of the invite message slots
+    pub async fn invite_user(
+        &self,
+        user_id: &str,
+        location: &str,
+        message_slot: Option<u32>,
+    ) -> VRCResult<SentNotification> {
+        Self::check_instance_location(location)?;
+        Self::check_message_slot(message_slot)?;
+        let body = InviteRequest {
+            instance_id: location.to_string(),
+            message_slot,
+        };
+
+        self.post_invite(
+            &format!("/invite/{}", user_id),
+            &body,
+            &format!("Failed to invite {}", user_id),
+        )
+        .await
+    }
+
+    /// Ask a user to invite us, optionally with one of the request message slots
+    pub async fn request_invite(
+        &self,
+        user_id: &str,
+        message_slot: Option<u32>,
+    ) -> VRCResult<SentNotification> {
+        Self::check_message_slot(message_slot)?;
+        let body = RequestInviteRequest { message_slot };
+
+        self.post_invite(
+            &format!("/requestInvite/{}", user_id),
+            &body,
+            &format!("Failed to request an invite from {}", user_id),
+        )
+        .await
+    }
+
+    /// Send ourselves an invite, which the game client uses to join the instance
+    pub async fn invite_myself(&self, location: &str) -> VRCResult<SentNotification> {
+        Self::check_instance_location(location)?;
+
+        self.post_invite(
+            &format!("/invite/myself/to/{}", location),
+            &serde_json::json!({}),
+            &format!("Failed to invite yourself to {}", location),
+        )
+        .await
+    }
+
+    /// Answer an invite or invite request with one of the response message slots
+    pub async fn respond_to_invite(
+        &self,
+        notification_id: &str,
+        response_slot: u32,
+    ) -> VRCResult<SentNotification> {
+        Self::check_message_slot(Some(response_slot))?;
+        let body = InviteResponseRequest { response_slot };
+
+        self.post_invite(
+            &format!("/invite/{}/response", notification_id),
+            &body,
+            "Failed to respond to invite",
+        )
+        .await
+    }
+
+    async fn post_invite<T: serde::Serialize + ?Sized>(
+        &self,
+        path: &str,
+        body: &T,
+        fallback: &str,
+    ) -> VRCResult<SentNotification> {
+        let headers = self.session_headers().await?;
+
+        let response = self
+            .execute_request(
+                self.http_client
+                    .post(self.api_url(path))
+                    .headers(headers)
+                    .json(body),
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(Self::api_error(response, fallback).await);
+        }
+
+        let notification: SentNotification = response.json().await?;
+        Ok(notification)
+    }
+
+    fn check_moderation_kind(kind: PlayerModerationType) -> VRCResult<()> {
+        match kind {
+            PlayerModerationType::Unknown => Err(VRCError::invalid_input(
+                "Unknown moderation types can't be sent to VRChat",
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    fn check_message_slot(slot: Option<u32>) -> VRCResult<()> {
+        match slot {
+            Some(slot) if slot > MAX_MESSAGE_SLOT => Err(VRCError::invalid_input(format!(
+                "Message slot must be between 0 and {}",
+                MAX_MESSAGE_SLOT
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    // Notifications
+
+    /// Fetch the v1 notification list (friend requests, invites, ...)
+    pub async fn get_notifications(&self, hidden: bool) -> VRCResult<Vec<NotificationPayload>> {
+        self.fetch_pages(
+            |n, offset| {
+                format!(
+                    "/auth/user/notifications?type=all&hidden={}&n={}&offset={}",
+                    hidden, n, offset
+                )
+            },
+            "Failed to fetch notifications",
+        )
+        .await
+    }
+
+    /// Fetch the v2 notification list (group announcements, system messages, ...)
+    pub async fn get_notifications_v2(&self) -> VRCResult<Vec<NotificationV2Payload>> {
+        self.fetch_pages(
+            |n, offset| format!("/notifications?n={}&offset={}", n, offset),
+            "Failed to fetch notifications",
+        )
+        .await
+    }
+
+    pub async fn see_notification(&self, notification_id: &str) -> VRCResult<()> {
+        self.send_action(
+            reqwest::Method::PUT,
+            &format!("/auth/user/notifications/{}/see", notification_id),
+            None,
+            "Failed to mark notification as seen",
+        )
+        .await
+    }
+
+    pub async fn see_notification_v2(&self, notification_id: &str) -> VRCResult<()> {
+        self.send_action(
+            reqwest::Method::POST,
+            &format!("/notifications/{}/see", notification_id),
+            None,
+            "Failed to mark notification as seen",
+        )
+        .await
+    }
+
+    pub async fn hide_notification(&self, notification_id: &str) -> VRCResult<()> {
+        self.send_action(
+            reqwest::Method::PUT,
+            &format!("/auth/user/notifications/{}/hide", notification_id),
+            None,
+            "Failed to hide notification",
+        )
+        .await
+    }
+
+    pub async fn delete_notification_v2(&self, notification_id: &str) -> VRCResult<()> {
+        self.send_action(
+            reqwest::Method::DELETE,
+            &format!("/notifications/{}", notification_id),
+            None,
+            "Failed to delete notification",
+        )
+        .await
+    }
+
+    /// Clear every v1 notification
+    pub async fn clear_notifications(&self) -> VRCResult<()> {
+        self.send_action(
+            reqwest::Method::PUT,
+            "/auth/user/notifications/clear",
+            None,
+            "Failed to clear notifications",
+        )
+        .await
+    }
+
+    /// Accept the friend request carried by a `friendRequest` notification
+    pub async fn accept_friend_request(&self, notification_id: &str) -> VRCResult<()> {
+        self.send_action(
+            reqwest::Method::PUT,
+            &format!("/auth/user/notifications/{}/accept", notification_id),
+            None,
+            "Failed to accept friend request",
+        )
+        .await
+    }
+
+    /// Trigger one of the actions offered in a v2 notification's `responses`
+    pub async fn respond_notification_v2(
+        &self,
+        notification_id: &str,
+        response: &NotificationV2Response,
+    ) -> VRCResult<()> {
+        let response_type = response
+            .kind
+            .as_deref()
+            .ok_or_else(|| VRCError::invalid_input("Notification response has no type"))?;
+        let body = serde_json::json!({
+            "responseType": response_type,
+            "responseData": response.data.clone().unwrap_or_default(),
+        });
+
+        self.send_action(
+            reqwest::Method::POST,
+            &format!("/notifications/{}/respond", notification_id),
+            Some(body),
+            "Failed to respond to notification",
+        )
+        .await
+    }
+
+    /// GET an `n`/`offset` paged list until a short page comes back
+    async fn fetch_pages<T: serde::de::DeserializeOwned>(
+        &self,
+        path: impl Fn(usize, usize) -> String,
+        fallback: &str,
+    ) -> VRCResult<Vec<T>> {
+        const PAGE_SIZE: usize = 100;
+        let mut results = Vec::new();
+        let mut offset = 0usize;
+
+        loop {
+            let headers = self.session_headers().await?;
+            let response = self
+                .execute_request(
+                    self.http_client
+                        .get(self.api_url(&path(PAGE_SIZE, offset)))
+                        .headers(headers),
+                )
+                .await?;
+
+            if !response.status().is_success() {
+                return Err(Self::api_error(response, fallback).await);
+            }
+
+            let page: Vec<T> = response.json().await?;
+            let count = page.len();
+            results.extend(page);
+
+            if count < PAGE_SIZE {
+                break;
+            }
+
+            offset += PAGE_SIZE;
+        }
+
+        Ok(results)
+    }
+
+    /// GET a single JSON document
+    async fn get_json<T: serde::de::DeserializeOwned>(
+        &self,
+        path: &str,
+        fallback: &str,
+    ) -> VRCResult<T> {
+        let headers = self.session_headers().await?;
+        let response = self
+            .execute_request(self.http_client.get(self.api_url(path)).headers(headers))
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(Self::api_error(response, fallback).await);
+        }
+
+        Ok(response.json().await?)
+    }
+
+    /// Send a request whose response body we do not need
+    async fn send_action(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<serde_json::Value>,
+        fallback: &str,
+    ) -> VRCResult<()> {
+        let headers = self.session_headers().await?;
+        let mut builder = self
+            .http_client
+            .request(method, self.api_url(path))
+            .headers(headers);
+        if let Some(body) = body {
+            builder = builder.json(&body);
+        }
+
+        let response = self.execute_request(builder).await?;
+
+        if !response.status().is_success() {
+            return Err(Self::api_error(response, fallback).await);
+        }
+
+        Ok(())
+    }
+
+    // Session Management
+
+    /// Check if the client has a valid session
+    pub async fn has_valid_session(&self) -> bool {
+        let cookies = self.cookies.lock().await;
+        cookies.has_auth()
+    }
+
+    /// Export cookies for storage
+    pub async fn export_cookies(&self) -> (Option<String>, Option<String>) {
+        let cookies = self.cookies.lock().await;
+        (
+            cookies.auth_cookie.clone(),
+            cookies.two_factor_cookie.clone(),
+        )
+    }
+
+    /// Import previously stored cookies
+    pub async fn import_cookies(&self, auth: Option<String>, two_factor: Option<String>) {
+        let mut cookies = self.cookies.lock().await;
+        self.session_active.store(auth.is_some(), Ordering::SeqCst);
+        cookies.auth_cookie = auth;
+        cookies.two_factor_cookie = two_factor;
+    }
+
+    /// Clear all stored cookies
+    pub async fn clear_cookies(&self) {
+        let mut cookies = self.cookies.lock().await;
+        cookies.clear();
+        self.session_active.store(false, Ordering::SeqCst);
+    }
+
+    // Private Helper Methods
+
+    fn create_basic_auth(email: &str, password: &str) -> String {
+        let credentials = format!("{}:{}", email, password);
+        let encoded = BASE64.encode(credentials.as_bytes());
+        format!("Basic {}", encoded)
+    }
+
+    fn api_url(&self, path: &str) -> String {
+        self.config.api_url(path)
+    }
+
+    fn build_headers(
+        &self,
+        auth: Option<&str>,
+        _referer: Option<&str>,
+        cookie: Option<&str>,
+    ) -> HeaderMap {
+        crate::http_common::build_api_headers(&self.config.user_agent, auth, cookie)
+    }
+
+    /// Headers for an endpoint that requires a logged in session
+    async fn session_headers(&self) -> VRCResult<HeaderMap> {
+        let cookie_header = {
+            let cookies = self.cookies.lock().await;
+            cookies.to_header_value()
+        };
+
+        let cookie =
+            cookie_header.ok_or_else(|| VRCError::missing_credentials("Not authenticated"))?;
+        Ok(self.build_headers(None, None, Some(&cookie)))
+    }
+
+    /// Validate that `location` points at a concrete instance
+    fn check_instance_location(location: &str) -> VRCResult<()> {
+        match Location::parse(location) {
+            Location::Instance(_) => Ok(()),
+            _ => Err(VRCError::invalid_input(format!(
+                "Not an instance location: {}",
+                location
+            ))),
+        }
+    }
+
+    /// Turn a failed response into a typed error using VRChat's error envelope
+    async fn api_error(response: Response, fallback: &str) -> VRCError {
+        let status = response.status().as_u16();
+        let body = response.text().await.unwrap_or_default();
+        VRCError::from_api_response(status, &body, fallback)
+    }
+
+    /// Whether the request carries an `auth` session cookie
+    fn carries_session(request: &Request) -> bool {
+        request
+            .headers()
+            .get_all(reqwest::header::COOKIE)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(';'))
+            .any(|pair| pair.trim_start().starts_with("auth="))
+    }
+
+    /// A 401 on a request that carried session cookies means the session itself is no
+    /// longer valid. Notify subscribers once, then surface it as `SessionExpired`.
+    async fn session_expired(&self, response: Response) -> VRCError {
+        let message = match Self::api_error(response, "Session expired").await {
+            VRCError::Authentication(message) | VRCError::MissingCredentials(message) => message,
+            other => other.message().to_string(),
+        };
+
+        if self.session_active.swap(false, Ordering::SeqCst) {
+            log::warn!("VRChat session expired: {}", message);
+            let _ = self.session_expired_tx.send(SessionExpiredNotice {
+                message: message.clone(),
+            });
+        }
+
+        VRCError::session_expired(message)
+    }
+
+    async fn execute_request(&self, builder: RequestBuilder) -> VRCResult<Response> {
+        let request = builder
+            .build()
+            .map_err(|e| VRCError::network(format!("Failed to build request: {}", e)))?;
+        self.send_with_retry(request).await
+    }
+
+    /// Endpoint class of a request, based on its path below the API root
+    fn endpoint_class(&self, request: &Request) -> EndpointClass {
+        let base_path = url::Url::parse(&self.config.api_base_url)
+            .map(|url| url.path().trim_end_matches('/').to_string())
+            .unwrap_or_default();
+        let path = request.url().path();
+
+        EndpointClass::from_api_path(path.strip_prefix(&base_path).unwrap_or(path))
+    }
+
+    async fn send_with_retry(&self, request: Request) -> VRCResult<Response> {
+        let retry = self.config.retry;
+        let class = self.endpoint_class(&request);
+        let mut attempt: u8 = 0;
+        let mut backoff = retry.initial_backoff;
+
+        loop {
+            self.rate_limiter.acquire(class).await;
+
+            let req = request
+                .try_clone()
+                .ok_or_else(|| VRCError::network("Failed to clone request for retry attempts"))?;
+
+            match self.http_client.execute(req).await {
+                Ok(response) => {
+                    let status = response.status();
+
+                    if status.as_u16() == 429 {
+                        // Hold back every caller, not just this request
+                        let wait = Self::extract_retry_after(&response).unwrap_or(backoff);
+                        self.rate_limiter.pause_for(wait);
+
+                        if attempt >= retry.max_retries {
+                            return Err(VRCError::rate_limit(
+                                "Too many requests. Please wait before trying again.",
+                            ));
+                        }
+
+                        drop(response);
+                        attempt += 1;
+                        backoff = retry.next_backoff(backoff);
+                        continue;
+                    }
+
+                    if status.is_server_error() {
+                        if attempt >= retry.max_retries {
+                            return Err(VRCError::http(
+                                status.as_u16(),
+                                status
+                                    .canonical_reason()
+                                    .unwrap_or("Server error")
+                                    .to_string(),
+                            ));
+                        }
+
+                        let wait = Self::extract_retry_after(&response).unwrap_or(backoff);
+                        drop(response);
+                        sleep(wait).await;
+                        attempt += 1;
+                        backoff = retry.next_backoff(backoff);
+                        continue;
+                    }
+
+                    if status.as_u16() == 401 && Self::carries_session(&request) {
+                        return Err(self.session_expired(response).await);
                     }
 
                     return Ok(response);
                 }
                 Err(err) => {
-                    if attempt >= MAX_REQUEST_RETRIES {
+                    if attempt >= retry.max_retries {
                         return Err(VRCError::network(format!(
                             "Request failed after retries: {}",
                             err
@@ -687,7 +2023,7 @@ impl VRChatClient {
 
                     sleep(backoff).await;
                     attempt += 1;
-                    backoff = (backoff * 2).min(Duration::from_millis(MAX_BACKOFF));
+                    backoff = retry.next_backoff(backoff);
                 }
             }
         }
@@ -705,6 +2041,6 @@ impl VRChatClient {
 
 impl Default for VRChatClient {
     fn default() -> Self {
-        Self::new().expect("Failed to create default VRChatClient")
+        Self::new(VRChatClientConfig::default()).expect("Failed to create default VRChatClient")
     }
 }
@@ -0,0 +1,97 @@
+use std::time::Duration;
+
+use crate::http_common::{INITIAL_BACKOFF, MAX_BACKOFF, MAX_REQUEST_RETRIES, USER_AGENT_STRING};
+use crate::rate_limiter::RateLimitConfig;
+
+pub const DEFAULT_API_BASE_URL: &str = "https://api.vrchat.cloud/api/1";
+pub const DEFAULT_PIPELINE_BASE_URL: &str = "wss://pipeline.vrchat.cloud/";
+
+/// Retry behaviour for transient failures (429, 5xx and network errors)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub max_retries: u8,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// Policy that fails on the first transient error
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            initial_backoff: Duration::ZERO,
+            max_backoff: Duration::ZERO,
+        }
+    }
+
+    /// Backoff to use after the given one, capped at `max_backoff`
+    pub fn next_backoff(&self, current: Duration) -> Duration {
+        (current * 2).min(self.max_backoff)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: MAX_REQUEST_RETRIES,
+            initial_backoff: Duration::from_millis(INITIAL_BACKOFF),
+            max_backoff: Duration::from_millis(MAX_BACKOFF),
+        }
+    }
+}
+
+/// Connection settings shared by the REST client and the pipeline websocket.
+/// Defaults point at the production VRChat endpoints, override them to target
+/// a proxy or a local mock server.
+#[derive(Debug, Clone)]
+pub struct VRChatClientConfig {
+    /// REST API root, without a trailing slash (e.g. `https://api.vrchat.cloud/api/1`)
+    pub api_base_url: String,
+    /// Pipeline websocket URL (e.g. `wss://pipeline.vrchat.cloud/`)
+    pub pipeline_base_url: String,
+    pub user_agent: String,
+    pub connect_timeout: Duration,
+    pub request_timeout: Duration,
+    pub retry: RetryPolicy,
+    /// Client-side throttling per endpoint class
+    pub rate_limits: RateLimitConfig,
+    /// Optional HTTP(S) proxy applied to all REST requests
+    pub proxy_url: Option<String>,
+    /// Detail requests in flight at once when backfilling list results
+    pub backfill_concurrency: usize,
+}
+
+impl VRChatClientConfig {
+    /// Config targeting a custom API root, keeping every other default
+    pub fn with_base_url(api_base_url: impl Into<String>) -> Self {
+        Self {
+            api_base_url: api_base_url.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Build a full REST URL from a path such as `/auth/user`
+    pub fn api_url(&self, path: &str) -> String {
+        format!(
+            "{}/{}",
+            self.api_base_url.trim_end_matches('/'),
+            path.trim_start_matches('/')
+        )
+    }
+}
+
+impl Default for VRChatClientConfig {
+    fn default() -> Self {
+        Self {
+            api_base_url: DEFAULT_API_BASE_URL.to_string(),
+            pipeline_base_url: DEFAULT_PIPELINE_BASE_URL.to_string(),
+            user_agent: USER_AGENT_STRING.to_string(),
+            connect_timeout: Duration::from_secs(10),
+            request_timeout: Duration::from_secs(30),
+            retry: RetryPolicy::default(),
+            rate_limits: RateLimitConfig::default(),
+            proxy_url: None,
+            backfill_concurrency: 4,
+        }
+    }
+}
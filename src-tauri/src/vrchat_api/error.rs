@@ -5,7 +5,8 @@ use std::fmt;
 /// Result type alias for VRChat API operations
 pub type VRCResult<T> = Result<T, VRCError>;
 
-/// Main error type for VRChat API operations
+/// Main error type for VRChat API operations.
+/// The serialized `type` tag doubles as a stable, machine-readable error code for the frontend.
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 #[serde(tag = "type", content = "data")]
 pub enum VRCError {
@@ -18,6 +19,18 @@ pub enum VRCError {
     /// Authentication errors
     Authentication(String),
 
+    /// The stored session was rejected by VRChat and a new login is required
+    SessionExpired(String),
+
+    /// The request was made without any session credentials
+    MissingCredentials(String),
+
+    /// The session is valid but not allowed to access the resource (HTTP 403)
+    Forbidden(String),
+
+    /// The requested resource does not exist (HTTP 404)
+    NotFound(String),
+
     /// Rate limiting error
     RateLimit(String),
 
@@ -50,6 +63,26 @@ impl VRCError {
         Self::Authentication(message.into())
     }
 
+    /// Create a new session expired error
+    pub fn session_expired(message: impl Into<String>) -> Self {
+        Self::SessionExpired(message.into())
+    }
+
+    /// Create a new missing credentials error
+    pub fn missing_credentials(message: impl Into<String>) -> Self {
+        Self::MissingCredentials(message.into())
+    }
+
+    /// Create a new forbidden error
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self::Forbidden(message.into())
+    }
+
+    /// Create a new not found error
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self::NotFound(message.into())
+    }
+
     /// Create a new rate limit error
     pub fn rate_limit(message: impl Into<String>) -> Self {
         Self::RateLimit(message.into())
@@ -76,6 +109,10 @@ impl VRCError {
             Self::Network(msg)
             | Self::Http { message: msg, .. }
             | Self::Authentication(msg)
+            | Self::SessionExpired(msg)
+            | Self::MissingCredentials(msg)
+            | Self::Forbidden(msg)
+            | Self::NotFound(msg)
             | Self::RateLimit(msg)
             | Self::Parse(msg)
             | Self::InvalidInput(msg)
@@ -87,9 +124,82 @@ impl VRCError {
     pub fn status_code(&self) -> Option<u16> {
         match self {
             Self::Http { status, .. } => Some(*status),
+            Self::SessionExpired(_) | Self::MissingCredentials(_) => Some(401),
+            Self::Forbidden(_) => Some(403),
+            Self::NotFound(_) => Some(404),
+            Self::RateLimit(_) => Some(429),
             _ => None,
         }
     }
+
+    /// Stable identifier for the error kind, identical to the serialized `type` tag
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Network(_) => "Network",
+            Self::Http { .. } => "Http",
+            Self::Authentication(_) => "Authentication",
+            Self::SessionExpired(_) => "SessionExpired",
+            Self::MissingCredentials(_) => "MissingCredentials",
+            Self::Forbidden(_) => "Forbidden",
+            Self::NotFound(_) => "NotFound",
+            Self::RateLimit(_) => "RateLimit",
+            Self::Parse(_) => "Parse",
+            Self::InvalidInput(_) => "InvalidInput",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
+
+    /// Build an error from a failed VRChat API response.
+    /// Decodes the `{"error":{"message","status_code"}}` envelope when present and falls back
+    /// to `fallback` for bodies that are empty or not JSON.
+    pub fn from_api_response(status: u16, body: &str, fallback: &str) -> Self {
+        let (status, message) = match ApiErrorResponse::parse(body) {
+            Some(error) => (
+                error.status_code.unwrap_or(status),
+                error.message.unwrap_or_else(|| fallback.to_string()),
+            ),
+            None => (status, fallback.to_string()),
+        };
+
+        match status {
+            401 if message.eq_ignore_ascii_case("missing credentials") => {
+                Self::MissingCredentials(message)
+            }
+            401 => Self::Authentication(message),
+            403 => Self::Forbidden(message),
+            404 => Self::NotFound(message),
+            429 => Self::RateLimit(message),
+            _ => Self::Http { status, message },
+        }
+    }
+}
+
+/// Error envelope returned by the VRChat API: `{"error":{"message":"...","status_code":401}}`
+#[derive(Debug, Clone, Deserialize)]
+struct ApiErrorEnvelope {
+    error: ApiErrorResponse,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+struct ApiErrorResponse {
+    #[serde(default)]
+    message: Option<String>,
+    #[serde(default)]
+    status_code: Option<u16>,
+}
+
+impl ApiErrorResponse {
+    fn parse(body: &str) -> Option<Self> {
+        let mut error = serde_json::from_str::<ApiErrorEnvelope>(body).ok()?.error;
+
+        // VRChat frequently wraps the message in an extra pair of quotes, e.g. "\"Missing Credentials\""
+        error.message = error
+            .message
+            .map(|message| message.trim().trim_matches('"').trim().to_string())
+            .filter(|message| !message.is_empty());
+
+        Some(error)
+    }
 }
 
 impl fmt::Display for VRCError {
@@ -98,6 +208,10 @@ impl fmt::Display for VRCError {
             Self::Network(msg) => write!(f, "Network error: {}", msg),
             Self::Http { status, message } => write!(f, "HTTP {} error: {}", status, message),
             Self::Authentication(msg) => write!(f, "Authentication error: {}", msg),
+            Self::SessionExpired(msg) => write!(f, "Session expired: {}", msg),
+            Self::MissingCredentials(msg) => write!(f, "Missing credentials: {}", msg),
+            Self::Forbidden(msg) => write!(f, "Forbidden: {}", msg),
+            Self::NotFound(msg) => write!(f, "Not found: {}", msg),
             Self::RateLimit(msg) => write!(f, "Rate limit: {}", msg),
             Self::Parse(msg) => write!(f, "Parse error: {}", msg),
             Self::InvalidInput(msg) => write!(f, "Invalid input: {}", msg),
@@ -127,3 +241,73 @@ impl From<serde_json::Error> for VRCError {
         VRCError::parse(format!("JSON parsing failed: {}", err))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decodes_error_envelope() {
+        let body = r#"{"error":{"message":"\"Missing Credentials\"","status_code":401}}"#;
+        let error = VRCError::from_api_response(401, body, "Request failed");
+
+        assert!(
+            matches!(&error, VRCError::MissingCredentials(msg) if msg == "Missing Credentials")
+        );
+        assert_eq!(error.code(), "MissingCredentials");
+        assert_eq!(error.status_code(), Some(401));
+    }
+
+    #[test]
+    fn test_maps_status_codes_to_variants() {
+        let body = |status: u16| {
+            format!(
+                r#"{{"error":{{"message":"Something went wrong","status_code":{}}}}}"#,
+                status
+            )
+        };
+
+        assert!(matches!(
+            VRCError::from_api_response(401, &body(401), ""),
+            VRCError::Authentication(_)
+        ));
+        assert!(matches!(
+            VRCError::from_api_response(403, &body(403), ""),
+            VRCError::Forbidden(_)
+        ));
+        assert!(matches!(
+            VRCError::from_api_response(404, &body(404), ""),
+            VRCError::NotFound(_)
+        ));
+        assert!(matches!(
+            VRCError::from_api_response(429, &body(429), ""),
+            VRCError::RateLimit(_)
+        ));
+        assert!(matches!(
+            VRCError::from_api_response(418, &body(418), ""),
+            VRCError::Http { status: 418, .. }
+        ));
+    }
+
+    #[test]
+    fn test_falls_back_for_non_envelope_bodies() {
+        let error = VRCError::from_api_response(502, "<html>Bad Gateway</html>", "Request failed");
+
+        match error {
+            VRCError::Http { status, message } => {
+                assert_eq!(status, 502);
+                assert_eq!(message, "Request failed");
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_code_matches_serialized_tag() {
+        let error = VRCError::not_found("World not found");
+        let value = serde_json::to_value(&error).unwrap();
+
+        assert_eq!(value["type"], error.code());
+        assert_eq!(value["data"], "World not found");
+    }
+}
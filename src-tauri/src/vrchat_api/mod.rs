@@ -1,8 +1,10 @@
 pub mod client;
+pub mod config;
 pub mod error;
 pub mod types;
 
 // Re-export common types
-pub use client::VRChatClient;
+pub use client::{SessionExpiredNotice, VRChatClient};
+pub use config::{RetryPolicy, VRChatClientConfig};
 pub use error::{VRCError, VRCResult};
 pub use types::*;
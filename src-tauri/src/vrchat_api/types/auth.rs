@@ -38,3 +38,105 @@ pub struct UpdateStatusRequest {
     pub status: UserStatus,
     pub status_description: String,
 }
+
+/// Profile fields of the current user to change; fields left `None` are not sent
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateUserRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<UserStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bio: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bio_links: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pronouns: Option<String>,
+    /// Language tags such as `language_eng`; they replace the current language tags while the
+    /// user's other tags are kept
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    /// File URL of an uploaded icon, VRC+ only
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_icon: Option<String>,
+}
+
+impl UpdateUserRequest {
+    pub const MAX_STATUS_DESCRIPTION_LENGTH: usize = 32;
+    pub const MAX_BIO_LENGTH: usize = 512;
+    pub const MAX_BIO_LINKS: usize = 3;
+    pub const MAX_PRONOUNS_LENGTH: usize = 32;
+    pub const MAX_LANGUAGES: usize = 3;
+    pub const LANGUAGE_TAG_PREFIX: &'static str = "language_";
+
+    /// Check VRChat's limits so bad input fails before a request is made.
+    /// Lengths are counted in characters, like the website does.
+    pub fn validate(&self) -> Result<(), String> {
+        fn check_length(field: &str, value: &Option<String>, max: usize) -> Result<(), String> {
+            match value {
+                Some(value) if value.chars().count() > max => {
+                    Err(format!("{} must be at most {} characters", field, max))
+                }
+                _ => Ok(()),
+            }
+        }
+
+        check_length(
+            "Status description",
+            &self.status_description,
+            Self::MAX_STATUS_DESCRIPTION_LENGTH,
+        )?;
+        check_length("Bio", &self.bio, Self::MAX_BIO_LENGTH)?;
+        check_length("Pronouns", &self.pronouns, Self::MAX_PRONOUNS_LENGTH)?;
+
+        if let Some(links) = &self.bio_links {
+            if links.len() > Self::MAX_BIO_LINKS {
+                return Err(format!(
+                    "At most {} bio links are allowed",
+                    Self::MAX_BIO_LINKS
+                ));
+            }
+            if links.iter().any(|link| link.trim().is_empty()) {
+                return Err("Bio links must not be empty".to_string());
+            }
+        }
+
+        if let Some(tags) = &self.tags {
+            if let Some(tag) = tags
+                .iter()
+                .find(|tag| !tag.starts_with(Self::LANGUAGE_TAG_PREFIX))
+            {
+                return Err(format!("{} is not a language tag", tag));
+            }
+            if tags.len() > Self::MAX_LANGUAGES {
+                return Err(format!(
+                    "At most {} languages are allowed",
+                    Self::MAX_LANGUAGES
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Full tag list to send: `current` with its language tags swapped for the requested ones
+    pub fn merged_tags(&self, current: &[String]) -> Option<Vec<String>> {
+        let languages = self.tags.as_ref()?;
+        let mut tags: Vec<String> = current
+            .iter()
+            .filter(|tag| !tag.starts_with(Self::LANGUAGE_TAG_PREFIX))
+            .cloned()
+            .collect();
+        tags.extend(languages.iter().cloned());
+        Some(tags)
+    }
+}
+
+/// Payload of the `session-expired` event emitted when VRChat rejects the stored session
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionExpiredEvent {
+    pub user_id: Option<String>,
+    pub message: String,
+}
@@ -25,6 +25,20 @@ impl Default for UserStatus {
     }
 }
 
+impl UserStatus {
+    /// Inverse of `Display`
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "active" => Some(UserStatus::Active),
+            "join me" => Some(UserStatus::JoinMe),
+            "ask me" => Some(UserStatus::AskMe),
+            "busy" => Some(UserStatus::Busy),
+            "offline" => Some(UserStatus::Offline),
+            _ => None,
+        }
+    }
+}
+
 impl std::fmt::Display for UserStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -252,3 +266,44 @@ impl std::fmt::Display for OrderOption {
         }
     }
 }
+
+/// Sort field for list endpoints such as `/worlds` and `/avatars`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub enum SortOption {
+    Popularity,
+    Heat,
+    Trust,
+    Shuffle,
+    Random,
+    Favorites,
+    PublicationDate,
+    LabsPublicationDate,
+    Created,
+    Updated,
+    Order,
+    Relevance,
+    Magic,
+    Name,
+}
+
+impl std::fmt::Display for SortOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SortOption::Popularity => write!(f, "popularity"),
+            SortOption::Heat => write!(f, "heat"),
+            SortOption::Trust => write!(f, "trust"),
+            SortOption::Shuffle => write!(f, "shuffle"),
+            SortOption::Random => write!(f, "random"),
+            SortOption::Favorites => write!(f, "favorites"),
+            SortOption::PublicationDate => write!(f, "publicationDate"),
+            SortOption::LabsPublicationDate => write!(f, "labsPublicationDate"),
+            SortOption::Created => write!(f, "created"),
+            SortOption::Updated => write!(f, "updated"),
+            SortOption::Order => write!(f, "order"),
+            SortOption::Relevance => write!(f, "relevance"),
+            SortOption::Magic => write!(f, "magic"),
+            SortOption::Name => write!(f, "name"),
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// What a favorite or favorite group holds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type)]
+#[serde(rename_all = "lowercase")]
+pub enum FavoriteType {
+    Friend,
+    World,
+    Avatar,
+}
+
+impl std::fmt::Display for FavoriteType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FavoriteType::Friend => write!(f, "friend"),
+            FavoriteType::World => write!(f, "world"),
+            FavoriteType::Avatar => write!(f, "avatar"),
+        }
+    }
+}
+
+/// Who can see a favorite group
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "lowercase")]
+pub enum FavoriteGroupVisibility {
+    #[default]
+    Private,
+    Friends,
+    Public,
+}
+
+/// Entry of `/favorites`
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct Favorite {
+    /// Favorite record ID (`fvrt_...`), used to remove it
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: FavoriteType,
+    /// ID of the favorited user, world or avatar
+    pub favorite_id: String,
+    /// Favorite group names (`name`, not `displayName`) the entry belongs to
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// Entry of `/favorite/groups`
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct FavoriteGroup {
+    pub id: String,
+    pub owner_id: String,
+    #[serde(default)]
+    pub owner_display_name: Option<String>,
+    /// Internal name, e.g. `worlds1`, used as the tag on favorites
+    pub name: String,
+    pub display_name: String,
+    #[serde(rename = "type")]
+    pub kind: FavoriteType,
+    #[serde(default)]
+    pub visibility: FavoriteGroupVisibility,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// Per-type limit, as found in `/auth/user/favoritelimits`
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct FavoriteTypeLimits {
+    #[serde(default)]
+    pub avatar: i32,
+    #[serde(default)]
+    pub friend: i32,
+    #[serde(default)]
+    pub world: i32,
+}
+
+/// Response of `/auth/user/favoritelimits`
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct FavoriteLimits {
+    #[serde(default)]
+    pub default_max_favorite_groups: i32,
+    #[serde(default)]
+    pub default_max_favorites_per_group: i32,
+    #[serde(default)]
+    pub max_favorite_groups: FavoriteTypeLimits,
+    #[serde(default)]
+    pub max_favorites_per_group: FavoriteTypeLimits,
+}
+
+/// Body of `POST /favorites`
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct AddFavoriteRequest {
+    #[serde(rename = "type")]
+    pub kind: FavoriteType,
+    pub favorite_id: String,
+    /// Favorite group name to add the entry to
+    pub tags: Vec<String>,
+}
+
+/// Body of `PUT /favorite/group/{type}/{name}/{userId}`
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateFavoriteGroupRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<FavoriteGroupVisibility>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+}
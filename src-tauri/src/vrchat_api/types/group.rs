@@ -0,0 +1,362 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use super::world::LimitedWorld;
+
+/// Who can see or join a group
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "lowercase")]
+pub enum GroupPrivacy {
+    /// Listed in search
+    #[default]
+    Default,
+    /// Hidden from search
+    Private,
+    /// A privacy level this client does not know yet
+    #[serde(other)]
+    Unknown,
+}
+
+/// How new members get in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "lowercase")]
+pub enum GroupJoinState {
+    Open,
+    /// Join requests need approval
+    Request,
+    #[default]
+    Invite,
+    Closed,
+    /// A join state this client does not know yet
+    #[serde(other)]
+    Unknown,
+}
+
+/// Membership state of a user in a group
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "lowercase")]
+pub enum GroupMemberStatus {
+    #[default]
+    Inactive,
+    Member,
+    Requested,
+    Invited,
+    Banned,
+    /// The user blocked the group
+    Userblocked,
+    /// A membership status this client does not know yet
+    #[serde(other)]
+    Unknown,
+}
+
+/// Response of `/groups/{groupId}`
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct Group {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub short_code: String,
+    #[serde(default)]
+    pub discriminator: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub icon_url: Option<String>,
+    #[serde(default)]
+    pub banner_url: Option<String>,
+    #[serde(default)]
+    pub privacy: GroupPrivacy,
+    #[serde(default)]
+    pub owner_id: Option<String>,
+    #[serde(default)]
+    pub rules: Option<String>,
+    #[serde(default)]
+    pub links: Vec<String>,
+    #[serde(default)]
+    pub languages: Vec<String>,
+    #[serde(default)]
+    pub member_count: i32,
+    #[serde(default)]
+    pub online_member_count: Option<i32>,
+    #[serde(default)]
+    pub is_verified: bool,
+    #[serde(default)]
+    pub join_state: GroupJoinState,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub membership_status: GroupMemberStatus,
+    /// The current user's membership, absent when not a member
+    #[serde(default)]
+    pub my_member: Option<GroupMember>,
+    /// Only included with `includeRoles=true`
+    #[serde(default)]
+    pub roles: Option<Vec<GroupRole>>,
+    #[serde(default)]
+    pub created_at: Option<String>,
+}
+
+/// Entry of `/users/{userId}/groups`
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct UserGroup {
+    /// Membership ID (`gmem_...`), see `group_id` for the group itself
+    pub id: String,
+    pub group_id: String,
+    pub name: String,
+    #[serde(default)]
+    pub short_code: String,
+    #[serde(default)]
+    pub discriminator: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub icon_url: Option<String>,
+    #[serde(default)]
+    pub banner_url: Option<String>,
+    #[serde(default)]
+    pub privacy: GroupPrivacy,
+    #[serde(default)]
+    pub owner_id: Option<String>,
+    #[serde(default)]
+    pub member_count: i32,
+    #[serde(default)]
+    pub member_visibility: Option<String>,
+    #[serde(default)]
+    pub is_representing: bool,
+    #[serde(default)]
+    pub mutual_group: bool,
+    #[serde(default)]
+    pub last_post_created_at: Option<String>,
+}
+
+/// Response of `/users/{userId}/groups/represented`
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct RepresentedGroup {
+    /// Missing when the user doesn't represent any group
+    #[serde(default)]
+    pub group_id: Option<String>,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub short_code: String,
+    #[serde(default)]
+    pub discriminator: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub icon_url: Option<String>,
+    #[serde(default)]
+    pub banner_url: Option<String>,
+    #[serde(default)]
+    pub privacy: GroupPrivacy,
+    #[serde(default)]
+    pub owner_id: Option<String>,
+    #[serde(default)]
+    pub member_count: i32,
+}
+
+/// User summary embedded in group member listings
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupMemberUser {
+    pub id: String,
+    pub display_name: String,
+    #[serde(default)]
+    pub thumbnail_url: Option<String>,
+    #[serde(default)]
+    pub icon_url: Option<String>,
+    #[serde(default)]
+    pub profile_pic_override: Option<String>,
+    #[serde(default)]
+    pub current_avatar_thumbnail_image_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupMember {
+    /// Membership ID (`gmem_...`)
+    pub id: String,
+    pub group_id: String,
+    pub user_id: String,
+    #[serde(default)]
+    pub is_representing: bool,
+    /// Absent in websocket updates
+    #[serde(default)]
+    pub user: Option<GroupMemberUser>,
+    #[serde(default)]
+    pub role_ids: Vec<String>,
+    /// Roles that grant management permissions
+    #[serde(default)]
+    pub m_role_ids: Vec<String>,
+    #[serde(default)]
+    pub joined_at: Option<String>,
+    #[serde(default)]
+    pub membership_status: GroupMemberStatus,
+    #[serde(default)]
+    pub visibility: Option<String>,
+    #[serde(default)]
+    pub is_subscribed_to_announcements: bool,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub banned_at: Option<String>,
+    /// Only visible to group managers
+    #[serde(default)]
+    pub manager_notes: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupRole {
+    pub id: String,
+    pub group_id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub is_self_assignable: bool,
+    /// Permission keys such as `group-bans-manage`, `*` grants everything
+    #[serde(default)]
+    pub permissions: Vec<String>,
+    #[serde(default)]
+    pub is_management_role: bool,
+    #[serde(default)]
+    pub requires_two_factor: bool,
+    #[serde(default)]
+    pub requires_purchase: bool,
+    #[serde(default)]
+    pub order: i32,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+/// Response of `/groups/{groupId}/announcements`, the latest announcement only
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupAnnouncement {
+    /// Missing when the group has never posted an announcement
+    #[serde(default)]
+    pub id: Option<String>,
+    #[serde(default)]
+    pub group_id: Option<String>,
+    #[serde(default)]
+    pub author_id: Option<String>,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub text: Option<String>,
+    #[serde(default)]
+    pub image_url: Option<String>,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupPost {
+    pub id: String,
+    pub group_id: String,
+    #[serde(default)]
+    pub author_id: Option<String>,
+    #[serde(default)]
+    pub editor_id: Option<String>,
+    /// `public` or `group`
+    #[serde(default)]
+    pub visibility: String,
+    /// Roles allowed to see a `group` post, empty means every member
+    #[serde(rename = "roleId", default)]
+    pub role_ids: Vec<String>,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub text: String,
+    #[serde(default)]
+    pub image_url: Option<String>,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+/// Response of `/groups/{groupId}/posts`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupPostPage {
+    #[serde(default)]
+    pub posts: Vec<GroupPost>,
+    #[serde(default)]
+    pub total: Option<i32>,
+}
+
+/// Entry of `/groups/{groupId}/instances`
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupInstance {
+    pub instance_id: String,
+    /// Full location, `worldId:instanceId`
+    pub location: String,
+    #[serde(default)]
+    pub member_count: i32,
+    #[serde(default)]
+    pub world: Option<LimitedWorld>,
+}
+
+/// Answer to a pending join request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "lowercase")]
+pub enum GroupJoinRequestAction {
+    Accept,
+    Reject,
+}
+
+/// Body of `PUT /groups/{groupId}/requests/{userId}`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RespondGroupJoinRequest {
+    pub action: GroupJoinRequestAction,
+    /// Also block the user from requesting again, only valid with `reject`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block: Option<bool>,
+}
+
+/// Entry of `/groups/{groupId}/auditLogs`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupAuditLogEntry {
+    /// `gaud_...`
+    pub id: String,
+    #[serde(rename = "created_at")]
+    pub created_at: String,
+    pub group_id: String,
+    pub actor_id: String,
+    #[serde(default)]
+    pub actor_display_name: Option<String>,
+    #[serde(default)]
+    pub target_id: Option<String>,
+    /// e.g. `group.user.ban`, `group.member.role.assign`
+    pub event_type: String,
+    #[serde(default)]
+    pub description: String,
+    /// Event-specific details
+    #[serde(default)]
+    pub data: serde_json::Value,
+}
+
+/// Response of `/groups/{groupId}/auditLogs`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupAuditLogPage {
+    #[serde(default)]
+    pub results: Vec<GroupAuditLogEntry>,
+    #[serde(default)]
+    pub total_count: i32,
+    #[serde(default)]
+    pub has_next: bool,
+}
@@ -0,0 +1,156 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use super::location::GroupAccessType;
+use super::user::LimitedUserFriend;
+use super::world::LimitedWorld;
+
+/// Instance type as used by `/instances`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "lowercase")]
+pub enum InstanceType {
+    /// Anyone can join
+    #[default]
+    Public,
+    /// Friends+ (friends of anyone in the instance)
+    Hidden,
+    /// Friends of the owner only
+    Friends,
+    /// Invite and Invite+
+    Private,
+    /// Group instance, see `groupAccessType`
+    Group,
+}
+
+impl std::fmt::Display for InstanceType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InstanceType::Public => write!(f, "public"),
+            InstanceType::Hidden => write!(f, "hidden"),
+            InstanceType::Friends => write!(f, "friends"),
+            InstanceType::Private => write!(f, "private"),
+            InstanceType::Group => write!(f, "group"),
+        }
+    }
+}
+
+/// Player count per platform
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct InstancePlatforms {
+    #[serde(default)]
+    pub android: i32,
+    #[serde(default)]
+    pub ios: i32,
+    #[serde(default)]
+    pub standalonewindows: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct Instance {
+    /// Full location, `worldId:instanceId`
+    pub id: String,
+    #[serde(default)]
+    pub location: String,
+    /// Everything after `worldId:`
+    pub instance_id: String,
+    pub world_id: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub display_name: Option<String>,
+    #[serde(rename = "type", default)]
+    pub instance_type: InstanceType,
+    #[serde(default)]
+    pub region: String,
+    #[serde(default)]
+    pub photon_region: Option<String>,
+    #[serde(default)]
+    pub owner_id: Option<String>,
+    #[serde(default)]
+    pub group_access_type: Option<GroupAccessType>,
+    #[serde(default)]
+    pub capacity: i32,
+    #[serde(default)]
+    pub recommended_capacity: i32,
+    /// Current player count
+    #[serde(default)]
+    pub user_count: i32,
+    #[serde(rename = "n_users", default)]
+    pub n_users: i32,
+    #[serde(default)]
+    pub platforms: InstancePlatforms,
+    /// Only returned to the instance owner or group moderators
+    #[serde(default)]
+    pub users: Vec<LimitedUserFriend>,
+    #[serde(default)]
+    pub full: bool,
+    #[serde(default)]
+    pub has_capacity_for_you: Option<bool>,
+    #[serde(default)]
+    pub queue_enabled: bool,
+    #[serde(default)]
+    pub queue_size: i32,
+    #[serde(default)]
+    pub active: bool,
+    #[serde(default)]
+    pub can_request_invite: bool,
+    #[serde(default)]
+    pub permanent: bool,
+    #[serde(default)]
+    pub strict: bool,
+    #[serde(default)]
+    pub age_gate: Option<bool>,
+    #[serde(default)]
+    pub role_restricted: Option<bool>,
+    #[serde(default)]
+    pub closed_at: Option<String>,
+    #[serde(default)]
+    pub hard_close: Option<bool>,
+    #[serde(default)]
+    pub nonce: Option<String>,
+    #[serde(default)]
+    pub short_name: Option<String>,
+    #[serde(default)]
+    pub secure_name: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub world: Option<LimitedWorld>,
+}
+
+/// Response of `/instances/{location}/shortName`
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct InstanceShortName {
+    #[serde(default)]
+    pub secure_name: Option<String>,
+    #[serde(default)]
+    pub short_name: Option<String>,
+}
+
+/// Body of `POST /instances`
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateInstanceRequest {
+    pub world_id: String,
+    #[serde(rename = "type")]
+    pub instance_type: InstanceType,
+    pub region: String,
+    /// User ID for friends/invite instances, group ID for group instances
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub role_ids: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group_access_type: Option<GroupAccessType>,
+    #[serde(default)]
+    pub queue_enabled: bool,
+    #[serde(default)]
+    pub can_request_invite: bool,
+    #[serde(default)]
+    pub age_gate: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+}
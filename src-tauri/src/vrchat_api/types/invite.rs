@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Highest message slot VRChat accepts for invite messages and responses
+pub const MAX_MESSAGE_SLOT: u32 = 11;
+
+/// Body of `POST /invite/{userId}`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InviteRequest {
+    /// Full location, `worldId:instanceId`
+    pub instance_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_slot: Option<u32>,
+}
+
+/// Body of `POST /requestInvite/{userId}`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestInviteRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_slot: Option<u32>,
+}
+
+/// Body of `POST /invite/{notificationId}/response`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InviteResponseRequest {
+    pub response_slot: u32,
+}
+
+/// Notification created by an invite call, as returned by the API
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct SentNotification {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(default)]
+    pub sender_user_id: Option<String>,
+    #[serde(default)]
+    pub receiver_user_id: Option<String>,
+    #[serde(default)]
+    pub message: Option<String>,
+    #[serde(default)]
+    pub created_at: Option<String>,
+}
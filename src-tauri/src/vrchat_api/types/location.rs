@@ -0,0 +1,399 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use specta::{DataType, Generics, Type, TypeCollection};
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+/// Who can join an instance, derived from its location tags
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub enum InstanceAccessType {
+    Public,
+    /// `hidden(usr_...)`
+    FriendsPlus,
+    /// `friends(usr_...)`
+    Friends,
+    /// `private(usr_...)~canRequestInvite`
+    InvitePlus,
+    /// `private(usr_...)`
+    Invite,
+    /// `group(grp_...)`
+    Group,
+}
+
+/// Who can join a group instance, from the `groupAccessType(...)` tag
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "lowercase")]
+pub enum GroupAccessType {
+    Public,
+    Plus,
+    Members,
+}
+
+impl GroupAccessType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Public => "public",
+            Self::Plus => "plus",
+            Self::Members => "members",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "public" => Some(Self::Public),
+            "plus" => Some(Self::Plus),
+            "members" => Some(Self::Members),
+            _ => None,
+        }
+    }
+}
+
+/// A concrete world instance such as
+/// `wrld_...:12345~hidden(usr_...)~region(jp)~nonce(...)`.
+/// The parsed fields are read-only views; the raw string is what gets sent back to VRChat.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstanceLocation {
+    raw: String,
+    world_id: String,
+    instance_name: String,
+    access_type: InstanceAccessType,
+    owner_id: Option<String>,
+    group_id: Option<String>,
+    group_access_type: Option<GroupAccessType>,
+    region: Option<String>,
+    nonce: Option<String>,
+    can_request_invite: bool,
+    strict: bool,
+    age_gate: bool,
+    extra_tags: Vec<String>,
+}
+
+impl InstanceLocation {
+    /// The location exactly as VRChat sent it
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+
+    /// The part after `world_id:`, as expected by `/instances/{worldId}:{instanceId}`
+    pub fn instance_id(&self) -> &str {
+        self.raw
+            .split_once(':')
+            .map(|(_, instance)| instance)
+            .unwrap_or_default()
+    }
+
+    pub fn world_id(&self) -> &str {
+        &self.world_id
+    }
+
+    /// Instance name, the numeric part before the first `~`
+    pub fn instance_name(&self) -> &str {
+        &self.instance_name
+    }
+
+    pub fn access_type(&self) -> InstanceAccessType {
+        self.access_type
+    }
+
+    /// Creator of friends and invite instances
+    pub fn owner_id(&self) -> Option<&str> {
+        self.owner_id.as_deref()
+    }
+
+    pub fn group_id(&self) -> Option<&str> {
+        self.group_id.as_deref()
+    }
+
+    pub fn group_access_type(&self) -> Option<GroupAccessType> {
+        self.group_access_type
+    }
+
+    pub fn region(&self) -> Option<&str> {
+        self.region.as_deref()
+    }
+
+    pub fn nonce(&self) -> Option<&str> {
+        self.nonce.as_deref()
+    }
+
+    pub fn can_request_invite(&self) -> bool {
+        self.can_request_invite
+    }
+
+    pub fn strict(&self) -> bool {
+        self.strict
+    }
+
+    pub fn age_gate(&self) -> bool {
+        self.age_gate
+    }
+
+    /// Tags this parser does not understand
+    pub fn extra_tags(&self) -> &[String] {
+        &self.extra_tags
+    }
+
+    fn parse(raw: &str) -> Option<Self> {
+        let (world_id, instance) = raw.split_once(':')?;
+        if world_id.is_empty() || world_id.contains('~') {
+            return None;
+        }
+
+        let mut parts = instance.split('~');
+        let instance_name = parts.next().filter(|name| !name.is_empty())?;
+
+        let mut location = Self {
+            raw: raw.to_string(),
+            world_id: world_id.to_string(),
+            instance_name: instance_name.to_string(),
+            access_type: InstanceAccessType::Public,
+            owner_id: None,
+            group_id: None,
+            group_access_type: None,
+            region: None,
+            nonce: None,
+            can_request_invite: false,
+            strict: false,
+            age_gate: false,
+            extra_tags: Vec::new(),
+        };
+
+        for tag in parts {
+            let (name, value) = match tag.split_once('(') {
+                Some((name, rest)) => match rest.strip_suffix(')') {
+                    Some(value) => (name, Some(value.to_string())),
+                    None => {
+                        location.extra_tags.push(tag.to_string());
+                        continue;
+                    }
+                },
+                None => (tag, None),
+            };
+
+            match (name, value) {
+                ("hidden", Some(owner)) => {
+                    location.access_type = InstanceAccessType::FriendsPlus;
+                    location.owner_id = Some(owner);
+                }
+                ("friends", Some(owner)) => {
+                    location.access_type = InstanceAccessType::Friends;
+                    location.owner_id = Some(owner);
+                }
+                ("private", Some(owner)) => {
+                    location.access_type = InstanceAccessType::Invite;
+                    location.owner_id = Some(owner);
+                }
+                ("group", Some(group)) => {
+                    location.access_type = InstanceAccessType::Group;
+                    location.group_id = Some(group);
+                }
+                ("groupAccessType", Some(access)) if GroupAccessType::parse(&access).is_some() => {
+                    location.group_access_type = GroupAccessType::parse(&access);
+                }
+                ("region", Some(region)) => location.region = Some(region),
+                ("nonce", Some(nonce)) => location.nonce = Some(nonce),
+                ("canRequestInvite", None) => location.can_request_invite = true,
+                ("strict", None) => location.strict = true,
+                ("ageGate", None) => location.age_gate = true,
+                _ => location.extra_tags.push(tag.to_string()),
+            }
+        }
+
+        if location.access_type == InstanceAccessType::Invite && location.can_request_invite {
+            location.access_type = InstanceAccessType::InvitePlus;
+        }
+
+        Some(location)
+    }
+}
+
+impl fmt::Display for InstanceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.raw)
+    }
+}
+
+/// A user's location as reported by the API and the pipeline.
+/// Serialized as the raw VRChat string, so the frontend keeps seeing `string`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Location {
+    Offline,
+    /// Online, but the location is hidden from us
+    Private,
+    /// Loading into a new instance, see `traveling_to_location`
+    Traveling,
+    Instance(InstanceLocation),
+    /// Anything else (including an empty string), kept verbatim
+    Unknown(String),
+}
+
+impl Location {
+    pub fn parse(raw: &str) -> Self {
+        match raw {
+            "offline" => Self::Offline,
+            "private" => Self::Private,
+            "traveling" => Self::Traveling,
+            _ => InstanceLocation::parse(raw)
+                .map(Self::Instance)
+                .unwrap_or_else(|| Self::Unknown(raw.to_string())),
+        }
+    }
+
+    pub fn is_online(&self) -> bool {
+        matches!(self, Self::Private | Self::Traveling | Self::Instance(_))
+    }
+
+    pub fn instance(&self) -> Option<&InstanceLocation> {
+        match self {
+            Self::Instance(instance) => Some(instance),
+            _ => None,
+        }
+    }
+
+    pub fn world_id(&self) -> Option<&str> {
+        self.instance().map(InstanceLocation::world_id)
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Offline => write!(f, "offline"),
+            Self::Private => write!(f, "private"),
+            Self::Traveling => write!(f, "traveling"),
+            Self::Instance(instance) => instance.fmt(f),
+            Self::Unknown(raw) => write!(f, "{}", raw),
+        }
+    }
+}
+
+impl FromStr for Location {
+    type Err = Infallible;
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        Ok(Self::parse(raw))
+    }
+}
+
+impl Serialize for Location {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Location {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+        Ok(Self::parse(&raw))
+    }
+}
+
+impl Type for Location {
+    fn inline(type_map: &mut TypeCollection, generics: Generics) -> DataType {
+        <String as Type>::inline(type_map, generics)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_special_locations() {
+        assert_eq!(Location::parse("offline"), Location::Offline);
+        assert_eq!(Location::parse("private"), Location::Private);
+        assert_eq!(Location::parse("traveling"), Location::Traveling);
+        assert_eq!(Location::parse(""), Location::Unknown(String::new()));
+        assert!(!Location::parse("offline").is_online());
+        assert!(Location::parse("private").is_online());
+    }
+
+    #[test]
+    fn test_parse_friends_plus_instance() {
+        let location =
+            Location::parse("wrld_abc:12345~hidden(usr_owner)~region(jp)~nonce(abc-123)");
+        let instance = location.instance().expect("instance");
+
+        assert_eq!(instance.world_id(), "wrld_abc");
+        assert_eq!(instance.instance_name(), "12345");
+        assert_eq!(instance.access_type(), InstanceAccessType::FriendsPlus);
+        assert_eq!(instance.owner_id(), Some("usr_owner"));
+        assert_eq!(instance.region(), Some("jp"));
+        assert_eq!(instance.nonce(), Some("abc-123"));
+        assert_eq!(
+            instance.instance_id(),
+            "12345~hidden(usr_owner)~region(jp)~nonce(abc-123)"
+        );
+    }
+
+    #[test]
+    fn test_parse_invite_plus_and_group_instances() {
+        let invite = Location::parse("wrld_abc:1~private(usr_owner)~canRequestInvite~region(eu)");
+        let invite = invite.instance().unwrap();
+        assert_eq!(invite.access_type(), InstanceAccessType::InvitePlus);
+        assert!(invite.can_request_invite());
+
+        let group = Location::parse(
+            "wrld_abc:2~group(grp_123)~groupAccessType(plus)~region(us)~strict~ageGate",
+        );
+        let group = group.instance().unwrap();
+        assert_eq!(group.access_type(), InstanceAccessType::Group);
+        assert_eq!(group.group_id(), Some("grp_123"));
+        assert_eq!(group.group_access_type(), Some(GroupAccessType::Plus));
+        assert!(group.strict() && group.age_gate());
+    }
+
+    #[test]
+    fn test_location_round_trips() {
+        let samples = [
+            "offline",
+            "private",
+            "traveling",
+            "",
+            "wrld_abc:12345",
+            "wrld_abc:12345~region(use)",
+            "wrld_abc:12345~hidden(usr_owner)~region(jp)~nonce(abc-123)",
+            "wrld_abc:12345~friends(usr_owner)~region(us)~nonce(x)",
+            "wrld_abc:12345~private(usr_owner)~canRequestInvite~region(eu)~nonce(x)",
+            "wrld_abc:12345~private(usr_owner)~region(eu)~nonce(x)",
+            "wrld_abc:12345~group(grp_1)~groupAccessType(members)~region(jp)~strict",
+            "wrld_abc:12345~region(us)~someFutureTag(value)~anotherFlag",
+            "not a location",
+        ];
+
+        for raw in samples {
+            assert_eq!(Location::parse(raw).to_string(), raw);
+        }
+    }
+
+    #[test]
+    fn test_non_canonical_tag_order_is_kept() {
+        let raw = "wrld_abc:12345~region(jp)~nonce(x)~strict~hidden(usr_owner)~region(us)";
+        let location = Location::parse(raw);
+        let instance = location.instance().unwrap();
+
+        assert_eq!(instance.access_type(), InstanceAccessType::FriendsPlus);
+        assert_eq!(location.to_string(), raw);
+        assert_eq!(instance.as_str(), raw);
+        assert_eq!(
+            instance.instance_id(),
+            "12345~region(jp)~nonce(x)~strict~hidden(usr_owner)~region(us)"
+        );
+
+        let json = serde_json::to_string(&location).unwrap();
+        assert_eq!(json, format!("\"{}\"", raw));
+        let back: Location = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.to_string(), raw);
+    }
+
+    #[test]
+    fn test_location_serializes_as_string() {
+        let location: Location = serde_json::from_str("\"wrld_abc:1~region(jp)\"").unwrap();
+        assert_eq!(location.world_id(), Some("wrld_abc"));
+        assert_eq!(
+            serde_json::to_string(&location).unwrap(),
+            "\"wrld_abc:1~region(jp)\""
+        );
+    }
+}
@@ -1,6 +1,12 @@
 pub mod auth;
 pub mod avatar;
 pub mod enums;
+pub mod favorite;
+pub mod group;
+pub mod instance;
+pub mod invite;
+pub mod location;
+pub mod moderation;
 pub mod two_factor;
 pub mod user;
 pub mod world;
@@ -9,6 +15,12 @@ pub mod world;
 pub use auth::*;
 pub use avatar::*;
 pub use enums::*;
+pub use favorite::*;
+pub use group::*;
+pub use instance::*;
+pub use invite::*;
+pub use location::*;
+pub use moderation::*;
 pub use two_factor::*;
 pub use user::*;
 pub use world::*;
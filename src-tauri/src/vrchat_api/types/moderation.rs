@@ -0,0 +1,125 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Kind of player moderation applied by the current user
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub enum PlayerModerationType {
+    /// User is blocked
+    Block,
+    /// User's voice is muted
+    Mute,
+    /// User's voice is explicitly unmuted
+    Unmute,
+    /// User's avatar is hidden
+    HideAvatar,
+    /// User's avatar is always shown
+    ShowAvatar,
+    /// Interactions with the user are enabled
+    InteractOn,
+    /// Interactions with the user are disabled
+    InteractOff,
+    /// A type this client does not know yet, e.g. `muteChat`
+    #[serde(other)]
+    Unknown,
+}
+
+impl std::fmt::Display for PlayerModerationType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlayerModerationType::Block => write!(f, "block"),
+            PlayerModerationType::Mute => write!(f, "mute"),
+            PlayerModerationType::Unmute => write!(f, "unmute"),
+            PlayerModerationType::HideAvatar => write!(f, "hideAvatar"),
+            PlayerModerationType::ShowAvatar => write!(f, "showAvatar"),
+            PlayerModerationType::InteractOn => write!(f, "interactOn"),
+            PlayerModerationType::InteractOff => write!(f, "interactOff"),
+            PlayerModerationType::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// Entry of `/auth/user/playermoderations`
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerModeration {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: PlayerModerationType,
+    pub source_user_id: String,
+    #[serde(default)]
+    pub source_display_name: Option<String>,
+    pub target_user_id: String,
+    #[serde(default)]
+    pub target_display_name: Option<String>,
+    #[serde(default)]
+    pub created: Option<String>,
+}
+
+/// Moderations the current user applied to another user
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct UserModerationState {
+    pub user_id: String,
+    pub blocked: bool,
+    pub muted: bool,
+    pub avatar_hidden: bool,
+    pub avatar_shown: bool,
+    pub interaction_disabled: bool,
+    pub moderations: Vec<PlayerModeration>,
+}
+
+impl UserModerationState {
+    pub fn new(user_id: &str, moderations: Vec<PlayerModeration>) -> Self {
+        let has = |kind| moderations.iter().any(|m| m.kind == kind);
+
+        Self {
+            user_id: user_id.to_string(),
+            blocked: has(PlayerModerationType::Block),
+            muted: has(PlayerModerationType::Mute),
+            avatar_hidden: has(PlayerModerationType::HideAvatar),
+            avatar_shown: has(PlayerModerationType::ShowAvatar),
+            interaction_disabled: has(PlayerModerationType::InteractOff),
+            moderations,
+        }
+    }
+}
+
+/// Body of `POST /auth/user/playermoderations` and `PUT /auth/user/unplayermoderate`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModerateUserRequest {
+    /// Target user ID
+    pub moderated: String,
+    #[serde(rename = "type")]
+    pub kind: PlayerModerationType,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unlisted_moderation_types_deserialize_as_unknown() {
+        let moderations: Vec<PlayerModeration> = serde_json::from_value(serde_json::json!([
+            {
+                "id": "pmod_1",
+                "type": "muteChat",
+                "sourceUserId": "usr_me",
+                "targetUserId": "usr_a",
+            },
+            {
+                "id": "pmod_2",
+                "type": "block",
+                "sourceUserId": "usr_me",
+                "targetUserId": "usr_a",
+            },
+        ]))
+        .unwrap();
+
+        assert_eq!(moderations[0].kind, PlayerModerationType::Unknown);
+        let state = UserModerationState::new("usr_a", moderations);
+        assert!(state.blocked && !state.muted);
+        assert_eq!(state.moderations.len(), 2);
+    }
+}
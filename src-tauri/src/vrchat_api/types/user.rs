@@ -1,7 +1,12 @@
 use serde::{Deserialize, Serialize};
 use specta::Type;
 
+use super::avatar::LimitedAvatar;
 use super::enums::{UserStatus, DeveloperType, AgeVerificationStatus, FriendRequestStatus};
+use super::group::{RepresentedGroup, UserGroup};
+use super::location::Location;
+use super::moderation::UserModerationState;
+use super::world::LimitedWorld;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 #[serde(rename_all = "camelCase")]
@@ -51,11 +56,11 @@ pub struct User {
     #[serde(default)]
     pub platform_history: Vec<String>,
     #[serde(default)]
-    pub location: Option<String>,
+    pub location: Option<Location>,
     #[serde(default)]
     pub traveling_to_world: Option<String>,
     #[serde(default)]
-    pub traveling_to_location: Option<String>,
+    pub traveling_to_location: Option<Location>,
     #[serde(default)]
     pub traveling_to_instance: Option<String>,
     #[serde(default)]
@@ -160,6 +165,9 @@ pub struct User {
     pub pico_id: Option<String>,
     #[serde(default)]
     pub vive_id: Option<String>,
+    /// Our moderations of this user, filled in by `get_user_by_id`; never sent by VRChat
+    #[serde(default, skip_deserializing)]
+    pub moderation: Option<UserModerationState>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
@@ -273,7 +281,7 @@ pub struct LimitedUserFriend {
     #[serde(default)]
     pub last_platform: Option<String>,
     #[serde(default)]
-    pub location: Option<String>,
+    pub location: Option<Location>,
     #[serde(default)]
     pub last_login: Option<String>,
     #[serde(default)]
@@ -295,3 +303,84 @@ pub struct LimitedUserFriend {
     #[serde(default)]
     pub user_icon: Option<String>,
 }
+
+/// Entry of `/users?search=` and `/users/{userId}/mutuals/friends`
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct LimitedUser {
+    pub id: String,
+    pub display_name: String,
+    #[serde(default)]
+    pub bio: String,
+    #[serde(default)]
+    pub bio_links: Vec<String>,
+    #[serde(default)]
+    pub current_avatar_image_url: Option<String>,
+    #[serde(default)]
+    pub current_avatar_thumbnail_image_url: Option<String>,
+    #[serde(default)]
+    pub current_avatar_tags: Vec<String>,
+    #[serde(default)]
+    pub developer_type: DeveloperType,
+    #[serde(default)]
+    pub is_friend: bool,
+    #[serde(default)]
+    pub last_platform: Option<String>,
+    #[serde(default)]
+    pub profile_pic_override: Option<String>,
+    #[serde(default)]
+    pub profile_pic_override_thumbnail: Option<String>,
+    #[serde(default)]
+    pub pronouns: Option<String>,
+    #[serde(default)]
+    pub status: UserStatus,
+    #[serde(default)]
+    pub status_description: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub user_icon: Option<String>,
+}
+
+/// Response of `/user/{userId}/friendStatus`
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct FriendStatus {
+    #[serde(default)]
+    pub is_friend: bool,
+    #[serde(default)]
+    pub incoming_request: bool,
+    #[serde(default)]
+    pub outgoing_request: bool,
+}
+
+impl FriendStatus {
+    pub fn request_status(&self) -> FriendRequestStatus {
+        if self.is_friend {
+            FriendRequestStatus::Completed
+        } else if self.incoming_request {
+            FriendRequestStatus::Incoming
+        } else if self.outgoing_request {
+            FriendRequestStatus::Outgoing
+        } else {
+            FriendRequestStatus::None
+        }
+    }
+}
+
+/// Everything the profile page shows, fetched in one go
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct UserProfile {
+    pub user: User,
+    /// Friends shared with the current user
+    pub mutual_friends: Vec<LimitedUser>,
+    pub groups: Vec<UserGroup>,
+    pub represented_group: Option<RepresentedGroup>,
+    /// Public worlds, most recently updated first
+    pub worlds: Vec<LimitedWorld>,
+    /// Public avatars, most recently updated first
+    pub avatars: Vec<LimitedAvatar>,
+    /// Sections that failed to load and were left empty, e.g. `mutualFriends`
+    pub unavailable: Vec<String>,
+}
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use specta::Type;
 
-use super::enums::ReleaseStatus;
+use super::enums::{OrderOption, ReleaseStatus, SortOption};
 
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 #[serde(rename_all = "camelCase")]
@@ -78,3 +78,167 @@ pub struct LimitedWorld {
     #[serde(default)]
     pub unity_packages: Vec<UnityPackageSummary>,
 }
+
+impl LimitedWorld {
+    /// List endpoints leave out statistics that `/worlds/{id}` returns
+    pub fn is_missing_stats(&self) -> bool {
+        self.visits.is_none()
+            || self.favorites.is_none()
+            || self.popularity.is_none()
+            || self.occupants.is_none()
+            || self.capacity.is_none()
+            || self.recommended_capacity.is_none()
+    }
+
+    /// Copy the statistics present in a `/worlds/{id}` response
+    pub fn merge_stats(&mut self, details: &LimitedWorld) {
+        if details.visits.is_some() {
+            self.visits = details.visits;
+        }
+        if details.favorites.is_some() {
+            self.favorites = details.favorites;
+        }
+        if details.popularity.is_some() {
+            self.popularity = details.popularity;
+        }
+        if details.occupants.is_some() {
+            self.occupants = details.occupants;
+        }
+        if details.capacity.is_some() {
+            self.capacity = details.capacity;
+        }
+        if details.recommended_capacity.is_some() {
+            self.recommended_capacity = details.recommended_capacity;
+        }
+        if details.heat.is_some() {
+            self.heat = details.heat;
+        }
+        if details.organization.is_some() {
+            self.organization = details.organization.clone();
+        }
+    }
+}
+
+/// Which world list to query
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "lowercase")]
+pub enum WorldListSource {
+    /// `/worlds`, the general search
+    #[default]
+    All,
+    /// `/worlds/active`, worlds with players in them
+    Active,
+    /// `/worlds/recent`, recently visited by the current user
+    Recent,
+    /// `/worlds/favorites`, favorited by the current user
+    Favorites,
+}
+
+impl WorldListSource {
+    pub fn path(&self) -> &'static str {
+        match self {
+            WorldListSource::All => "/worlds",
+            WorldListSource::Active => "/worlds/active",
+            WorldListSource::Recent => "/worlds/recent",
+            WorldListSource::Favorites => "/worlds/favorites",
+        }
+    }
+}
+
+/// Filters for a world search. Everything is optional; one call returns one page.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct WorldSearchParams {
+    #[serde(default)]
+    pub source: WorldListSource,
+    #[serde(default)]
+    pub search: Option<String>,
+    /// Worlds must have all of these tags
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Worlds must have none of these tags
+    #[serde(default)]
+    pub exclude_tags: Vec<String>,
+    #[serde(default)]
+    pub featured: Option<bool>,
+    #[serde(default)]
+    pub sort: Option<SortOption>,
+    #[serde(default)]
+    pub order: Option<OrderOption>,
+    /// Only worlds by this author
+    #[serde(default)]
+    pub user_id: Option<String>,
+    #[serde(default)]
+    pub release_status: Option<ReleaseStatus>,
+    #[serde(default)]
+    pub platform: Option<String>,
+    /// Page size, 1 to 100
+    #[serde(default)]
+    pub n: Option<u32>,
+    #[serde(default)]
+    pub offset: Option<u32>,
+}
+
+impl WorldSearchParams {
+    pub const DEFAULT_PAGE_SIZE: u32 = 60;
+    pub const MAX_PAGE_SIZE: u32 = 100;
+
+    /// Query string pairs in the form `/worlds` expects
+    pub fn query_pairs(&self) -> Vec<(&'static str, String)> {
+        let mut pairs = Vec::new();
+
+        if let Some(search) = self
+            .search
+            .as_deref()
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+        {
+            pairs.push(("search", search.to_string()));
+        }
+        if !self.tags.is_empty() {
+            pairs.push(("tag", self.tags.join(",")));
+        }
+        if !self.exclude_tags.is_empty() {
+            pairs.push(("notag", self.exclude_tags.join(",")));
+        }
+        if let Some(featured) = self.featured {
+            pairs.push(("featured", featured.to_string()));
+        }
+        if let Some(sort) = self.sort {
+            pairs.push(("sort", sort.to_string()));
+        }
+        if let Some(order) = self.order {
+            pairs.push(("order", order.to_string()));
+        }
+        if let Some(user_id) = &self.user_id {
+            pairs.push(("userId", user_id.clone()));
+        }
+        if let Some(release_status) = self.release_status {
+            pairs.push(("releaseStatus", release_status.to_string()));
+        }
+        if let Some(platform) = &self.platform {
+            pairs.push(("platform", platform.clone()));
+        }
+
+        let n = self
+            .n
+            .unwrap_or(Self::DEFAULT_PAGE_SIZE)
+            .clamp(1, Self::MAX_PAGE_SIZE);
+        pairs.push(("n", n.to_string()));
+        pairs.push(("offset", self.offset.unwrap_or(0).to_string()));
+
+        pairs
+    }
+}
+
+/// Payload of the `uploaded-worlds-progress` event emitted while uploaded worlds are backfilled
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadedWorldsProgress {
+    /// Worlds updated since the previous event; the first event carries the whole list
+    pub worlds: Vec<LimitedWorld>,
+    /// Detail requests finished so far, failed ones included
+    pub completed: u32,
+    /// Detail requests needed, after the world cache was consulted
+    pub total: u32,
+}
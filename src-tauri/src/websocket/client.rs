@@ -10,35 +10,53 @@ use tokio_tungstenite::{
 };
 
 use super::types::*;
-use crate::store::{UserStore, user_store::CurrentUserPipelineUpdate};
+use crate::friend_alerts::FriendAlerts;
+use crate::status_scheduler::StatusScheduler;
+use crate::store::feed_store::{FeedEntryDraft, FeedEventType, FeedStore};
+use crate::store::notification_store::{InboxNotification, NotificationStore};
+use crate::store::{SettingsStore, UserStore, user_store::CurrentUserPipelineUpdate};
+use crate::vrchat_api::VRChatClientConfig;
 use crate::vrchat_api::error::{VRCError, VRCResult};
+use crate::vrchat_api::types::UserStatus;
 
-const PIPELINE_BASE_URL: &str = "wss://pipeline.vrchat.cloud/";
-const PIPELINE_HOST: &str = "pipeline.vrchat.cloud";
-use crate::http_common::USER_AGENT_STRING;
 // const HEARTBEAT_INTERVAL_SECS: u64 = 30;
 
+/// Stores updated from pipeline events
+#[derive(Clone)]
+pub struct PipelineStores {
+    pub user_store: UserStore,
+    pub feed_store: FeedStore,
+    pub notification_store: NotificationStore,
+    /// Status history of the current user
+    pub settings_store: SettingsStore,
+    /// Reacts to the current user's location
+    pub status_scheduler: StatusScheduler,
+    pub friend_alerts: FriendAlerts,
+}
+
 pub struct VRChatWebSocket {
     auth_cookie: Arc<Mutex<Option<String>>>,
     two_factor_cookie: Arc<Mutex<Option<String>>>,
     app_handle: AppHandle,
     running: Arc<Mutex<bool>>,
-    user_store: UserStore,
+    stores: PipelineStores,
+    config: Arc<VRChatClientConfig>,
 }
 
 impl VRChatWebSocket {
-    pub fn new(app_handle: AppHandle, user_store: UserStore) -> Self {
+    pub fn new(app_handle: AppHandle, stores: PipelineStores, config: VRChatClientConfig) -> Self {
         Self {
             auth_cookie: Arc::new(Mutex::new(None)),
             two_factor_cookie: Arc::new(Mutex::new(None)),
             app_handle,
             running: Arc::new(Mutex::new(false)),
-            user_store,
+            stores,
+            config: Arc::new(config),
         }
     }
 
     pub fn get_user_store(&self) -> UserStore {
-        self.user_store.clone()
+        self.stores.user_store.clone()
     }
 
     pub async fn set_cookies(
@@ -66,7 +84,8 @@ impl VRChatWebSocket {
         let two_factor_cookie = self.two_factor_cookie.clone();
         let app_handle = self.app_handle.clone();
         let running = self.running.clone();
-        let user_store = self.user_store.clone();
+        let stores = self.stores.clone();
+        let config = self.config.clone();
 
         tokio::spawn(async move {
             Self::run_connection_loop(
@@ -74,7 +93,8 @@ impl VRChatWebSocket {
                 two_factor_cookie,
                 app_handle,
                 running,
-                user_store,
+                stores,
+                config,
             )
             .await;
         });
@@ -92,7 +112,8 @@ impl VRChatWebSocket {
         two_factor_cookie: Arc<Mutex<Option<String>>>,
         app_handle: AppHandle,
         running: Arc<Mutex<bool>>,
-        user_store: UserStore,
+        stores: PipelineStores,
+        config: Arc<VRChatClientConfig>,
     ) {
         let mut reconnect_delay = 2;
         const MAX_RECONNECT_DELAY: u64 = 60;
@@ -121,7 +142,8 @@ impl VRChatWebSocket {
                 cookies.1.as_deref(),
                 &app_handle,
                 &running,
-                &user_store,
+                &stores,
+                &config,
             )
             .await
             {
@@ -153,7 +175,8 @@ impl VRChatWebSocket {
         two_factor_cookie: Option<&str>,
         app_handle: &AppHandle,
         running: &Arc<Mutex<bool>>,
-        user_store: &UserStore,
+        stores: &PipelineStores,
+        config: &VRChatClientConfig,
     ) -> VRCResult<()> {
         let auth_cookie_value = auth_cookie.split(';').next().unwrap_or(auth_cookie).trim();
         let auth_token = auth_cookie_value
@@ -166,7 +189,18 @@ impl VRChatWebSocket {
                     "Auth cookie missing auth token required for pipeline WebSocket",
                 )
             })?;
-        let websocket_url = format!("{PIPELINE_BASE_URL}?authToken={}", auth_token);
+        let mut websocket_url = url::Url::parse(&config.pipeline_base_url)
+            .map_err(|e| VRCError::invalid_input(format!("Invalid pipeline URL: {}", e)))?;
+        websocket_url
+            .query_pairs_mut()
+            .append_pair("authToken", auth_token);
+        let pipeline_host = match (websocket_url.host_str(), websocket_url.port()) {
+            (Some(host), Some(port)) => format!("{}:{}", host, port),
+            (Some(host), None) => host.to_string(),
+            (None, _) => {
+                return Err(VRCError::invalid_input("Pipeline URL is missing a host"));
+            }
+        };
 
         log::debug!("Attempting WebSocket connection to: {}", websocket_url);
         log::trace!(
@@ -188,9 +222,9 @@ impl VRChatWebSocket {
         let ws_key = generate_key();
         let request = Request::builder()
             .method("GET")
-            .uri(&websocket_url)
-            .header("Host", PIPELINE_HOST)
-            .header("User-Agent", USER_AGENT_STRING)
+            .uri(websocket_url.as_str())
+            .header("Host", pipeline_host)
+            .header("User-Agent", config.user_agent.as_str())
             .header("Connection", "Upgrade")
             .header("Upgrade", "websocket")
             .header("Sec-WebSocket-Version", "13")
@@ -243,7 +277,7 @@ impl VRChatWebSocket {
 
             match msg {
                 Ok(Message::Text(text)) => {
-                    if let Err(e) = Self::handle_message(&text, app_handle, user_store).await {
+                    if let Err(e) = Self::handle_message(&text, app_handle, stores).await {
                         log::error!("Error handling WebSocket message: {:?}", e);
                     }
                 }
@@ -267,14 +301,27 @@ impl VRChatWebSocket {
     async fn handle_message(
         text: &str,
         app_handle: &AppHandle,
-        user_store: &UserStore,
+        stores: &PipelineStores,
     ) -> VRCResult<()> {
         log::trace!("WebSocket Message Received: {}", text);
+        let PipelineStores {
+            user_store,
+            feed_store,
+            notification_store,
+            settings_store,
+            status_scheduler,
+            friend_alerts,
+        } = stores;
 
         // Parse the outer envelope
         let message: WebSocketMessage = serde_json::from_str(text)
             .map_err(|e| VRCError::parse(format!("Failed to parse WebSocket message: {}", e)))?;
 
+        // Sees the friend state from before this message is applied below
+        friend_alerts
+            .process(&message, user_store, app_handle)
+            .await;
+
         // Handle different message types
         match message {
             WebSocketMessage::Notification(payload) => {
@@ -283,6 +330,12 @@ impl VRChatWebSocket {
                     "Notification event: {}",
                     payload.kind.as_deref().unwrap_or("unknown")
                 );
+                if let Some(account_id) = Self::inbox_account(user_store).await
+                    && let Some(notification) = InboxNotification::from_v1(&account_id, &payload)
+                    && let Err(e) = notification_store.upsert(notification).await
+                {
+                    log::warn!("Failed to store notification: {}", e);
+                }
                 let _ = app_handle.emit("vrchat-notification", &payload);
             }
             WebSocketMessage::ResponseNotification(payload) => {
@@ -297,30 +350,66 @@ impl VRChatWebSocket {
             WebSocketMessage::SeeNotification(notification_id) => {
                 let notification_id = notification_id.into_inner();
                 log::trace!("Notification seen: {}", notification_id);
+                if let Some(account_id) = Self::inbox_account(user_store).await
+                    && let Err(e) = notification_store
+                        .mark_seen(&account_id, &notification_id)
+                        .await
+                {
+                    log::warn!("Failed to mark notification as seen: {}", e);
+                }
                 let _ = app_handle.emit("vrchat-notification-see", &notification_id);
             }
             WebSocketMessage::HideNotification(notification_id) => {
                 let notification_id = notification_id.into_inner();
                 log::trace!("Notification hide requested: {}", notification_id);
+                if let Some(account_id) = Self::inbox_account(user_store).await
+                    && let Err(e) = notification_store.hide(&account_id, &notification_id).await
+                {
+                    log::warn!("Failed to hide notification: {}", e);
+                }
                 let _ = app_handle.emit("vrchat-notification-hide", &notification_id);
             }
             WebSocketMessage::ClearNotification => {
                 log::trace!("Notification clear requested");
+                if let Some(account_id) = Self::inbox_account(user_store).await
+                    && let Err(e) = notification_store.clear(&account_id, Some(1)).await
+                {
+                    log::warn!("Failed to clear notifications: {}", e);
+                }
                 let _ = app_handle.emit("vrchat-notification-clear", ());
             }
             WebSocketMessage::NotificationV2(payload) => {
                 let payload = payload.into_inner();
                 log::trace!("Notification v2: {}", payload.kind);
+                if let Some(account_id) = Self::inbox_account(user_store).await
+                    && let Err(e) = notification_store
+                        .upsert(InboxNotification::from_v2(&account_id, &payload))
+                        .await
+                {
+                    log::warn!("Failed to store notification: {}", e);
+                }
                 let _ = app_handle.emit("vrchat-notification-v2", &payload);
             }
             WebSocketMessage::NotificationV2Update(payload) => {
                 let payload = payload.into_inner();
                 log::trace!("Notification v2 update: {}", payload.id);
+                if let Some(account_id) = Self::inbox_account(user_store).await
+                    && let Err(e) = notification_store
+                        .apply_v2_update(&account_id, &payload)
+                        .await
+                {
+                    log::warn!("Failed to update notification: {}", e);
+                }
                 let _ = app_handle.emit("vrchat-notification-v2-update", &payload);
             }
             WebSocketMessage::NotificationV2Delete(payload) => {
                 let payload = payload.into_inner();
                 log::trace!("Notification v2 delete: {} ids", payload.ids.len());
+                if let Some(account_id) = Self::inbox_account(user_store).await
+                    && let Err(e) = notification_store.remove(&account_id, &payload.ids).await
+                {
+                    log::warn!("Failed to delete notifications: {}", e);
+                }
                 let _ = app_handle.emit("vrchat-notification-v2-delete", &payload);
             }
             WebSocketMessage::FriendAdd(payload) => {
@@ -354,6 +443,10 @@ impl VRChatWebSocket {
                     content.user.display_name,
                     content.user_id
                 );
+                if let Some(previous) = user_store.get_user(&content.user_id).await {
+                    let changes = FeedStore::profile_changes(&previous, &content.user);
+                    Self::record_feed(feed_store, user_store, changes).await;
+                }
                 user_store.upsert_friend(content.user.clone()).await;
                 let event = FriendUpdateEvent {
                     user_id: content.user_id,
@@ -368,6 +461,14 @@ impl VRChatWebSocket {
                     content.user.display_name,
                     content.user_id
                 );
+                let previous = user_store.get_user(&content.user_id).await;
+                let online = FeedStore::online_change(
+                    previous.as_ref(),
+                    &content.user,
+                    content.location.as_ref(),
+                    content.platform.clone(),
+                );
+                Self::record_feed(feed_store, user_store, online.into_iter().collect()).await;
                 user_store.upsert_friend(content.user.clone()).await;
                 if let Some(location) = content.location.clone() {
                     user_store
@@ -398,6 +499,20 @@ impl VRChatWebSocket {
             WebSocketMessage::FriendOffline(payload) => {
                 let content = payload.into_inner();
                 log::info!("Friend offline: {}", content.user_id);
+                let previous = user_store.get_user(&content.user_id).await;
+                let draft = FeedEntryDraft::new(
+                    &content.user_id,
+                    previous
+                        .as_ref()
+                        .map_or(content.user_id.as_str(), |friend| &friend.display_name),
+                    FeedEventType::Offline,
+                )
+                .change(
+                    previous.and_then(|friend| friend.location.map(|loc| loc.to_string())),
+                    None,
+                )
+                .details(content.platform.clone());
+                Self::record_feed(feed_store, user_store, vec![draft]).await;
                 user_store.set_friend_offline(&content.user_id).await;
                 let event = FriendOfflineEvent {
                     user_id: content.user_id,
@@ -411,6 +526,32 @@ impl VRChatWebSocket {
                     content.user_id,
                     content.location
                 );
+                let previous = user_store.get_user(&content.user_id).await;
+                let previous_location =
+                    previous.as_ref().and_then(|friend| friend.location.clone());
+                if previous_location.as_ref() != Some(&content.location) {
+                    let display_name = content
+                        .user
+                        .as_ref()
+                        .or(previous.as_ref())
+                        .map_or(content.user_id.as_str(), |friend| &friend.display_name);
+                    let draft = FeedEntryDraft::new(
+                        &content.user_id,
+                        display_name,
+                        FeedEventType::Location,
+                    )
+                    .change(
+                        previous_location.map(|loc| loc.to_string()),
+                        Some(content.location.to_string()),
+                    )
+                    .details(
+                        content
+                            .world_id
+                            .clone()
+                            .or_else(|| content.location.world_id().map(str::to_string)),
+                    );
+                    Self::record_feed(feed_store, user_store, vec![draft]).await;
+                }
                 if let Some(user) = content.user.clone() {
                     user_store.upsert_friend(user).await;
                 }
@@ -446,6 +587,14 @@ impl VRChatWebSocket {
                     tags: user.tags.clone(),
                 };
                 user_store.apply_current_user_update(patch).await;
+                // Also catches status changes made in-game or on the website
+                if let Some(status) = UserStatus::parse(&user.status)
+                    && let Err(e) = settings_store
+                        .record_status_change(&user.id, status, &user.status_description)
+                        .await
+                {
+                    log::warn!("Failed to record status history: {}", e);
+                }
                 let _ = app_handle.emit("user-update", &content);
             }
             WebSocketMessage::UserLocation(payload) => {
@@ -463,6 +612,8 @@ impl VRChatWebSocket {
                     .update_user_location(&content.user_id, content.location.clone(), platform)
                     .await;
                 let _ = app_handle.emit("user-location", &content);
+                // Rules may call the API, which must not hold up the read loop
+                status_scheduler.notify_location(content.location.clone());
             }
             WebSocketMessage::UserBadgeAssigned(payload) => {
                 let payload = payload.into_inner();
@@ -533,4 +684,33 @@ impl VRChatWebSocket {
 
         Ok(())
     }
+
+    /// Persist friend transitions for the logged-in account, failures are only logged
+    async fn record_feed(
+        feed_store: &FeedStore,
+        user_store: &UserStore,
+        drafts: Vec<FeedEntryDraft>,
+    ) {
+        if drafts.is_empty() {
+            return;
+        }
+
+        let Some(account_id) = user_store.get_current_user_id().await else {
+            log::debug!("Skipping feed entries, no logged-in account");
+            return;
+        };
+
+        if let Err(e) = feed_store.record(&account_id, drafts).await {
+            log::warn!("Failed to record friend feed: {}", e);
+        }
+    }
+
+    /// Account whose inbox pipeline notification events apply to
+    async fn inbox_account(user_store: &UserStore) -> Option<String> {
+        let account_id = user_store.get_current_user_id().await;
+        if account_id.is_none() {
+            log::debug!("Skipping notification inbox update, no logged-in account");
+        }
+        account_id
+    }
 }
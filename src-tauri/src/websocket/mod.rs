@@ -1,5 +1,5 @@
 pub mod client;
 pub mod types;
 
-pub use client::VRChatWebSocket;
+pub use client::{PipelineStores, VRChatWebSocket};
 pub use types::*;
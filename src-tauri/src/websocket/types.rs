@@ -1,4 +1,4 @@
-use crate::vrchat_api::types::LimitedUserFriend;
+use crate::vrchat_api::types::{GroupMember, GroupRole, LimitedUserFriend, Location};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
@@ -134,7 +134,7 @@ pub enum WebSocketMessage {
 }
 
 // Notification payloads
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Type)]
 #[serde(rename_all = "camelCase")]
 pub struct NotificationPayload {
     #[serde(default)]
@@ -179,6 +179,12 @@ pub struct NotificationPayload {
     pub extra: BTreeMap<String, Value>,
 }
 
+impl NotificationPayload {
+    pub fn is_invite_request(&self) -> bool {
+        self.kind.as_deref() == Some("requestInvite")
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ResponseNotificationContent {
@@ -196,6 +202,7 @@ pub struct NotificationV2Payload {
     pub kind: String,
     pub category: String,
     pub is_system: bool,
+    #[serde(alias = "ignoreDND")]
     pub ignore_dnd: bool,
     #[serde(default)]
     pub sender_user_id: Option<String>,
@@ -298,7 +305,7 @@ pub struct FriendOnlineContent {
     #[serde(default)]
     pub platform: Option<String>,
     #[serde(default)]
-    pub location: Option<String>,
+    pub location: Option<Location>,
     #[serde(default)]
     pub can_request_invite: Option<bool>,
     pub user: LimitedUserFriend,
@@ -328,9 +335,9 @@ pub struct FriendOfflineContent {
 pub struct FriendLocationContent {
     #[serde(rename = "userId")]
     pub user_id: String,
-    pub location: String,
+    pub location: Location,
     #[serde(default)]
-    pub traveling_to_location: Option<String>,
+    pub traveling_to_location: Option<Location>,
     #[serde(default)]
     pub world_id: Option<String>,
     #[serde(default)]
@@ -387,11 +394,11 @@ pub struct UserLocationContent {
     pub user_id: String,
     #[serde(default)]
     pub user: Option<LimitedUserFriend>,
-    pub location: String,
+    pub location: Location,
     #[serde(default)]
     pub instance: Option<String>,
     #[serde(default)]
-    pub traveling_to_location: Option<String>,
+    pub traveling_to_location: Option<Location>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -447,14 +454,14 @@ pub struct ModifiedImageUpdateContent {
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 #[serde(rename_all = "camelCase")]
 pub struct InstanceQueueJoinedContent {
-    pub instance_location: String,
+    pub instance_location: Location,
     pub position: i64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 #[serde(rename_all = "camelCase")]
 pub struct InstanceQueueReadyContent {
-    pub instance_location: String,
+    pub instance_location: Location,
     pub expiry: String,
 }
 
@@ -465,16 +472,16 @@ pub struct GroupChangedContent {
     pub group_id: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
 #[serde(rename_all = "camelCase")]
 pub struct GroupMemberUpdatedContent {
-    pub member: Value,
+    pub member: GroupMember,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
 #[serde(rename_all = "camelCase")]
 pub struct GroupRoleUpdatedContent {
-    pub role: Value,
+    pub role: GroupRole,
 }
 
 // Typed payloads for frontend events
@@ -503,3 +510,33 @@ pub struct FriendUpdateEvent {
 pub struct FriendRemovedEvent {
     pub user_id: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vrchat_api::types::GroupMemberStatus;
+
+    #[test]
+    fn test_group_member_update_with_unknown_status() {
+        let content = serde_json::json!({
+            "member": {
+                "id": "gmem_1",
+                "groupId": "grp_1",
+                "userId": "usr_a",
+                "membershipStatus": "suspended",
+            }
+        });
+        let message = serde_json::json!({
+            "type": "group-member-updated",
+            "content": content.to_string(),
+        });
+
+        let message: WebSocketMessage = serde_json::from_str(&message.to_string()).unwrap();
+        let WebSocketMessage::GroupMemberUpdated(payload) = message else {
+            panic!("expected group-member-updated, got {:?}", message);
+        };
+        let member = payload.into_inner().member;
+        assert_eq!(member.user_id, "usr_a");
+        assert_eq!(member.membership_status, GroupMemberStatus::Unknown);
+    }
+}
@@ -0,0 +1,1113 @@
+//! In-process mock of the VRChat REST API used by the integration tests.
+//!
+//! The server speaks just enough HTTP/1.1 for reqwest (one request per
+//! connection, `Connection: close`) and keeps all of its data in a shared
+//! [`MockState`] so tests can seed fixtures and inspect the requests made.
+#![allow(dead_code)]
+
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use serde_json::{Value, json};
+use std::collections::{HashMap, VecDeque};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+use vrc_one_lib::rate_limiter::RateLimitConfig;
+use vrc_one_lib::vrchat_api::{RetryPolicy, VRChatClientConfig};
+
+pub const MOCK_EMAIL: &str = "tester@example.com";
+pub const MOCK_PASSWORD: &str = "hunter2";
+pub const MOCK_TOTP_CODE: &str = "123456";
+pub const MOCK_USER_ID: &str = "usr_00000000-0000-0000-0000-000000000001";
+pub const AUTH_COOKIE: &str = "auth=authcookie_mock";
+pub const TWO_FACTOR_COOKIE: &str = "twoFactorAuth=twofactor_mock";
+
+const API_PREFIX: &str = "/api/1";
+
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub path: String,
+    pub query: HashMap<String, String>,
+    pub headers: HashMap<String, String>,
+    pub body: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl MockResponse {
+    pub fn json(status: u16, body: Value) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body: body.to_string(),
+        }
+    }
+
+    /// VRChat-style `{error:{message,status_code}}` body
+    pub fn error(status: u16, message: &str) -> Self {
+        Self::json(
+            status,
+            json!({ "error": { "message": message, "status_code": status } }),
+        )
+    }
+
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+/// A one-shot response served instead of the regular route handler
+#[derive(Debug, Clone)]
+struct Override {
+    method: String,
+    path: String,
+    /// Query parameter the request must carry, if any
+    query: Option<(String, String)>,
+    response: MockResponse,
+}
+
+pub struct MockState {
+    pub require_two_factor: bool,
+    pub current_user: Value,
+    pub users: HashMap<String, Value>,
+    /// Friends shared with the current user, keyed by the other user's ID
+    pub mutual_friends: HashMap<String, Vec<Value>>,
+    pub online_friends: Vec<Value>,
+    pub offline_friends: Vec<Value>,
+    pub worlds: Vec<Value>,
+    pub world_details: HashMap<String, Value>,
+    pub avatars: Vec<Value>,
+    pub favorite_avatars: Vec<Value>,
+    /// Keyed by full location, `worldId:instanceId`
+    pub instances: HashMap<String, Value>,
+    pub notifications: Vec<Value>,
+    pub notifications_v2: Vec<Value>,
+    /// User IDs with a pending outgoing friend request
+    pub friend_requests: Vec<String>,
+    pub player_moderations: Vec<Value>,
+    pub favorites: Vec<Value>,
+    pub favorite_groups: Vec<Value>,
+    pub groups: Vec<Value>,
+    /// Memberships of every group, matched by `groupId` and `userId`
+    pub group_members: Vec<Value>,
+    pub group_roles: Vec<Value>,
+    pub group_posts: Vec<Value>,
+    /// Newest first, like the API
+    pub group_audit_logs: Vec<Value>,
+    pub requests: Vec<RecordedRequest>,
+    pub response_delay: Duration,
+    overrides: VecDeque<Override>,
+}
+
+impl Default for MockState {
+    fn default() -> Self {
+        Self {
+            require_two_factor: false,
+            current_user: mock_user(MOCK_USER_ID, "Mock Tester"),
+            users: HashMap::new(),
+            mutual_friends: HashMap::new(),
+            online_friends: Vec::new(),
+            offline_friends: Vec::new(),
+            worlds: Vec::new(),
+            world_details: HashMap::new(),
+            avatars: Vec::new(),
+            favorite_avatars: Vec::new(),
+            instances: HashMap::new(),
+            notifications: Vec::new(),
+            notifications_v2: Vec::new(),
+            friend_requests: Vec::new(),
+            player_moderations: Vec::new(),
+            favorites: Vec::new(),
+            favorite_groups: Vec::new(),
+            groups: Vec::new(),
+            group_members: Vec::new(),
+            group_roles: Vec::new(),
+            group_posts: Vec::new(),
+            group_audit_logs: Vec::new(),
+            requests: Vec::new(),
+            response_delay: Duration::ZERO,
+            overrides: VecDeque::new(),
+        }
+    }
+}
+
+pub struct MockVRChatServer {
+    addr: SocketAddr,
+    state: Arc<Mutex<MockState>>,
+    task: JoinHandle<()>,
+}
+
+impl MockVRChatServer {
+    pub async fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind mock server");
+        let addr = listener.local_addr().expect("mock server address");
+        let state = Arc::new(Mutex::new(MockState::default()));
+
+        let accept_state = state.clone();
+        let task = tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let state = accept_state.clone();
+                tokio::spawn(async move {
+                    let _ = handle_connection(stream, state).await;
+                });
+            }
+        });
+
+        Self { addr, state, task }
+    }
+
+    pub fn base_url(&self) -> String {
+        format!("http://{}{}", self.addr, API_PREFIX)
+    }
+
+    /// Client config pointing at this server with fast, bounded retries
+    pub fn config(&self) -> VRChatClientConfig {
+        VRChatClientConfig {
+            api_base_url: self.base_url(),
+            pipeline_base_url: format!("ws://{}/", self.addr),
+            connect_timeout: Duration::from_secs(2),
+            request_timeout: Duration::from_secs(5),
+            retry: RetryPolicy {
+                max_retries: 2,
+                initial_backoff: Duration::from_millis(10),
+                max_backoff: Duration::from_millis(50),
+            },
+            rate_limits: RateLimitConfig::unlimited(),
+            ..VRChatClientConfig::default()
+        }
+    }
+
+    pub fn with_state<R>(&self, f: impl FnOnce(&mut MockState) -> R) -> R {
+        let mut state = self.state.lock().unwrap();
+        f(&mut state)
+    }
+
+    /// Serve `response` for the next request matching `method` and `path` (without `/api/1`)
+    pub fn push_override(&self, method: &str, path: &str, response: MockResponse) {
+        self.with_state(|state| {
+            state.overrides.push_back(Override {
+                method: method.to_string(),
+                path: path.to_string(),
+                query: None,
+                response,
+            })
+        });
+    }
+
+    /// Like `push_override`, but only for requests whose query has `key` set to `value`
+    pub fn push_query_override(
+        &self,
+        method: &str,
+        path: &str,
+        (key, value): (&str, &str),
+        response: MockResponse,
+    ) {
+        self.with_state(|state| {
+            state.overrides.push_back(Override {
+                method: method.to_string(),
+                path: path.to_string(),
+                query: Some((key.to_string(), value.to_string())),
+                response,
+            })
+        });
+    }
+
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.with_state(|state| state.requests.clone())
+    }
+
+    pub fn requests_to(&self, method: &str, path: &str) -> Vec<RecordedRequest> {
+        self.requests()
+            .into_iter()
+            .filter(|req| req.method == method && req.path == path)
+            .collect()
+    }
+}
+
+impl Drop for MockVRChatServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+// Fixtures
+
+pub fn mock_user(id: &str, display_name: &str) -> Value {
+    json!({
+        "id": id,
+        "username": display_name.to_lowercase().replace(' ', "_"),
+        "displayName": display_name,
+        "status": "active",
+        "statusDescription": "",
+        "bio": "",
+        "platform": "standalonewindows",
+        "location": "offline",
+        "tags": [],
+    })
+}
+
+pub fn mock_friend(index: usize, online: bool) -> Value {
+    json!({
+        "id": format!("usr_friend_{:04}", index),
+        "displayName": format!("Friend {}", index),
+        "status": if online { "active" } else { "offline" },
+        "statusDescription": "",
+        "location": if online { "wrld_mock:1~region(jp)" } else { "offline" },
+        "platform": if online { "standalonewindows" } else { "" },
+        "isFriend": true,
+    })
+}
+
+/// World as returned by the list endpoint, which omits most statistics
+pub fn mock_world_summary(id: &str, name: &str) -> Value {
+    json!({
+        "id": id,
+        "name": name,
+        "authorId": MOCK_USER_ID,
+        "releaseStatus": "public",
+        "tags": [],
+    })
+}
+
+pub fn mock_world_details(id: &str, name: &str, visits: i32) -> Value {
+    json!({
+        "id": id,
+        "name": name,
+        "authorId": MOCK_USER_ID,
+        "releaseStatus": "public",
+        "visits": visits,
+        "favorites": visits / 10,
+        "popularity": 5,
+        "occupants": 2,
+        "capacity": 32,
+        "recommendedCapacity": 16,
+        "heat": 3,
+        "organization": "vrchat",
+        "tags": [],
+    })
+}
+
+pub fn mock_avatar(id: &str, name: &str) -> Value {
+    json!({
+        "id": id,
+        "name": name,
+        "authorId": MOCK_USER_ID,
+        "releaseStatus": "private",
+        "imageUrl": format!("https://example.com/{}.png", id),
+        "thumbnailImageUrl": format!("https://example.com/{}_thumb.png", id),
+        "tags": [],
+    })
+}
+
+pub fn mock_group(id: &str, name: &str) -> Value {
+    json!({
+        "id": id,
+        "name": name,
+        "shortCode": "MOCK",
+        "discriminator": "0001",
+        "description": "",
+        "privacy": "default",
+        "ownerId": MOCK_USER_ID,
+        "memberCount": 0,
+        "joinState": "request",
+        "tags": [],
+    })
+}
+
+pub fn mock_group_member(group_id: &str, user_id: &str, role_ids: &[&str]) -> Value {
+    json!({
+        "id": format!("gmem_{}_{}", group_id, user_id),
+        "groupId": group_id,
+        "userId": user_id,
+        "user": { "id": user_id, "displayName": user_id },
+        "roleIds": role_ids,
+        "membershipStatus": "member",
+        "joinedAt": "2026-01-01T00:00:00.000Z",
+    })
+}
+
+pub fn mock_group_role(group_id: &str, id: &str, name: &str) -> Value {
+    json!({
+        "id": id,
+        "groupId": group_id,
+        "name": name,
+        "permissions": ["group-members-viewall"],
+        "order": 1,
+    })
+}
+
+pub fn mock_group_audit_entry(group_id: &str, id: &str, event_type: &str, minute: u32) -> Value {
+    json!({
+        "id": id,
+        "created_at": format!("2026-03-01T{:02}:{:02}:00.000Z", minute / 60, minute % 60),
+        "groupId": group_id,
+        "actorId": MOCK_USER_ID,
+        "actorDisplayName": "Mock Tester",
+        "targetId": "usr_target",
+        "eventType": event_type,
+        "description": format!("Mock Tester {}", event_type),
+        "data": {},
+    })
+}
+
+pub fn mock_instance(world_id: &str, instance_id: &str, user_count: i32) -> Value {
+    let location = format!("{}:{}", world_id, instance_id);
+    json!({
+        "id": location,
+        "location": location,
+        "instanceId": instance_id,
+        "worldId": world_id,
+        "name": instance_id.split('~').next().unwrap_or_default(),
+        "type": "public",
+        "region": "jp",
+        "capacity": 32,
+        "recommendedCapacity": 16,
+        "userCount": user_count,
+        "n_users": user_count,
+        "platforms": { "android": 1, "standalonewindows": user_count - 1 },
+        "queueEnabled": true,
+        "queueSize": 0,
+        "full": false,
+        "active": true,
+        "shortName": "abcd1234",
+        "tags": [],
+    })
+}
+
+pub fn mock_notification(id: &str, kind: &str) -> Value {
+    json!({
+        "id": id,
+        "type": kind,
+        "senderUserId": "usr_sender",
+        "senderUsername": "Sender",
+        "receiverUserId": MOCK_USER_ID,
+        "message": "",
+        "details": "{}",
+        "seen": false,
+        "created_at": "2024-01-01T00:00:00.000Z",
+    })
+}
+
+pub fn mock_favorite(id: &str, kind: &str, target_id: &str, group: &str) -> Value {
+    json!({
+        "id": id,
+        "type": kind,
+        "favoriteId": target_id,
+        "tags": [group],
+    })
+}
+
+pub fn mock_favorite_group(kind: &str, name: &str, display_name: &str) -> Value {
+    json!({
+        "id": format!("fvgrp_{}", name),
+        "ownerId": MOCK_USER_ID,
+        "ownerDisplayName": "Mock Tester",
+        "name": name,
+        "displayName": display_name,
+        "type": kind,
+        "visibility": "private",
+        "tags": [],
+    })
+}
+
+pub fn mock_player_moderation(id: &str, target_user_id: &str, kind: &str) -> Value {
+    json!({
+        "id": id,
+        "type": kind,
+        "sourceUserId": MOCK_USER_ID,
+        "sourceDisplayName": "Mock Tester",
+        "targetUserId": target_user_id,
+        "targetDisplayName": format!("Name of {}", target_user_id),
+        "created": "2024-01-01T00:00:00.000Z",
+    })
+}
+
+pub fn mock_notification_v2(id: &str) -> Value {
+    json!({
+        "id": id,
+        "version": 2,
+        "type": "group.announcement",
+        "category": "social.group",
+        "isSystem": false,
+        "ignoreDND": false,
+        "receiverUserId": MOCK_USER_ID,
+        "title": "Announcement",
+        "responses": [{ "type": "unsubscribe", "data": "grp_mock", "text": "Unsubscribe" }],
+        "createdAt": "2024-01-02T00:00:00.000Z",
+    })
+}
+
+// HTTP plumbing
+
+async fn handle_connection(
+    mut stream: TcpStream,
+    state: Arc<Mutex<MockState>>,
+) -> std::io::Result<()> {
+    let Some(request) = read_request(&mut stream).await? else {
+        return Ok(());
+    };
+
+    let delay = state.lock().unwrap().response_delay;
+    if !delay.is_zero() {
+        tokio::time::sleep(delay).await;
+    }
+
+    let response = {
+        let mut state = state.lock().unwrap();
+        state.requests.push(request.clone());
+        route(&mut state, &request)
+    };
+
+    let mut raw = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n",
+        response.status,
+        reason_phrase(response.status),
+        response.body.len()
+    );
+    for (name, value) in &response.headers {
+        raw.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    raw.push_str("\r\n");
+    raw.push_str(&response.body);
+
+    stream.write_all(raw.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+async fn read_request(stream: &mut TcpStream) -> std::io::Result<Option<RecordedRequest>> {
+    let mut buffer = Vec::new();
+    let mut chunk = [0u8; 4096];
+
+    let header_end = loop {
+        let read = stream.read(&mut chunk).await?;
+        if read == 0 {
+            return Ok(None);
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+        if let Some(pos) = buffer.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos + 4;
+        }
+    };
+
+    let head = String::from_utf8_lossy(&buffer[..header_end]).to_string();
+    let mut lines = head.split("\r\n");
+    let request_line = lines.next().unwrap_or_default();
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let target = parts.next().unwrap_or_default().to_string();
+
+    let mut headers = HashMap::new();
+    for line in lines.filter(|line| !line.is_empty()) {
+        if let Some((name, value)) = line.split_once(':') {
+            headers.insert(name.trim().to_lowercase(), value.trim().to_string());
+        }
+    }
+
+    let content_length = headers
+        .get("content-length")
+        .and_then(|value| value.parse::<usize>().ok())
+        .unwrap_or(0);
+    while buffer.len() < header_end + content_length {
+        let read = stream.read(&mut chunk).await?;
+        if read == 0 {
+            break;
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+    }
+    let body = String::from_utf8_lossy(&buffer[header_end..]).to_string();
+
+    let parsed = url::Url::parse(&format!("http://mock{}", target)).expect("request target");
+    let path = parsed
+        .path()
+        .strip_prefix(API_PREFIX)
+        .unwrap_or(parsed.path())
+        .to_string();
+    let query = parsed.query_pairs().into_owned().collect();
+
+    Ok(Some(RecordedRequest {
+        method,
+        path,
+        query,
+        headers,
+        body,
+    }))
+}
+
+fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        429 => "Too Many Requests",
+        500 => "Internal Server Error",
+        503 => "Service Unavailable",
+        _ => "Unknown",
+    }
+}
+
+// Routing
+
+fn route(state: &mut MockState, request: &RecordedRequest) -> MockResponse {
+    if let Some(index) = state.overrides.iter().position(|o| {
+        o.method == request.method
+            && o.path == request.path
+            && o.query
+                .as_ref()
+                .is_none_or(|(key, value)| request.query.get(key) == Some(value))
+    }) {
+        return state.overrides.remove(index).unwrap().response;
+    }
+
+    let method = request.method.as_str();
+    let segments: Vec<&str> = request
+        .path
+        .trim_matches('/')
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect();
+
+    match (method, segments.as_slice()) {
+        ("GET", ["auth", "user"]) => get_auth_user(state, request),
+        ("POST", ["auth", "twofactorauth", _, "verify"]) => verify_two_factor(request),
+        ("PUT", ["logout"]) => ok_message(),
+        _ if !is_authenticated(state, request) => {
+            MockResponse::error(401, "\"Missing Credentials\"")
+        }
+        ("GET", ["auth", "user", "friends"]) => list_friends(state, request),
+        ("GET", ["auth", "user", "notifications"]) => paged(&state.notifications, request),
+        ("PUT", ["auth", "user", "notifications", ..]) => ok_message(),
+        ("GET", ["notifications"]) => paged(&state.notifications_v2, request),
+        ("POST", ["notifications", _, "see" | "respond"]) => ok_message(),
+        ("DELETE", ["notifications", _]) => ok_message(),
+        ("GET", ["worlds"]) => paged(&by_author(&state.worlds, request), request),
+        ("GET", ["worlds", "active" | "recent" | "favorites"]) => paged(&state.worlds, request),
+        ("GET", ["worlds", world_id]) => match state.world_details.get(*world_id) {
+            Some(world) => MockResponse::json(200, world.clone()),
+            None => MockResponse::error(404, "World not found"),
+        },
+        ("GET", ["avatars"]) => paged(&by_author(&state.avatars, request), request),
+        ("GET", ["avatars", "favorites"]) => paged(&state.favorite_avatars, request),
+        ("GET", ["avatars", avatar_id]) => match find_by_id(&state.avatars, avatar_id) {
+            Some(avatar) => MockResponse::json(200, avatar),
+            None => MockResponse::error(404, "Avatar not found"),
+        },
+        ("PUT", ["avatars", avatar_id, action @ ("select" | "selectFallback")]) => {
+            select_avatar(state, avatar_id, action)
+        }
+        ("GET", ["users"]) => search_users(state, request),
+        ("GET", ["users", user_id, "mutuals", "friends"]) => {
+            match state.mutual_friends.get(*user_id) {
+                Some(friends) => paged(friends, request),
+                None => MockResponse::json(200, json!([])),
+            }
+        }
+        ("GET", ["users", user_id, "groups"]) => {
+            MockResponse::json(200, json!(user_groups(state, user_id)))
+        }
+        ("GET", ["users", user_id, "groups", "represented"]) => {
+            let represented = user_groups(state, user_id)
+                .into_iter()
+                .find(|group| group["isRepresenting"] == true);
+            MockResponse::json(200, represented.unwrap_or_else(|| json!({})))
+        }
+        ("GET", ["groups", group_id]) => match find_by_id(&state.groups, group_id) {
+            Some(mut group) => {
+                group["roles"] = json!(in_group(&state.group_roles, group_id));
+                MockResponse::json(200, group)
+            }
+            None => MockResponse::error(404, "Group not found"),
+        },
+        ("GET", ["groups", group_id, "members"]) => {
+            paged(&in_group(&state.group_members, group_id), request)
+        }
+        ("GET", ["groups", group_id, "roles"]) => {
+            MockResponse::json(200, json!(in_group(&state.group_roles, group_id)))
+        }
+        ("GET", ["groups", _, "announcements"]) => MockResponse::json(200, json!({})),
+        ("GET", ["groups", group_id, "posts"]) => {
+            let posts = in_group(&state.group_posts, group_id);
+            MockResponse::json(
+                200,
+                json!({ "posts": page(&posts, request), "total": posts.len() }),
+            )
+        }
+        ("GET", ["groups", _, "instances"]) => MockResponse::json(200, json!([])),
+        ("GET", ["groups", group_id, "bans"]) => {
+            paged(&with_status(state, group_id, "banned"), request)
+        }
+        ("POST", ["groups", group_id, "bans"]) => ban_group_member(state, group_id, request),
+        ("DELETE", ["groups", group_id, "bans", user_id]) => {
+            remove_member(state, group_id, user_id);
+            ok_message()
+        }
+        ("DELETE", ["groups", group_id, "members", user_id]) => {
+            if remove_member(state, group_id, user_id) {
+                ok_message()
+            } else {
+                MockResponse::error(404, "Member not found")
+            }
+        }
+        ("GET", ["groups", group_id, "requests"]) => {
+            paged(&with_status(state, group_id, "requested"), request)
+        }
+        ("PUT", ["groups", group_id, "requests", user_id]) => {
+            respond_join_request(state, group_id, user_id, request)
+        }
+        (
+            method @ ("PUT" | "DELETE"),
+            ["groups", group_id, "members", user_id, "roles", role_id],
+        ) => {
+            let Some(member) = find_member(state, group_id, user_id) else {
+                return MockResponse::error(404, "Member not found");
+            };
+            let roles = member["roleIds"].as_array_mut().unwrap();
+            roles.retain(|role| role != role_id);
+            if method == "PUT" {
+                roles.push(json!(role_id));
+            }
+            MockResponse::json(200, Value::Array(roles.clone()))
+        }
+        ("GET", ["groups", group_id, "auditLogs"]) => group_audit_logs(state, group_id, request),
+        ("GET", ["instances", location]) => match state.instances.get(*location) {
+            Some(instance) => MockResponse::json(200, instance.clone()),
+            None => MockResponse::error(404, "Instance not found"),
+        },
+        ("GET", ["instances", location, "shortName"]) => match state.instances.get(*location) {
+            Some(instance) => MockResponse::json(
+                200,
+                json!({ "secureName": "secure", "shortName": instance["shortName"] }),
+            ),
+            None => MockResponse::error(404, "Instance not found"),
+        },
+        ("POST", ["instances"]) => create_instance(state, request),
+        ("POST", ["invite", "myself", "to", _]) => sent_notification("invite", MOCK_USER_ID),
+        ("POST", ["invite", _, "response"]) => sent_notification("inviteResponse", "usr_sender"),
+        ("POST", ["invite", user_id]) => sent_notification("invite", user_id),
+        ("POST", ["requestInvite", user_id]) => sent_notification("requestInvite", user_id),
+        ("POST", ["user", user_id, "friendRequest"]) => {
+            state.friend_requests.push(user_id.to_string());
+            sent_notification("friendRequest", user_id)
+        }
+        ("DELETE", ["user", user_id, "friendRequest"]) => {
+            state.friend_requests.retain(|id| id != user_id);
+            ok_message()
+        }
+        ("GET", ["user", user_id, "friendStatus"]) => MockResponse::json(
+            200,
+            json!({
+                "isFriend": false,
+                "incomingRequest": false,
+                "outgoingRequest": state.friend_requests.iter().any(|id| id == user_id),
+            }),
+        ),
+        ("DELETE", ["auth", "user", "friends", _]) => ok_message(),
+        ("GET", ["favorites"]) => {
+            let kind = request.query.get("type").cloned();
+            let favorites: Vec<Value> = state
+                .favorites
+                .iter()
+                .filter(|f| kind.as_deref().is_none_or(|kind| f["type"] == kind))
+                .cloned()
+                .collect();
+            paged(&favorites, request)
+        }
+        ("POST", ["favorites"]) => add_favorite(state, request),
+        ("DELETE", ["favorites", favorite_id]) => {
+            state.favorites.retain(|f| f["id"] != *favorite_id);
+            ok_message()
+        }
+        ("GET", ["favorite", "groups"]) => paged(&state.favorite_groups, request),
+        ("PUT" | "DELETE", ["favorite", "group", _, _, user_id]) if *user_id == MOCK_USER_ID => {
+            ok_message()
+        }
+        ("GET", ["auth", "user", "favoritelimits"]) => MockResponse::json(
+            200,
+            json!({
+                "defaultMaxFavoriteGroups": 4,
+                "defaultMaxFavoritesPerGroup": 100,
+                "maxFavoriteGroups": { "avatar": 6, "friend": 3, "world": 4 },
+                "maxFavoritesPerGroup": { "avatar": 50, "friend": 150, "world": 100 },
+            }),
+        ),
+        ("GET", ["auth", "user", "playermoderations"]) => {
+            MockResponse::json(200, Value::Array(state.player_moderations.clone()))
+        }
+        ("POST", ["auth", "user", "playermoderations"]) => moderate(state, request),
+        ("PUT", ["auth", "user", "unplayermoderate"]) => {
+            let body: Value = serde_json::from_str(&request.body).unwrap_or_default();
+            state
+                .player_moderations
+                .retain(|m| m["targetUserId"] != body["moderated"] || m["type"] != body["type"]);
+            ok_message()
+        }
+        ("GET", ["users", user_id]) => match state.users.get(*user_id) {
+            Some(user) => MockResponse::json(200, user.clone()),
+            None if *user_id == MOCK_USER_ID => MockResponse::json(200, state.current_user.clone()),
+            None => MockResponse::error(404, "User not found"),
+        },
+        ("PUT", ["users", user_id]) if *user_id == MOCK_USER_ID => {
+            update_current_user(state, request)
+        }
+        _ => MockResponse::error(404, "Not found"),
+    }
+}
+
+fn ok_message() -> MockResponse {
+    MockResponse::json(200, json!({ "success": { "message": "Ok!" } }))
+}
+
+fn has_cookie(request: &RecordedRequest, cookie: &str) -> bool {
+    request
+        .headers
+        .get("cookie")
+        .map(|value| value.split(';').any(|part| part.trim() == cookie))
+        .unwrap_or(false)
+}
+
+fn is_authenticated(state: &MockState, request: &RecordedRequest) -> bool {
+    has_cookie(request, AUTH_COOKIE)
+        && (!state.require_two_factor || has_cookie(request, TWO_FACTOR_COOKIE))
+}
+
+fn get_auth_user(state: &MockState, request: &RecordedRequest) -> MockResponse {
+    let expected = format!(
+        "Basic {}",
+        BASE64.encode(format!("{}:{}", MOCK_EMAIL, MOCK_PASSWORD))
+    );
+
+    let mut response = match request.headers.get("authorization") {
+        Some(value) if *value == expected => MockResponse::json(200, Value::Null)
+            .with_header("Set-Cookie", &format!("{}; Path=/; HttpOnly", AUTH_COOKIE)),
+        Some(_) => return MockResponse::error(401, "Invalid Username/Email or Password"),
+        None if has_cookie(request, AUTH_COOKIE) => MockResponse::json(200, Value::Null),
+        None => return MockResponse::error(401, "\"Missing Credentials\""),
+    };
+
+    if state.require_two_factor && !has_cookie(request, TWO_FACTOR_COOKIE) {
+        response.body = json!({ "requiresTwoFactorAuth": ["totp", "otp"] }).to_string();
+    } else {
+        response.body = state.current_user.to_string();
+    }
+
+    response
+}
+
+fn verify_two_factor(request: &RecordedRequest) -> MockResponse {
+    if !has_cookie(request, AUTH_COOKIE) {
+        return MockResponse::error(401, "\"Missing Credentials\"");
+    }
+
+    let code = serde_json::from_str::<Value>(&request.body)
+        .ok()
+        .and_then(|body| body.get("code").and_then(Value::as_str).map(str::to_string));
+
+    if code.as_deref() == Some(MOCK_TOTP_CODE) {
+        MockResponse::json(200, json!({ "verified": true })).with_header(
+            "Set-Cookie",
+            &format!("{}; Path=/; HttpOnly", TWO_FACTOR_COOKIE),
+        )
+    } else {
+        MockResponse::json(400, json!({ "verified": false }))
+    }
+}
+
+fn list_friends(state: &MockState, request: &RecordedRequest) -> MockResponse {
+    let offline = request.query.get("offline").map(String::as_str) == Some("true");
+    let source = if offline {
+        &state.offline_friends
+    } else {
+        &state.online_friends
+    };
+    paged(source, request)
+}
+
+fn paged(items: &[Value], request: &RecordedRequest) -> MockResponse {
+    MockResponse::json(200, Value::Array(page(items, request)))
+}
+
+/// Slice of `items` selected by the `n` and `offset` query parameters
+fn page(items: &[Value], request: &RecordedRequest) -> Vec<Value> {
+    let n = request
+        .query
+        .get("n")
+        .and_then(|value| value.parse::<usize>().ok())
+        .unwrap_or(60);
+    let offset = request
+        .query
+        .get("offset")
+        .and_then(|value| value.parse::<usize>().ok())
+        .unwrap_or(0);
+
+    items.iter().skip(offset).take(n).cloned().collect()
+}
+
+fn create_instance(state: &mut MockState, request: &RecordedRequest) -> MockResponse {
+    let body: Value = serde_json::from_str(&request.body).unwrap_or(Value::Null);
+    let Some(world_id) = body.get("worldId").and_then(Value::as_str) else {
+        return MockResponse::error(400, "worldId is required");
+    };
+
+    let instance_id = format!(
+        "{}~region({})",
+        10000 + state.instances.len(),
+        body["region"].as_str().unwrap_or("us")
+    );
+    let mut instance = mock_instance(world_id, &instance_id, 0);
+    instance["type"] = body["type"].clone();
+    instance["ownerId"] = body["ownerId"].clone();
+    instance["region"] = body["region"].clone();
+    state.instances.insert(
+        instance["id"].as_str().unwrap().to_string(),
+        instance.clone(),
+    );
+    MockResponse::json(200, instance)
+}
+
+fn add_favorite(state: &mut MockState, request: &RecordedRequest) -> MockResponse {
+    let body: Value = serde_json::from_str(&request.body).unwrap_or_default();
+    let (Some(kind), Some(target)) = (body["type"].as_str(), body["favoriteId"].as_str()) else {
+        return MockResponse::error(400, "Invalid favorite");
+    };
+    let group = body["tags"][0].as_str().unwrap_or_default();
+
+    let favorite = mock_favorite(
+        &format!("fvrt_{}", state.favorites.len()),
+        kind,
+        target,
+        group,
+    );
+    state.favorites.push(favorite.clone());
+    MockResponse::json(200, favorite)
+}
+
+fn moderate(state: &mut MockState, request: &RecordedRequest) -> MockResponse {
+    let body: Value = serde_json::from_str(&request.body).unwrap_or_default();
+    let (Some(target), Some(kind)) = (body["moderated"].as_str(), body["type"].as_str()) else {
+        return MockResponse::error(400, "Invalid moderation");
+    };
+
+    let moderation = mock_player_moderation(
+        &format!("pmod_{}", state.player_moderations.len()),
+        target,
+        kind,
+    );
+    state.player_moderations.push(moderation.clone());
+    MockResponse::json(200, moderation)
+}
+
+fn sent_notification(kind: &str, receiver: &str) -> MockResponse {
+    MockResponse::json(
+        200,
+        json!({
+            "id": format!("not_{}", kind),
+            "type": kind,
+            "senderUserId": MOCK_USER_ID,
+            "receiverUserId": receiver,
+            "message": "",
+            "details": {},
+            "created_at": "2024-01-01T00:00:00.000Z",
+        }),
+    )
+}
+
+fn update_current_user(state: &mut MockState, request: &RecordedRequest) -> MockResponse {
+    let patch: Value = serde_json::from_str(&request.body).unwrap_or(Value::Null);
+    if let (Some(user), Some(patch)) = (state.current_user.as_object_mut(), patch.as_object()) {
+        for (key, value) in patch {
+            user.insert(key.clone(), value.clone());
+        }
+    }
+    MockResponse::json(200, state.current_user.clone())
+}
+
+fn find_by_id(items: &[Value], id: &str) -> Option<Value> {
+    items.iter().find(|item| item["id"] == id).cloned()
+}
+
+fn select_avatar(state: &mut MockState, avatar_id: &str, action: &str) -> MockResponse {
+    let Some(avatar) = find_by_id(&state.avatars, avatar_id) else {
+        return MockResponse::error(404, "Avatar not found");
+    };
+
+    let user = &mut state.current_user;
+    if action == "selectFallback" {
+        user["fallbackAvatar"] = json!(avatar_id);
+    } else {
+        user["currentAvatar"] = json!(avatar_id);
+        user["currentAvatarImageUrl"] = avatar["imageUrl"].clone();
+        user["currentAvatarThumbnailImageUrl"] = avatar["thumbnailImageUrl"].clone();
+    }
+    MockResponse::json(200, state.current_user.clone())
+}
+
+fn in_group(items: &[Value], group_id: &str) -> Vec<Value> {
+    items
+        .iter()
+        .filter(|item| item["groupId"] == group_id)
+        .cloned()
+        .collect()
+}
+
+fn user_groups(state: &MockState, user_id: &str) -> Vec<Value> {
+    state
+        .group_members
+        .iter()
+        .filter(|member| member["userId"] == user_id)
+        .filter_map(|member| {
+            let mut group = find_by_id(&state.groups, member["groupId"].as_str()?)?;
+            group["groupId"] = group["id"].clone();
+            group["id"] = member["id"].clone();
+            group["isRepresenting"] = json!(member["isRepresenting"] == true);
+            Some(group)
+        })
+        .collect()
+}
+
+/// Case-insensitive display name match on the `search` query parameter
+fn search_users(state: &MockState, request: &RecordedRequest) -> MockResponse {
+    let search = request
+        .query
+        .get("search")
+        .map(|value| value.to_lowercase())
+        .unwrap_or_default();
+    let mut users: Vec<Value> = state
+        .users
+        .values()
+        .filter(|user| {
+            user["displayName"]
+                .as_str()
+                .is_some_and(|name| name.to_lowercase().contains(&search))
+        })
+        .cloned()
+        .collect();
+    users.sort_by_key(|user| user["id"].as_str().unwrap_or_default().to_string());
+    paged(&users, request)
+}
+
+/// Applies the `userId` and `releaseStatus` filters of the world and avatar lists
+fn by_author(items: &[Value], request: &RecordedRequest) -> Vec<Value> {
+    let author = request.query.get("userId");
+    let release_status = request
+        .query
+        .get("releaseStatus")
+        .filter(|status| *status != "all");
+    items
+        .iter()
+        .filter(|item| author.is_none_or(|author| item["authorId"] == author.as_str()))
+        .filter(|item| release_status.is_none_or(|status| item["releaseStatus"] == status.as_str()))
+        .cloned()
+        .collect()
+}
+
+fn find_member<'a>(
+    state: &'a mut MockState,
+    group_id: &str,
+    user_id: &str,
+) -> Option<&'a mut Value> {
+    state
+        .group_members
+        .iter_mut()
+        .find(|m| m["groupId"] == group_id && m["userId"] == user_id)
+}
+
+/// Returns whether the user had a membership record
+fn remove_member(state: &mut MockState, group_id: &str, user_id: &str) -> bool {
+    let before = state.group_members.len();
+    state
+        .group_members
+        .retain(|m| !(m["groupId"] == group_id && m["userId"] == user_id));
+    state.group_members.len() != before
+}
+
+fn with_status(state: &MockState, group_id: &str, status: &str) -> Vec<Value> {
+    in_group(&state.group_members, group_id)
+        .into_iter()
+        .filter(|m| m["membershipStatus"] == status)
+        .collect()
+}
+
+fn ban_group_member(
+    state: &mut MockState,
+    group_id: &str,
+    request: &RecordedRequest,
+) -> MockResponse {
+    let body: Value = serde_json::from_str(&request.body).unwrap_or(Value::Null);
+    let Some(user_id) = body.get("userId").and_then(Value::as_str) else {
+        return MockResponse::error(400, "userId is required");
+    };
+
+    if find_member(state, group_id, user_id).is_none() {
+        state
+            .group_members
+            .push(mock_group_member(group_id, user_id, &[]));
+    }
+    let member = find_member(state, group_id, user_id).unwrap();
+    member["membershipStatus"] = json!("banned");
+    member["bannedAt"] = json!("2026-03-01T00:00:00.000Z");
+    MockResponse::json(200, member.clone())
+}
+
+fn respond_join_request(
+    state: &mut MockState,
+    group_id: &str,
+    user_id: &str,
+    request: &RecordedRequest,
+) -> MockResponse {
+    let body: Value = serde_json::from_str(&request.body).unwrap_or(Value::Null);
+    let Some(member) = find_member(state, group_id, user_id) else {
+        return MockResponse::error(404, "Join request not found");
+    };
+    if member["membershipStatus"] != "requested" {
+        return MockResponse::error(400, "User has not requested to join");
+    }
+
+    match body["action"].as_str() {
+        Some("accept") => member["membershipStatus"] = json!("member"),
+        Some("reject") => {
+            remove_member(state, group_id, user_id);
+        }
+        _ => return MockResponse::error(400, "Invalid action"),
+    }
+    ok_message()
+}
+
+/// `startDate` is inclusive, matching the API
+fn group_audit_logs(state: &MockState, group_id: &str, request: &RecordedRequest) -> MockResponse {
+    let start = request.query.get("startDate");
+    let entries: Vec<Value> = in_group(&state.group_audit_logs, group_id)
+        .into_iter()
+        .filter(|entry| {
+            start.is_none_or(|start| {
+                entry["created_at"].as_str().unwrap_or_default() >= start.as_str()
+            })
+        })
+        .collect();
+    let results = page(&entries, request);
+    let offset = request
+        .query
+        .get("offset")
+        .and_then(|value| value.parse::<usize>().ok())
+        .unwrap_or(0);
+
+    MockResponse::json(
+        200,
+        json!({
+            "results": results,
+            "totalCount": entries.len(),
+            "hasNext": offset + results.len() < entries.len(),
+        }),
+    )
+}
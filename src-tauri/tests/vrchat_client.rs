@@ -0,0 +1,1315 @@
+mod common;
+
+use common::*;
+use sea_orm::Database;
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+use vrc_one_lib::friend_alerts::FriendAlerts;
+use vrc_one_lib::rate_limiter::RateLimit;
+use vrc_one_lib::store::{
+    AlertAction, AlertRule, AlertRuleStore, AlertTrigger, FavoritesStore, GroupAuditQuery,
+    GroupAuditStore,
+};
+use vrc_one_lib::vrchat_api::{
+    AddFavoriteRequest, CreateInstanceRequest, FavoriteType, FriendRequestStatus,
+    GroupJoinRequestAction, GroupJoinState, GroupMemberStatus, InstanceType, LimitedWorld,
+    LoginCredentials, LoginResult, OrderOption, PlayerModerationType, RetryPolicy, SortOption,
+    TwoFactorMethod, UpdateFavoriteGroupRequest, UpdateUserRequest, VRCError, VRCResult,
+    VRChatClient, WorldListSource, WorldSearchParams,
+};
+
+fn credentials() -> LoginCredentials {
+    LoginCredentials {
+        email: MOCK_EMAIL.to_string(),
+        password: MOCK_PASSWORD.to_string(),
+    }
+}
+
+async fn logged_in_client(server: &MockVRChatServer) -> VRChatClient {
+    let client = VRChatClient::new(server.config()).expect("client");
+    match client.login(&credentials()).await.expect("login") {
+        LoginResult::Success { .. } => client,
+        other => panic!("unexpected login result: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn login_without_two_factor_returns_user() {
+    let server = MockVRChatServer::start().await;
+    let client = VRChatClient::new(server.config()).unwrap();
+
+    let result = client.login(&credentials()).await.unwrap();
+
+    match result {
+        LoginResult::Success { user } => assert_eq!(user.id, MOCK_USER_ID),
+        other => panic!("expected success, got {:?}", other),
+    }
+    assert!(client.has_valid_session().await);
+
+    let requests = server.requests_to("GET", "/auth/user");
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].headers["authorization"].starts_with("Basic "));
+    assert_eq!(
+        requests[0].headers["user-agent"],
+        client.config().user_agent
+    );
+}
+
+#[tokio::test]
+async fn login_with_wrong_password_fails() {
+    let server = MockVRChatServer::start().await;
+    let client = VRChatClient::new(server.config()).unwrap();
+
+    let result = client
+        .login(&LoginCredentials {
+            email: MOCK_EMAIL.to_string(),
+            password: "wrong".to_string(),
+        })
+        .await;
+
+    assert!(matches!(result, Err(VRCError::Authentication(_))));
+    assert!(!client.has_valid_session().await);
+}
+
+#[tokio::test]
+async fn login_with_two_factor_then_verify() {
+    let server = MockVRChatServer::start().await;
+    server.with_state(|state| state.require_two_factor = true);
+    let client = VRChatClient::new(server.config()).unwrap();
+
+    match client.login(&credentials()).await.unwrap() {
+        LoginResult::TwoFactorRequired { methods } => {
+            assert!(methods.contains(&"totp".to_string()))
+        }
+        other => panic!("expected 2FA prompt, got {:?}", other),
+    }
+
+    assert!(client.get_current_user().await.is_err());
+
+    let verified = client
+        .verify_two_factor("000000", TwoFactorMethod::Totp)
+        .await
+        .unwrap();
+    assert!(!verified);
+
+    let verified = client
+        .verify_two_factor(MOCK_TOTP_CODE, TwoFactorMethod::Totp)
+        .await
+        .unwrap();
+    assert!(verified);
+
+    let user = client.get_current_user().await.unwrap();
+    assert_eq!(user.id, MOCK_USER_ID);
+
+    let (auth, two_factor) = client.export_cookies().await;
+    assert_eq!(auth.as_deref(), Some(AUTH_COOKIE));
+    assert_eq!(two_factor.as_deref(), Some(TWO_FACTOR_COOKIE));
+    assert_eq!(
+        server
+            .requests_to("POST", "/auth/twofactorauth/totp/verify")
+            .len(),
+        2
+    );
+}
+
+#[tokio::test]
+async fn imported_cookies_restore_session() {
+    let server = MockVRChatServer::start().await;
+    let client = VRChatClient::new(server.config()).unwrap();
+
+    client
+        .import_cookies(Some(AUTH_COOKIE.to_string()), None)
+        .await;
+
+    let user = client.get_current_user().await.unwrap();
+    assert_eq!(user.id, MOCK_USER_ID);
+}
+
+#[tokio::test]
+async fn friends_are_paged_and_deduplicated() {
+    let server = MockVRChatServer::start().await;
+    server.with_state(|state| {
+        state.online_friends = (0..150).map(|i| mock_friend(i, true)).collect();
+        // The last online friend also shows up in the offline list while transitioning
+        state.offline_friends = (149..170).map(|i| mock_friend(i, false)).collect();
+    });
+    let client = logged_in_client(&server).await;
+
+    let friends = client.get_all_friends().await.unwrap();
+
+    assert_eq!(friends.len(), 170);
+    let offsets: Vec<(String, String)> = server
+        .requests_to("GET", "/auth/user/friends")
+        .into_iter()
+        .map(|req| (req.query["offline"].clone(), req.query["offset"].clone()))
+        .collect();
+    assert_eq!(
+        offsets,
+        vec![
+            ("false".to_string(), "0".to_string()),
+            ("false".to_string(), "100".to_string()),
+            ("true".to_string(), "0".to_string()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn uploaded_worlds_are_backfilled_with_details() {
+    let server = MockVRChatServer::start().await;
+    server.with_state(|state| {
+        for i in 0..3 {
+            let id = format!("wrld_mock_{}", i);
+            let name = format!("World {}", i);
+            state.worlds.push(mock_world_summary(&id, &name));
+            state
+                .world_details
+                .insert(id.clone(), mock_world_details(&id, &name, 1000 * (i + 1)));
+        }
+    });
+    let client = logged_in_client(&server).await;
+
+    let worlds = client.get_uploaded_worlds().await.unwrap();
+
+    assert_eq!(worlds.len(), 3);
+    for (i, world) in worlds.iter().enumerate() {
+        assert_eq!(world.visits, Some(1000 * (i as i32 + 1)));
+        assert_eq!(world.capacity, Some(32));
+        assert_eq!(world.recommended_capacity, Some(16));
+        assert_eq!(world.organization.as_deref(), Some("vrchat"));
+    }
+    assert_eq!(server.requests_to("GET", "/worlds").len(), 1);
+    assert_eq!(server.requests_to("GET", "/worlds/wrld_mock_1").len(), 1);
+}
+
+#[tokio::test]
+async fn world_backfill_skips_known_worlds_and_streams_the_rest() {
+    let server = MockVRChatServer::start().await;
+    server.with_state(|state| {
+        for i in 0..3 {
+            let id = format!("wrld_mock_{}", i);
+            let name = format!("World {}", i);
+            state.worlds.push(mock_world_summary(&id, &name));
+            state
+                .world_details
+                .insert(id.clone(), mock_world_details(&id, &name, 1000 * (i + 1)));
+        }
+    });
+    let client = logged_in_client(&server).await;
+
+    // The caller already filled the first world, e.g. from its cache
+    let mut worlds = client.list_uploaded_worlds().await.unwrap();
+    let cached: LimitedWorld =
+        serde_json::from_value(mock_world_details("wrld_mock_0", "World 0", 42)).unwrap();
+    worlds[0].merge_stats(&cached);
+    let known = HashSet::from(["wrld_mock_0".to_string()]);
+
+    let mut events = Vec::new();
+    let fetched = client
+        .backfill_world_stats(&mut worlds, &known, |progress| events.push(progress))
+        .await;
+
+    assert_eq!(worlds[0].visits, Some(42));
+    assert_eq!(worlds[1].visits, Some(2000));
+    assert_eq!(worlds[2].visits, Some(3000));
+    assert!(server.requests_to("GET", "/worlds/wrld_mock_0").is_empty());
+
+    // Fetched details are handed back for the caller to cache
+    let mut fetched: Vec<_> = fetched.into_iter().map(|world| world.id).collect();
+    fetched.sort();
+    assert_eq!(fetched, vec!["wrld_mock_1", "wrld_mock_2"]);
+
+    // The whole list first, known stats included, then one event per fetched world
+    assert_eq!(events.len(), 3);
+    assert_eq!((events[0].completed, events[0].total), (0, 2));
+    assert_eq!(events[0].worlds.len(), 3);
+    assert_eq!(events[0].worlds[0].visits, Some(42));
+    for (completed, event) in events.iter().enumerate().skip(1) {
+        assert_eq!((event.completed, event.total), (completed as u32, 2));
+        assert_eq!(event.worlds.len(), 1);
+        assert!(event.worlds[0].visits.is_some());
+    }
+}
+
+#[tokio::test]
+async fn server_errors_are_retried_per_config() {
+    let server = MockVRChatServer::start().await;
+    let client = logged_in_client(&server).await;
+
+    server.push_override("GET", "/auth/user", MockResponse::error(503, "Down"));
+    server.push_override("GET", "/auth/user", MockResponse::error(503, "Down"));
+
+    let user = client.get_current_user().await.unwrap();
+    assert_eq!(user.id, MOCK_USER_ID);
+    // login + two failures + success
+    assert_eq!(server.requests_to("GET", "/auth/user").len(), 4);
+
+    let mut config = server.config();
+    config.retry = RetryPolicy::none();
+    let impatient = VRChatClient::new(config).unwrap();
+    impatient
+        .import_cookies(Some(AUTH_COOKIE.to_string()), None)
+        .await;
+
+    server.push_override("GET", "/auth/user", MockResponse::error(503, "Down"));
+    let result = impatient.get_current_user().await;
+    assert!(matches!(result, Err(VRCError::Http { status: 503, .. })));
+}
+
+#[tokio::test]
+async fn client_rejects_rate_limits_that_would_stall() {
+    let server = MockVRChatServer::start().await;
+
+    for limit in [RateLimit::new(0, 1.0), RateLimit::new(1, 0.0)] {
+        let mut config = server.config();
+        config.rate_limits.worlds = limit;
+        let result = VRChatClient::new(config);
+        assert!(matches!(result, Err(VRCError::InvalidInput(_))));
+    }
+}
+
+#[tokio::test]
+async fn api_errors_are_decoded_into_typed_variants() {
+    let server = MockVRChatServer::start().await;
+
+    let anonymous = VRChatClient::new(server.config()).unwrap();
+    let result = anonymous.get_uploaded_worlds().await;
+    assert!(matches!(result, Err(VRCError::MissingCredentials(_))));
+
+    let client = logged_in_client(&server).await;
+    match client.get_world_details("wrld_missing").await {
+        Err(VRCError::NotFound(message)) => assert_eq!(message, "World not found"),
+        other => panic!("expected NotFound, got {:?}", other),
+    }
+
+    server.push_override(
+        "GET",
+        "/users/usr_blocked",
+        MockResponse::error(403, "You can't see this user"),
+    );
+    let result = client.get_user_by_id("usr_blocked").await;
+    assert!(matches!(result, Err(VRCError::Forbidden(_))));
+}
+
+#[tokio::test]
+async fn rejected_session_cookie_is_reported_as_expired() {
+    let server = MockVRChatServer::start().await;
+    let client = VRChatClient::new(server.config()).unwrap();
+    client
+        .import_cookies(Some("auth=authcookie_stale".to_string()), None)
+        .await;
+
+    match client.get_current_user().await {
+        Err(VRCError::SessionExpired(message)) => assert_eq!(message, "Missing Credentials"),
+        other => panic!("expected SessionExpired, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn session_expiry_is_broadcast_once_per_session() {
+    let server = MockVRChatServer::start().await;
+    let client = logged_in_client(&server).await;
+    let mut notices = client.subscribe_session_expired();
+
+    // VRChat invalidates the session server-side
+    for _ in 0..2 {
+        server.push_override(
+            "GET",
+            "/worlds",
+            MockResponse::error(401, "Missing Credentials"),
+        );
+    }
+
+    let (first, second) = tokio::join!(client.get_uploaded_worlds(), client.get_uploaded_worlds());
+    assert!(matches!(first, Err(VRCError::SessionExpired(_))));
+    assert!(matches!(second, Err(VRCError::SessionExpired(_))));
+
+    let notice = notices.try_recv().expect("session expiry notice");
+    assert_eq!(notice.message, "Missing Credentials");
+    assert!(notices.try_recv().is_err());
+
+    // Signing in again re-arms detection for the new session
+    client.login(&credentials()).await.unwrap();
+    assert!(client.get_uploaded_worlds().await.is_ok());
+
+    server.push_override(
+        "GET",
+        "/worlds",
+        MockResponse::error(401, "Missing Credentials"),
+    );
+    assert!(client.get_uploaded_worlds().await.is_err());
+    assert!(notices.try_recv().is_ok());
+}
+
+#[tokio::test]
+async fn slow_world_backfill_does_not_block_other_calls() {
+    let delay = Duration::from_millis(100);
+
+    let server = MockVRChatServer::start().await;
+    server.with_state(|state| {
+        for i in 0..24 {
+            let id = format!("wrld_mock_{}", i);
+            let name = format!("World {}", i);
+            state.worlds.push(mock_world_summary(&id, &name));
+            state
+                .world_details
+                .insert(id.clone(), mock_world_details(&id, &name, 1000));
+        }
+    });
+    let client = logged_in_client(&server).await;
+    server.with_state(|state| state.response_delay = delay);
+
+    // One list request plus 24 detail requests, four at a time
+    let backfill = tokio::spawn({
+        let client = client.clone();
+        async move { client.get_uploaded_worlds().await }
+    });
+    tokio::time::sleep(delay / 2).await;
+
+    let started = Instant::now();
+    let (auth, _) = client.export_cookies().await;
+    assert!(auth.is_some());
+    let user = client.get_current_user().await.unwrap();
+    assert_eq!(user.id, MOCK_USER_ID);
+    let login = client.login(&credentials()).await.unwrap();
+    assert!(matches!(login, LoginResult::Success { .. }));
+    let elapsed = started.elapsed();
+
+    assert!(
+        elapsed < delay * 4,
+        "calls waited {:?} behind the world backfill",
+        elapsed
+    );
+    assert!(!backfill.is_finished());
+    assert_eq!(backfill.await.unwrap().unwrap().len(), 24);
+}
+
+#[tokio::test]
+async fn world_backfill_runs_detail_requests_concurrently() {
+    let delay = Duration::from_millis(100);
+
+    let server = MockVRChatServer::start().await;
+    server.with_state(|state| {
+        for i in 0..8 {
+            let id = format!("wrld_mock_{}", i);
+            let name = format!("World {}", i);
+            state.worlds.push(mock_world_summary(&id, &name));
+            state
+                .world_details
+                .insert(id.clone(), mock_world_details(&id, &name, 1000 + i));
+        }
+    });
+    let client = logged_in_client(&server).await;
+    server.with_state(|state| state.response_delay = delay);
+
+    let started = Instant::now();
+    let worlds = client.get_uploaded_worlds().await.unwrap();
+    let elapsed = started.elapsed();
+
+    // Sequential backfill would take nine round trips
+    assert!(
+        elapsed < delay * 6,
+        "backfill took {:?}, detail requests ran sequentially",
+        elapsed
+    );
+    assert_eq!(worlds.len(), 8);
+    assert_eq!(worlds[3].id, "wrld_mock_3");
+    assert_eq!(worlds[3].visits, Some(1003));
+    assert_eq!(worlds[3].capacity, Some(32));
+}
+
+#[tokio::test]
+async fn retry_after_pauses_every_caller() {
+    let server = MockVRChatServer::start().await;
+    let client = logged_in_client(&server).await;
+
+    server.push_override(
+        "GET",
+        "/worlds/wrld_mock_0",
+        MockResponse::error(429, "Slow down").with_header("Retry-After", "1"),
+    );
+    server.with_state(|state| {
+        state.world_details.insert(
+            "wrld_mock_0".to_string(),
+            mock_world_details("wrld_mock_0", "World 0", 10),
+        )
+    });
+
+    let started = Instant::now();
+    let world = tokio::spawn({
+        let client = client.clone();
+        async move { client.get_world_details("wrld_mock_0").await }
+    });
+
+    // Wait for the 429 to land, then call an unrelated endpoint class
+    while client.rate_limiter().paused_for().is_none() {
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    client.get_uploaded_avatars().await.unwrap();
+    assert!(started.elapsed() >= Duration::from_millis(900));
+
+    assert_eq!(world.await.unwrap().unwrap().visits, Some(10));
+    assert_eq!(server.requests_to("GET", "/worlds/wrld_mock_0").len(), 2);
+}
+
+#[tokio::test]
+async fn get_instance_returns_player_counts() {
+    let server = MockVRChatServer::start().await;
+    let client = logged_in_client(&server).await;
+
+    let location = "wrld_mock:12345~hidden(usr_owner)~region(jp)";
+    server.with_state(|state| {
+        state.instances.insert(
+            location.to_string(),
+            mock_instance("wrld_mock", "12345~hidden(usr_owner)~region(jp)", 7),
+        )
+    });
+
+    let instance = client.get_instance(location).await.expect("instance");
+    assert_eq!(instance.world_id, "wrld_mock");
+    assert_eq!(instance.user_count, 7);
+    assert_eq!(instance.n_users, 7);
+    assert_eq!(instance.platforms.standalonewindows, 6);
+    assert!(instance.queue_enabled);
+
+    let short_name = client.get_instance_short_name(location).await.unwrap();
+    assert_eq!(short_name.short_name.as_deref(), Some("abcd1234"));
+
+    let err = client.get_instance("offline").await.unwrap_err();
+    assert!(matches!(err, VRCError::InvalidInput(_)), "got {:?}", err);
+}
+
+#[tokio::test]
+async fn instance_lookup_keeps_tag_order() {
+    let server = MockVRChatServer::start().await;
+    let client = logged_in_client(&server).await;
+
+    let location = "wrld_mock:12345~region(jp)~nonce(n)~hidden(usr_owner)";
+    server.with_state(|state| {
+        state.instances.insert(
+            location.to_string(),
+            mock_instance(
+                "wrld_mock",
+                "12345~region(jp)~nonce(n)~hidden(usr_owner)",
+                3,
+            ),
+        )
+    });
+
+    let instance = client.get_instance(location).await.expect("instance");
+    assert_eq!(instance.user_count, 3);
+    client.get_instance_short_name(location).await.unwrap();
+
+    assert_eq!(
+        server
+            .requests_to("GET", &format!("/instances/{}", location))
+            .len(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn create_instance_posts_request_body() {
+    let server = MockVRChatServer::start().await;
+    let client = logged_in_client(&server).await;
+
+    let request = CreateInstanceRequest {
+        world_id: "wrld_mock".to_string(),
+        instance_type: InstanceType::Friends,
+        region: "eu".to_string(),
+        owner_id: Some(MOCK_USER_ID.to_string()),
+        role_ids: Vec::new(),
+        group_access_type: None,
+        queue_enabled: false,
+        can_request_invite: false,
+        age_gate: false,
+        display_name: None,
+    };
+    let instance = client.create_instance(&request).await.expect("created");
+    assert_eq!(instance.instance_type, InstanceType::Friends);
+    assert_eq!(instance.region, "eu");
+    assert!(instance.instance_id.ends_with("~region(eu)"));
+
+    let sent = server.requests_to("POST", "/instances");
+    let body: serde_json::Value = serde_json::from_str(&sent[0].body).unwrap();
+    assert_eq!(body["type"], "friends");
+    assert_eq!(body["ownerId"], MOCK_USER_ID);
+    assert!(body.get("roleIds").is_none());
+}
+
+#[tokio::test]
+async fn invites_send_location_and_message_slots() {
+    let server = MockVRChatServer::start().await;
+    let client = logged_in_client(&server).await;
+    // Tags out of VRChat's usual order must still be sent verbatim
+    let location = "wrld_mock:12345~region(eu)~friends(usr_owner)";
+
+    let sent = client
+        .invite_user("usr_friend", location, Some(3))
+        .await
+        .expect("invite");
+    assert_eq!(sent.kind, "invite");
+    assert_eq!(sent.receiver_user_id.as_deref(), Some("usr_friend"));
+    let body: serde_json::Value =
+        serde_json::from_str(&server.requests_to("POST", "/invite/usr_friend")[0].body).unwrap();
+    assert_eq!(body["instanceId"], location);
+    assert_eq!(body["messageSlot"], 3);
+
+    client.request_invite("usr_friend", None).await.unwrap();
+    let body = &server.requests_to("POST", "/requestInvite/usr_friend")[0].body;
+    assert_eq!(body, "{}");
+
+    client.invite_myself(location).await.unwrap();
+    assert_eq!(
+        server
+            .requests_to("POST", &format!("/invite/myself/to/{}", location))
+            .len(),
+        1
+    );
+
+    client.respond_to_invite("not_request", 2).await.unwrap();
+    let body: serde_json::Value =
+        serde_json::from_str(&server.requests_to("POST", "/invite/not_request/response")[0].body)
+            .unwrap();
+    assert_eq!(body["responseSlot"], 2);
+
+    let err = client
+        .respond_to_invite("not_request", 12)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, VRCError::InvalidInput(_)), "got {:?}", err);
+}
+
+#[tokio::test]
+async fn notifications_are_listed_and_answered() {
+    let server = MockVRChatServer::start().await;
+    let client = logged_in_client(&server).await;
+
+    server.with_state(|state| {
+        state.notifications = (0..150)
+            .map(|i| mock_notification(&format!("not_{}", i), "friendRequest"))
+            .collect();
+        state.notifications_v2 = vec![mock_notification_v2("not_v2")];
+    });
+
+    let notifications = client.get_notifications(false).await.expect("v1 list");
+    assert_eq!(notifications.len(), 150);
+    assert_eq!(notifications[0].kind.as_deref(), Some("friendRequest"));
+    let requests = server.requests_to("GET", "/auth/user/notifications");
+    assert_eq!(requests.len(), 2);
+    assert_eq!(
+        requests[1].query.get("offset").map(String::as_str),
+        Some("100")
+    );
+
+    let v2 = client.get_notifications_v2().await.expect("v2 list");
+    assert_eq!(v2[0].responses[0].kind.as_deref(), Some("unsubscribe"));
+
+    client.accept_friend_request("not_0").await.unwrap();
+    assert_eq!(
+        server
+            .requests_to("PUT", "/auth/user/notifications/not_0/accept")
+            .len(),
+        1
+    );
+
+    client
+        .respond_notification_v2("not_v2", &v2[0].responses[0])
+        .await
+        .unwrap();
+    let body: serde_json::Value =
+        serde_json::from_str(&server.requests_to("POST", "/notifications/not_v2/respond")[0].body)
+            .unwrap();
+    assert_eq!(body["responseType"], "unsubscribe");
+    assert_eq!(body["responseData"], "grp_mock");
+
+    client.delete_notification_v2("not_v2").await.unwrap();
+    client.clear_notifications().await.unwrap();
+    assert_eq!(
+        server
+            .requests_to("PUT", "/auth/user/notifications/clear")
+            .len(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn friend_requests_are_sent_checked_and_cancelled() {
+    let server = MockVRChatServer::start().await;
+    let client = logged_in_client(&server).await;
+
+    let sent = client
+        .send_friend_request("usr_stranger")
+        .await
+        .expect("send friend request");
+    assert_eq!(sent.kind, "friendRequest");
+    assert_eq!(sent.receiver_user_id.as_deref(), Some("usr_stranger"));
+
+    let status = client.get_friend_status("usr_stranger").await.unwrap();
+    assert!(status.outgoing_request);
+    assert_eq!(status.request_status(), FriendRequestStatus::Outgoing);
+
+    client.cancel_friend_request("usr_stranger").await.unwrap();
+    let status = client.get_friend_status("usr_stranger").await.unwrap();
+    assert_eq!(status.request_status(), FriendRequestStatus::None);
+
+    client.unfriend("usr_friend").await.unwrap();
+    assert_eq!(
+        server
+            .requests_to("DELETE", "/auth/user/friends/usr_friend")
+            .len(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn player_moderations_are_listed_created_and_removed() {
+    let server = MockVRChatServer::start().await;
+    let client = logged_in_client(&server).await;
+
+    server.with_state(|state| {
+        state.player_moderations = vec![mock_player_moderation("pmod_a", "usr_muted", "mute")];
+    });
+
+    let blocked = client
+        .moderate_user("usr_pest", PlayerModerationType::Block)
+        .await
+        .expect("block");
+    assert_eq!(blocked.kind, PlayerModerationType::Block);
+    assert_eq!(blocked.target_user_id, "usr_pest");
+
+    let moderations = client.get_player_moderations().await.expect("list");
+    assert_eq!(moderations.len(), 2);
+    assert_eq!(moderations[0].kind, PlayerModerationType::Mute);
+
+    client
+        .unmoderate_user("usr_pest", PlayerModerationType::Block)
+        .await
+        .unwrap();
+    let body: serde_json::Value =
+        serde_json::from_str(&server.requests_to("PUT", "/auth/user/unplayermoderate")[0].body)
+            .unwrap();
+    assert_eq!(body["moderated"], "usr_pest");
+    assert_eq!(body["type"], "block");
+    assert_eq!(client.get_player_moderations().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn favorites_and_groups_are_managed() {
+    let server = MockVRChatServer::start().await;
+    let client = logged_in_client(&server).await;
+
+    server.with_state(|state| {
+        state.favorites = vec![mock_favorite("fvrt_a", "friend", "usr_friend", "group_0")];
+        state.favorite_groups = vec![
+            mock_favorite_group("world", "worlds1", "Worlds"),
+            mock_favorite_group("friend", "group_0", "Besties"),
+        ];
+    });
+
+    let added = client
+        .add_favorite(&AddFavoriteRequest {
+            kind: FavoriteType::World,
+            favorite_id: "wrld_mock".to_string(),
+            tags: vec!["worlds1".to_string()],
+        })
+        .await
+        .expect("add favorite");
+    assert_eq!(added.kind, FavoriteType::World);
+    assert_eq!(added.tags, ["worlds1"]);
+
+    let worlds = client
+        .get_favorites(Some(FavoriteType::World))
+        .await
+        .unwrap();
+    assert_eq!(worlds.len(), 1);
+    let requests = server.requests_to("GET", "/favorites");
+    assert_eq!(
+        requests[0].query.get("type").map(String::as_str),
+        Some("world")
+    );
+
+    let groups = client.get_favorite_groups().await.unwrap();
+    assert_eq!(groups[1].display_name, "Besties");
+
+    client
+        .update_favorite_group(
+            FavoriteType::World,
+            "worlds1",
+            MOCK_USER_ID,
+            &UpdateFavoriteGroupRequest {
+                display_name: Some("Chill".to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    let path = format!("/favorite/group/world/worlds1/{}", MOCK_USER_ID);
+    let body: serde_json::Value =
+        serde_json::from_str(&server.requests_to("PUT", &path)[0].body).unwrap();
+    assert_eq!(body, serde_json::json!({ "displayName": "Chill" }));
+
+    client.remove_favorite(&added.id).await.unwrap();
+    assert_eq!(client.get_favorites(None).await.unwrap().len(), 1);
+
+    let limits = client.get_favorite_limits().await.unwrap();
+    assert_eq!(limits.max_favorite_groups.avatar, 6);
+}
+
+#[tokio::test]
+async fn world_search_sends_filters() {
+    let server = MockVRChatServer::start().await;
+    let client = logged_in_client(&server).await;
+
+    server.with_state(|state| {
+        state.worlds = (0..3)
+            .map(|i| mock_world_summary(&format!("wrld_{}", i), &format!("World {}", i)))
+            .collect();
+    });
+
+    let worlds = client
+        .search_worlds(&WorldSearchParams {
+            search: Some("  club ".to_string()),
+            tags: vec![
+                "system_approved".to_string(),
+                "author_tag_dance".to_string(),
+            ],
+            featured: Some(false),
+            sort: Some(SortOption::Popularity),
+            order: Some(OrderOption::Ascending),
+            n: Some(500),
+            ..Default::default()
+        })
+        .await
+        .expect("search worlds");
+    assert_eq!(worlds.len(), 3);
+
+    let query = &server.requests_to("GET", "/worlds")[0].query;
+    assert_eq!(query.get("search").map(String::as_str), Some("club"));
+    assert_eq!(
+        query.get("tag").map(String::as_str),
+        Some("system_approved,author_tag_dance")
+    );
+    assert_eq!(query.get("featured").map(String::as_str), Some("false"));
+    assert_eq!(query.get("sort").map(String::as_str), Some("popularity"));
+    assert_eq!(query.get("order").map(String::as_str), Some("ascending"));
+    assert_eq!(query.get("n").map(String::as_str), Some("100"));
+
+    client
+        .search_worlds(&WorldSearchParams {
+            source: WorldListSource::Recent,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(server.requests_to("GET", "/worlds/recent").len(), 1);
+}
+
+#[tokio::test]
+async fn avatar_selection_returns_updated_current_user() {
+    let server = MockVRChatServer::start().await;
+    server.with_state(|state| {
+        state.avatars.push(mock_avatar("avtr_main", "Main"));
+        state.avatars.push(mock_avatar("avtr_quest", "Quest"));
+        state
+            .favorite_avatars
+            .push(mock_avatar("avtr_fav", "Favorite"));
+    });
+    let client = logged_in_client(&server).await;
+
+    let avatar = client.get_avatar("avtr_main").await.unwrap();
+    assert_eq!(avatar.name, "Main");
+    let missing = client.get_avatar("avtr_missing").await.unwrap_err();
+    assert!(matches!(missing, VRCError::NotFound(_)));
+
+    let user = client.select_avatar("avtr_main").await.unwrap();
+    assert_eq!(user.current_avatar.as_deref(), Some("avtr_main"));
+    assert_eq!(
+        user.current_avatar_thumbnail_image_url.as_deref(),
+        Some("https://example.com/avtr_main_thumb.png")
+    );
+
+    let user = client.select_fallback_avatar("avtr_quest").await.unwrap();
+    assert_eq!(user.fallback_avatar.as_deref(), Some("avtr_quest"));
+    assert_eq!(user.current_avatar.as_deref(), Some("avtr_main"));
+
+    let favorites = client
+        .get_favorited_avatars(Some("avatars1"))
+        .await
+        .unwrap();
+    assert_eq!(favorites.len(), 1);
+    let requests = server.requests_to("GET", "/avatars/favorites");
+    assert_eq!(
+        requests[0].query.get("tag").map(String::as_str),
+        Some("avatars1")
+    );
+}
+
+#[tokio::test]
+async fn groups_members_roles_and_posts_are_fetched() {
+    let server = MockVRChatServer::start().await;
+    server.with_state(|state| {
+        state.groups.push(mock_group("grp_mock", "Mock Group"));
+        state
+            .group_roles
+            .push(mock_group_role("grp_mock", "grol_mod", "Moderator"));
+        state
+            .group_members
+            .push(mock_group_member("grp_mock", MOCK_USER_ID, &["grol_mod"]));
+        for index in 0..3 {
+            let user_id = format!("usr_member_{}", index);
+            state
+                .group_members
+                .push(mock_group_member("grp_mock", &user_id, &[]));
+        }
+        state.group_posts.push(serde_json::json!({
+            "id": "gpst_1",
+            "groupId": "grp_mock",
+            "title": "Welcome",
+            "text": "Hello",
+            "visibility": "group",
+            "roleId": ["grol_mod"],
+        }));
+    });
+    let client = logged_in_client(&server).await;
+
+    let mine = client.get_user_groups(MOCK_USER_ID).await.unwrap();
+    assert_eq!(mine.len(), 1);
+    assert_eq!(mine[0].group_id, "grp_mock");
+
+    let group = client.get_group("grp_mock").await.unwrap();
+    assert_eq!(group.join_state, GroupJoinState::Request);
+    assert_eq!(group.roles.unwrap()[0].name, "Moderator");
+    let request = &server.requests_to("GET", "/groups/grp_mock")[0];
+    assert_eq!(
+        request.query.get("includeRoles").map(String::as_str),
+        Some("true")
+    );
+
+    let page = client.get_group_members("grp_mock", 2, 2).await.unwrap();
+    assert_eq!(page.len(), 2);
+    assert_eq!(page[0].user_id, "usr_member_1");
+    assert_eq!(page[0].membership_status, GroupMemberStatus::Member);
+
+    let roles = client.get_group_roles("grp_mock").await.unwrap();
+    assert_eq!(roles[0].permissions, vec!["group-members-viewall"]);
+
+    assert!(
+        client
+            .get_group_announcement("grp_mock")
+            .await
+            .unwrap()
+            .is_none()
+    );
+
+    let posts = client.get_group_posts("grp_mock", 10, 0).await.unwrap();
+    assert_eq!(posts[0].role_ids, vec!["grol_mod"]);
+    assert!(
+        client
+            .get_group_instances("grp_mock")
+            .await
+            .unwrap()
+            .is_empty()
+    );
+
+    let missing = client.get_group("grp_missing").await.unwrap_err();
+    assert!(matches!(missing, VRCError::NotFound(_)));
+}
+
+#[tokio::test]
+async fn group_moderation_actions_update_membership() {
+    let server = MockVRChatServer::start().await;
+    server.with_state(|state| {
+        state.groups.push(mock_group("grp_mock", "Mock Group"));
+        state
+            .group_members
+            .push(mock_group_member("grp_mock", "usr_troll", &[]));
+        state
+            .group_members
+            .push(mock_group_member("grp_mock", "usr_helper", &["grol_a"]));
+        for user_id in ["usr_applicant", "usr_spammer"] {
+            let mut member = mock_group_member("grp_mock", user_id, &[]);
+            member["membershipStatus"] = "requested".into();
+            state.group_members.push(member);
+        }
+    });
+    let client = logged_in_client(&server).await;
+
+    let banned = client
+        .ban_group_member("grp_mock", "usr_troll")
+        .await
+        .unwrap();
+    assert_eq!(banned.membership_status, GroupMemberStatus::Banned);
+    let bans = client.get_group_bans("grp_mock", 10, 0).await.unwrap();
+    assert_eq!(bans.len(), 1);
+    client
+        .unban_group_member("grp_mock", "usr_troll")
+        .await
+        .unwrap();
+    assert!(
+        client
+            .get_group_bans("grp_mock", 10, 0)
+            .await
+            .unwrap()
+            .is_empty()
+    );
+
+    let requests = client
+        .get_group_join_requests("grp_mock", 10, 0)
+        .await
+        .unwrap();
+    assert_eq!(requests.len(), 2);
+    client
+        .respond_group_join_request(
+            "grp_mock",
+            "usr_applicant",
+            GroupJoinRequestAction::Accept,
+            false,
+        )
+        .await
+        .unwrap();
+    client
+        .respond_group_join_request(
+            "grp_mock",
+            "usr_spammer",
+            GroupJoinRequestAction::Reject,
+            true,
+        )
+        .await
+        .unwrap();
+    let body: serde_json::Value = serde_json::from_str(
+        &server.requests_to("PUT", "/groups/grp_mock/requests/usr_spammer")[0].body,
+    )
+    .unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({ "action": "reject", "block": true })
+    );
+    let invalid = client
+        .respond_group_join_request(
+            "grp_mock",
+            "usr_other",
+            GroupJoinRequestAction::Accept,
+            true,
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(invalid, VRCError::InvalidInput(_)));
+    assert!(
+        client
+            .get_group_join_requests("grp_mock", 10, 0)
+            .await
+            .unwrap()
+            .is_empty()
+    );
+
+    let roles = client
+        .add_group_member_role("grp_mock", "usr_helper", "grol_b")
+        .await
+        .unwrap();
+    assert_eq!(roles, vec!["grol_a", "grol_b"]);
+    let roles = client
+        .remove_group_member_role("grp_mock", "usr_helper", "grol_a")
+        .await
+        .unwrap();
+    assert_eq!(roles, vec!["grol_b"]);
+
+    client
+        .kick_group_member("grp_mock", "usr_helper")
+        .await
+        .unwrap();
+    let missing = client
+        .kick_group_member("grp_mock", "usr_helper")
+        .await
+        .unwrap_err();
+    assert!(matches!(missing, VRCError::NotFound(_)));
+}
+
+#[tokio::test]
+async fn group_audit_log_is_paged_from_start_date() {
+    let server = MockVRChatServer::start().await;
+    server.with_state(|state| {
+        for minute in (0..5).rev() {
+            let id = format!("gaud_{}", minute);
+            state.group_audit_logs.push(mock_group_audit_entry(
+                "grp_mock",
+                &id,
+                "group.user.ban",
+                minute,
+            ));
+        }
+    });
+    let client = logged_in_client(&server).await;
+
+    let page = client
+        .get_group_audit_logs("grp_mock", 2, 0, None)
+        .await
+        .unwrap();
+    assert_eq!(page.total_count, 5);
+    assert!(page.has_next);
+    assert_eq!(page.results[0].id, "gaud_4");
+    assert_eq!(
+        page.results[0].actor_display_name.as_deref(),
+        Some("Mock Tester")
+    );
+
+    let since = client
+        .get_group_audit_logs("grp_mock", 10, 0, Some("2026-03-01T00:03:00.000Z"))
+        .await
+        .unwrap();
+    let ids: Vec<_> = since.results.iter().map(|e| e.id.as_str()).collect();
+    assert_eq!(ids, vec!["gaud_4", "gaud_3"]);
+    assert!(!since.has_next);
+    let request = &server.requests_to("GET", "/groups/grp_mock/auditLogs")[1];
+    assert_eq!(
+        request.query.get("startDate").map(String::as_str),
+        Some("2026-03-01T00:03:00.000Z")
+    );
+}
+
+/// What the `sync_group_audit_log` command does: fetch everything since the newest
+/// stored entry, then store it in one go
+async fn sync_audit_log(client: &VRChatClient, store: &GroupAuditStore) -> VRCResult<u64> {
+    let since = store.latest_timestamp("grp_mock").await.unwrap();
+    let entries = client
+        .get_all_group_audit_logs("grp_mock", since.as_deref())
+        .await?;
+    Ok(store.insert(entries).await.unwrap())
+}
+
+#[tokio::test]
+async fn failed_group_audit_sync_is_filled_in_by_the_retry() {
+    let server = MockVRChatServer::start().await;
+    let client = logged_in_client(&server).await;
+    let db = Database::connect("sqlite::memory:").await.unwrap();
+    let store = GroupAuditStore::with_connection(db).await.unwrap();
+    let path = "/groups/grp_mock/auditLogs";
+
+    let push_entries = |minutes: std::ops::Range<u32>| {
+        server.with_state(|state| {
+            for minute in minutes {
+                let id = format!("gaud_{}", minute);
+                let entry = mock_group_audit_entry("grp_mock", &id, "group.user.ban", minute);
+                state.group_audit_logs.insert(0, entry);
+            }
+        })
+    };
+
+    push_entries(0..2);
+    assert_eq!(sync_audit_log(&client, &store).await.unwrap(), 2);
+
+    // 250 new entries span three pages; the second one fails even after retries
+    push_entries(2..252);
+    for _ in 0..3 {
+        server.push_query_override(
+            "GET",
+            path,
+            ("offset", "100"),
+            MockResponse::error(503, "Down"),
+        );
+    }
+    let result = sync_audit_log(&client, &store).await;
+    assert!(matches!(result, Err(VRCError::Http { status: 503, .. })));
+    assert_eq!(
+        store.latest_timestamp("grp_mock").await.unwrap().as_deref(),
+        Some("2026-03-01T00:01:00.000Z")
+    );
+
+    // Nothing was stored, so the retry starts from the same point and fills every page
+    assert_eq!(sync_audit_log(&client, &store).await.unwrap(), 250);
+    let all = store
+        .query(GroupAuditQuery {
+            group_id: "grp_mock".to_string(),
+            limit: Some(500),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(all.total, 252);
+    let last = server.requests_to("GET", path).pop().unwrap();
+    assert_eq!(
+        last.query.get("startDate").map(String::as_str),
+        Some("2026-03-01T00:01:00.000Z")
+    );
+}
+
+#[tokio::test]
+async fn friend_alerts_load_favorites_for_group_scoped_rules() {
+    let server = MockVRChatServer::start().await;
+    let client = logged_in_client(&server).await;
+    server.with_state(|state| {
+        state.favorites = vec![mock_favorite("fvrt_a", "friend", "usr_friend", "group_0")];
+        state.favorite_groups = vec![mock_favorite_group("friend", "group_0", "Besties")];
+    });
+
+    let db = Database::connect("sqlite::memory:").await.unwrap();
+    let rule_store = AlertRuleStore::with_connection(db).await.unwrap();
+    let favorites_store = FavoritesStore::new();
+    let alerts = FriendAlerts::new(client, rule_store, favorites_store.clone());
+    let mut rule = AlertRule {
+        id: 1,
+        name: "Besties online".to_string(),
+        enabled: true,
+        trigger: AlertTrigger::FriendOnline,
+        user_id: None,
+        favorite_group: None,
+        actions: vec![AlertAction::Event],
+        created_at: "2026-03-01T00:00:00.000Z".to_string(),
+    };
+
+    // Nothing needs favorites yet, so nothing is fetched
+    let groups = alerts
+        .favorite_groups(std::slice::from_ref(&rule), "usr_friend")
+        .await;
+    assert!(groups.is_empty());
+    assert!(server.requests_to("GET", "/favorites").is_empty());
+
+    // No favorites command ran this session, the store is filled on demand
+    rule.favorite_group = Some("group_0".to_string());
+    assert!(!favorites_store.is_loaded().await);
+    let groups = alerts
+        .favorite_groups(std::slice::from_ref(&rule), "usr_friend")
+        .await;
+    assert_eq!(groups, vec!["group_0".to_string()]);
+    assert!(favorites_store.is_loaded().await);
+
+    alerts
+        .favorite_groups(std::slice::from_ref(&rule), "usr_other")
+        .await;
+    assert_eq!(server.requests_to("GET", "/favorites").len(), 1);
+}
+
+#[tokio::test]
+async fn users_are_searched_by_display_name() {
+    let server = MockVRChatServer::start().await;
+    server.with_state(|state| {
+        for (id, name) in [("usr_a", "Alice"), ("usr_b", "Alina"), ("usr_c", "Bob")] {
+            state.users.insert(id.to_string(), mock_user(id, name));
+        }
+    });
+    let client = logged_in_client(&server).await;
+
+    let first = client.search_users("ali", 1, 0).await.unwrap();
+    let second = client.search_users("ali", 1, 1).await.unwrap();
+    assert_eq!(first[0].display_name, "Alice");
+    assert_eq!(second[0].display_name, "Alina");
+
+    let request = &server.requests_to("GET", "/users")[0];
+    assert_eq!(request.query.get("search").map(String::as_str), Some("ali"));
+
+    let empty = client.search_users("  ", 10, 0).await.unwrap_err();
+    assert!(matches!(empty, VRCError::InvalidInput(_)));
+}
+
+#[tokio::test]
+async fn user_profile_aggregates_sections_and_tolerates_failures() {
+    let server = MockVRChatServer::start().await;
+    server.with_state(|state| {
+        state.users.insert(
+            "usr_creator".to_string(),
+            mock_user("usr_creator", "Creator"),
+        );
+        state.groups.push(mock_group("grp_a", "Group A"));
+        state.groups.push(mock_group("grp_b", "Group B"));
+        state
+            .group_members
+            .push(mock_group_member("grp_a", "usr_creator", &[]));
+        let mut representing = mock_group_member("grp_b", "usr_creator", &[]);
+        representing["isRepresenting"] = true.into();
+        state.group_members.push(representing);
+
+        let mut world = mock_world_summary("wrld_public", "Public World");
+        world["authorId"] = "usr_creator".into();
+        state.worlds.push(world);
+        state
+            .worlds
+            .push(mock_world_summary("wrld_mine", "Not Theirs"));
+        let mut avatar = mock_avatar("avtr_public", "Public Avatar");
+        avatar["authorId"] = "usr_creator".into();
+        avatar["releaseStatus"] = "public".into();
+        state.avatars.push(avatar);
+        let mut private = mock_avatar("avtr_private", "Private Avatar");
+        private["authorId"] = "usr_creator".into();
+        state.avatars.push(private);
+    });
+    server.push_override(
+        "GET",
+        "/users/usr_creator/mutuals/friends",
+        MockResponse::error(403, "Mutuals are hidden"),
+    );
+    let client = logged_in_client(&server).await;
+
+    let profile = client.get_user_profile("usr_creator").await.unwrap();
+
+    assert_eq!(profile.user.display_name, "Creator");
+    assert_eq!(profile.groups.len(), 2);
+    assert_eq!(
+        profile
+            .represented_group
+            .and_then(|g| g.group_id)
+            .as_deref(),
+        Some("grp_b")
+    );
+    let worlds: Vec<_> = profile.worlds.iter().map(|w| w.id.as_str()).collect();
+    assert_eq!(worlds, vec!["wrld_public"]);
+    let avatars: Vec<_> = profile.avatars.iter().map(|a| a.id.as_str()).collect();
+    assert_eq!(avatars, vec!["avtr_public"]);
+    assert!(profile.mutual_friends.is_empty());
+    assert_eq!(profile.unavailable, vec!["mutualFriends"]);
+
+    let missing = client.get_user_profile("usr_missing").await.unwrap_err();
+    assert!(matches!(missing, VRCError::NotFound(_)));
+}
+
+#[tokio::test]
+async fn profile_update_merges_language_tags_and_validates_limits() {
+    let server = MockVRChatServer::start().await;
+    server.with_state(|state| {
+        state.current_user["tags"] = serde_json::json!(["system_trust_basic", "language_deu"]);
+    });
+    let client = logged_in_client(&server).await;
+    let current_user = client.get_current_user().await.unwrap();
+
+    let request = UpdateUserRequest {
+        bio: Some("Hello there".to_string()),
+        bio_links: Some(vec!["https://example.com".to_string()]),
+        pronouns: Some("they/them".to_string()),
+        tags: Some(vec!["language_eng".to_string(), "language_jpn".to_string()]),
+        ..Default::default()
+    };
+    let user = client
+        .update_current_user(&current_user, &request)
+        .await
+        .unwrap();
+
+    assert_eq!(user.bio, "Hello there");
+    assert_eq!(user.pronouns.as_deref(), Some("they/them"));
+    assert_eq!(
+        user.tags,
+        vec!["system_trust_basic", "language_eng", "language_jpn"]
+    );
+    let path = format!("/users/{}", MOCK_USER_ID);
+    let body: serde_json::Value =
+        serde_json::from_str(&server.requests_to("PUT", &path)[0].body).unwrap();
+    assert!(body.get("status").is_none());
+
+    let too_long = UpdateUserRequest {
+        status_description: Some("x".repeat(33)),
+        ..Default::default()
+    };
+    let error = client
+        .update_current_user(&current_user, &too_long)
+        .await
+        .unwrap_err();
+    assert!(matches!(error, VRCError::InvalidInput(_)));
+
+    let not_a_language = UpdateUserRequest {
+        tags: Some(vec!["system_trust_veteran".to_string()]),
+        ..Default::default()
+    };
+    let error = client
+        .update_current_user(&current_user, &not_a_language)
+        .await
+        .unwrap_err();
+    assert!(matches!(error, VRCError::InvalidInput(_)));
+    assert_eq!(server.requests_to("PUT", &path).len(), 1);
+}
@@ -11,17 +11,23 @@ import { AccountService } from "../services/account";
 import { WebSocketService } from "../services/websocket";
 import { userStore, accountsStore } from "@/stores";
 import { setActiveAccountId } from "@/stores/account-scope";
-import type { User } from "../types/bindings";
+import { VRChatError } from "../types/errors";
+import type { SessionExpiredEvent, User } from "../types/bindings";
 
 interface AuthContextType {
   user: User | null;
   loading: boolean;
   twoFactorMethods: string[];
+  sessionExpired: SessionExpiredEvent | null;
   login: (
     email: string,
     password: string
   ) => Promise<"success" | "needs_2fa" | "error">;
   verify2FA: (code: string, method: string) => Promise<boolean>;
+  reauthenticate: (
+    email: string,
+    password: string
+  ) => Promise<"success" | "needs_2fa" | "error">;
   logout: () => Promise<void>;
   clearLocalSession: () => Promise<void>;
   setUser: (user: User | null) => void;
@@ -34,6 +40,8 @@ export function AuthProvider({ children }: { children: ReactNode }) {
   const [user, setUserState] = useState<User | null>(userStore.getSnapshot());
   const [loading, setLoading] = useState(true);
   const [twoFactorMethods, setTwoFactorMethods] = useState<string[]>([]);
+  const [sessionExpired, setSessionExpired] =
+    useState<SessionExpiredEvent | null>(null);
 
   const checkAuth = useCallback(async () => {
     setLoading(true);
@@ -76,7 +84,11 @@ export function AuthProvider({ children }: { children: ReactNode }) {
         userStore.clearFriends();
         setActiveAccountId(null);
       }
-    } catch {
+    } catch (error) {
+      if (error instanceof VRChatError && error.isSessionExpired()) {
+        setSessionExpired({ userId: null, message: error.message });
+        accountsStore.refresh().catch(() => undefined);
+      }
       userStore.clear();
       userStore.clearFriends();
       setActiveAccountId(null);
@@ -132,6 +144,7 @@ export function AuthProvider({ children }: { children: ReactNode }) {
 
         await addListener("user-update", refreshCurrentUser);
         await addListener("user-location", refreshCurrentUser);
+        await addListener("status-rule-applied", refreshCurrentUser);
 
         // TODO: Strongly type these events
         const friendEvents = [
@@ -147,6 +160,19 @@ export function AuthProvider({ children }: { children: ReactNode }) {
         await Promise.all(
           friendEvents.map((event) => addListener(event, refreshFriends))
         );
+
+        // Backend already stopped the WebSocket and flagged the account,
+        // cached user data stays in place until the user signs in again
+        const unlistenSessionExpired = await listen<SessionExpiredEvent>(
+          "session-expired",
+          (event) => {
+            if (!isDisposed) {
+              setSessionExpired(event.payload);
+              accountsStore.refresh().catch(() => undefined);
+            }
+          }
+        );
+        unlistenFns.push(unlistenSessionExpired);
       } catch (error) {
         console.error("Failed to set up Tauri event listeners", error);
       }
@@ -187,6 +213,7 @@ export function AuthProvider({ children }: { children: ReactNode }) {
       }
 
       setTwoFactorMethods([]);
+      setSessionExpired(null);
       await accountsStore.saveFromUser(currentUser).catch((error) => {
         console.error("Failed to save account after login", error);
       });
@@ -202,9 +229,43 @@ export function AuthProvider({ children }: { children: ReactNode }) {
     return "error";
   };
 
+  const finishReauthentication = async (resumedUser: User) => {
+    setActiveAccountId(resumedUser.id);
+    userStore.set(resumedUser, { scopeId: resumedUser.id });
+    setTwoFactorMethods([]);
+    setSessionExpired(null);
+    await accountsStore.refresh().catch(() => undefined);
+  };
+
+  const reauthenticate = async (
+    email: string,
+    password: string
+  ): Promise<"success" | "needs_2fa" | "error"> => {
+    const response = await VRChatService.reauthenticate(email, password);
+
+    if (response.type === "Success") {
+      await finishReauthentication(response.user);
+      return "success";
+    } else if (response.type === "TwoFactorRequired") {
+      setTwoFactorMethods(response.methods);
+      return "needs_2fa";
+    }
+    return "error";
+  };
+
   const verify2FA = async (code: string, method: string): Promise<boolean> => {
     const verified = await VRChatService.verify2FA(code, method);
 
+    if (verified && sessionExpired) {
+      try {
+        await finishReauthentication(await VRChatService.resumeSession());
+        return true;
+      } catch (error) {
+        console.error("Failed to resume session after 2FA", error);
+        return false;
+      }
+    }
+
     if (verified) {
       let currentUser: User | null = null;
       try {
@@ -231,6 +292,7 @@ export function AuthProvider({ children }: { children: ReactNode }) {
       }
 
       setTwoFactorMethods([]);
+      setSessionExpired(null);
       await accountsStore.saveFromUser(currentUser).catch((error) => {
         console.error("Failed to save account after 2FA", error);
       });
@@ -300,8 +362,10 @@ export function AuthProvider({ children }: { children: ReactNode }) {
         user,
         loading,
         twoFactorMethods,
+        sessionExpired,
         login,
         verify2FA,
+        reauthenticate,
         logout,
         clearLocalSession,
         setUser,
@@ -0,0 +1,77 @@
+import { invoke } from '@tauri-apps/api/core';
+import type {
+  AddFavoriteRequest,
+  Favorite,
+  FavoriteAnnotation,
+  FavoriteGroup,
+  FavoriteLimits,
+  FavoriteType,
+  UpdateFavoriteGroupRequest,
+} from '../types/bindings';
+import { parseVRCError } from '../types/errors';
+
+export class FavoritesService {
+  static async getFavorites(kind: FavoriteType | null = null): Promise<Favorite[]> {
+    try {
+      return await invoke<Favorite[]>('vrchat_get_favorites', { kind });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async getGroups(kind: FavoriteType | null = null): Promise<FavoriteGroup[]> {
+    try {
+      return await invoke<FavoriteGroup[]>('vrchat_get_favorite_groups', { kind });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async getLimits(): Promise<FavoriteLimits> {
+    try {
+      return await invoke<FavoriteLimits>('vrchat_get_favorite_limits');
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async add(request: AddFavoriteRequest): Promise<Favorite> {
+    try {
+      return await invoke<Favorite>('vrchat_add_favorite', { request });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async remove(favoriteId: string): Promise<void> {
+    try {
+      return await invoke<void>('vrchat_remove_favorite', { favoriteId });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async updateGroup(kind: FavoriteType, name: string, request: UpdateFavoriteGroupRequest): Promise<void> {
+    try {
+      return await invoke<void>('vrchat_update_favorite_group', { kind, name, request });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async clearGroup(kind: FavoriteType, name: string): Promise<void> {
+    try {
+      return await invoke<void>('vrchat_clear_favorite_group', { kind, name });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async getAnnotations(kind: FavoriteType, targetIds: string[]): Promise<FavoriteAnnotation[]> {
+    try {
+      return await invoke<FavoriteAnnotation[]>('get_favorite_annotations', { kind, targetIds });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+}
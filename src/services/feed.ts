@@ -0,0 +1,21 @@
+import { invoke } from '@tauri-apps/api/core';
+import type { FeedPage, FeedQuery } from '../types/bindings';
+import { parseVRCError } from '../types/errors';
+
+export class FeedService {
+  static async getFriendFeed(query: Partial<FeedQuery> = {}): Promise<FeedPage> {
+    try {
+      return await invoke<FeedPage>('get_friend_feed', { query });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async clearFriendFeed(): Promise<void> {
+    try {
+      return await invoke<void>('clear_friend_feed');
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+}
@@ -0,0 +1,29 @@
+import { invoke } from '@tauri-apps/api/core';
+import type { AlertRule, AlertRuleInput } from '../types/bindings';
+import { parseVRCError } from '../types/errors';
+
+export class FriendAlertsService {
+  static async getFriendAlerts(): Promise<AlertRule[]> {
+    try {
+      return await invoke<AlertRule[]>('get_friend_alerts');
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async saveFriendAlert(rule: AlertRuleInput): Promise<AlertRule> {
+    try {
+      return await invoke<AlertRule>('save_friend_alert', { rule });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async deleteFriendAlert(id: number): Promise<boolean> {
+    try {
+      return await invoke<boolean>('delete_friend_alert', { id });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+}
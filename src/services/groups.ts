@@ -0,0 +1,173 @@
+import { invoke } from '@tauri-apps/api/core';
+import type {
+  Group,
+  GroupAnnouncement,
+  GroupAuditPage,
+  GroupAuditQuery,
+  GroupInstance,
+  GroupJoinRequestAction,
+  GroupMember,
+  GroupPost,
+  GroupRole,
+  UserGroup,
+} from '../types/bindings';
+import { parseVRCError } from '../types/errors';
+
+export class GroupsService {
+  static async getMyGroups(): Promise<UserGroup[]> {
+    try {
+      return await invoke<UserGroup[]>('vrchat_get_my_groups');
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async getUserGroups(userId: string): Promise<UserGroup[]> {
+    try {
+      return await invoke<UserGroup[]>('vrchat_get_user_groups', { userId });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async getGroup(groupId: string): Promise<Group> {
+    try {
+      return await invoke<Group>('vrchat_get_group', { groupId });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async getMembers(groupId: string, n = 50, offset = 0): Promise<GroupMember[]> {
+    try {
+      return await invoke<GroupMember[]>('vrchat_get_group_members', { groupId, n, offset });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async getRoles(groupId: string): Promise<GroupRole[]> {
+    try {
+      return await invoke<GroupRole[]>('vrchat_get_group_roles', { groupId });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async getAnnouncement(groupId: string): Promise<GroupAnnouncement | null> {
+    try {
+      return await invoke<GroupAnnouncement | null>('vrchat_get_group_announcement', { groupId });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async getPosts(groupId: string, n = 20, offset = 0): Promise<GroupPost[]> {
+    try {
+      return await invoke<GroupPost[]>('vrchat_get_group_posts', { groupId, n, offset });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async getInstances(groupId: string): Promise<GroupInstance[]> {
+    try {
+      return await invoke<GroupInstance[]>('vrchat_get_group_instances', { groupId });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async ban(groupId: string, userId: string): Promise<GroupMember> {
+    try {
+      return await invoke<GroupMember>('vrchat_ban_group_member', { groupId, userId });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async unban(groupId: string, userId: string): Promise<void> {
+    try {
+      await invoke('vrchat_unban_group_member', { groupId, userId });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async getBans(groupId: string, n = 50, offset = 0): Promise<GroupMember[]> {
+    try {
+      return await invoke<GroupMember[]>('vrchat_get_group_bans', { groupId, n, offset });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async kick(groupId: string, userId: string): Promise<void> {
+    try {
+      await invoke('vrchat_kick_group_member', { groupId, userId });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async getJoinRequests(groupId: string, n = 50, offset = 0): Promise<GroupMember[]> {
+    try {
+      return await invoke<GroupMember[]>('vrchat_get_group_join_requests', { groupId, n, offset });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async respondToJoinRequest(
+    groupId: string,
+    userId: string,
+    action: GroupJoinRequestAction,
+    block = false,
+  ): Promise<void> {
+    try {
+      await invoke('vrchat_respond_group_join_request', { groupId, userId, action, block });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async addMemberRole(groupId: string, userId: string, roleId: string): Promise<string[]> {
+    try {
+      return await invoke<string[]>('vrchat_add_group_member_role', { groupId, userId, roleId });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async removeMemberRole(groupId: string, userId: string, roleId: string): Promise<string[]> {
+    try {
+      return await invoke<string[]>('vrchat_remove_group_member_role', { groupId, userId, roleId });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async syncAuditLog(groupId: string): Promise<number> {
+    try {
+      return await invoke<number>('sync_group_audit_log', { groupId });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async searchAuditLog(query: GroupAuditQuery): Promise<GroupAuditPage> {
+    try {
+      return await invoke<GroupAuditPage>('search_group_audit_log', { query });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async clearAuditLog(groupId: string): Promise<void> {
+    try {
+      await invoke('clear_group_audit_log', { groupId });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+}
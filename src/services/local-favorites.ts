@@ -0,0 +1,109 @@
+import { invoke } from '@tauri-apps/api/core';
+import type {
+  Favorite,
+  LocalCollection,
+  LocalFavoriteEntry,
+  LocalFavoriteInput,
+  LocalFavoriteKind,
+  LocalFavoritesFormat,
+  LocalFavoritesImport,
+} from '../types/bindings';
+import { parseVRCError } from '../types/errors';
+
+export class LocalFavoritesService {
+  static async getCollections(kind: LocalFavoriteKind | null = null): Promise<LocalCollection[]> {
+    try {
+      return await invoke<LocalCollection[]>('get_local_collections', { kind });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async createCollection(
+    name: string,
+    kind: LocalFavoriteKind,
+    description: string | null = null
+  ): Promise<LocalCollection> {
+    try {
+      return await invoke<LocalCollection>('create_local_collection', { name, kind, description });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async updateCollection(
+    collectionId: number,
+    name: string,
+    description: string | null = null
+  ): Promise<LocalCollection> {
+    try {
+      return await invoke<LocalCollection>('update_local_collection', { collectionId, name, description });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async deleteCollection(collectionId: number): Promise<void> {
+    try {
+      return await invoke<void>('delete_local_collection', { collectionId });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async getEntries(collectionId: number, tag: string | null = null): Promise<LocalFavoriteEntry[]> {
+    try {
+      return await invoke<LocalFavoriteEntry[]>('get_local_favorites', { collectionId, tag });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async addEntry(collectionId: number, input: LocalFavoriteInput): Promise<LocalFavoriteEntry> {
+    try {
+      return await invoke<LocalFavoriteEntry>('add_local_favorite', { collectionId, input });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async updateEntry(entryId: number, notes: string | null, tags: string[]): Promise<LocalFavoriteEntry> {
+    try {
+      return await invoke<LocalFavoriteEntry>('update_local_favorite', { entryId, notes, tags });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async removeEntry(entryId: number): Promise<void> {
+    try {
+      return await invoke<void>('remove_local_favorite', { entryId });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async export(format: LocalFavoritesFormat, collectionIds: number[] = []): Promise<string> {
+    try {
+      return await invoke<string>('export_local_favorites', { format, collectionIds });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async import(format: LocalFavoritesFormat, data: string): Promise<LocalFavoritesImport> {
+    try {
+      return await invoke<LocalFavoritesImport>('import_local_favorites', { format, data });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async promote(entryId: number, groupName: string): Promise<Favorite> {
+    try {
+      return await invoke<Favorite>('promote_local_favorite', { entryId, groupName });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+}
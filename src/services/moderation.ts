@@ -0,0 +1,37 @@
+import { invoke } from '@tauri-apps/api/core';
+import type { PlayerModeration, PlayerModerationType } from '../types/bindings';
+import { parseVRCError } from '../types/errors';
+
+export class ModerationService {
+  static async list(kind: PlayerModerationType | null = null): Promise<PlayerModeration[]> {
+    try {
+      return await invoke<PlayerModeration[]>('vrchat_get_player_moderations', { kind });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async moderate(userId: string, kind: PlayerModerationType): Promise<PlayerModeration> {
+    try {
+      return await invoke<PlayerModeration>('vrchat_moderate_user', { userId, kind });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async unmoderate(userId: string, kind: PlayerModerationType): Promise<void> {
+    try {
+      return await invoke<void>('vrchat_unmoderate_user', { userId, kind });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async exportBlockList(): Promise<string> {
+    try {
+      return await invoke<string>('export_block_list');
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+}
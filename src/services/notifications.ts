@@ -0,0 +1,69 @@
+import { invoke } from '@tauri-apps/api/core';
+import type { InboxNotification, InboxResponse } from '../types/bindings';
+import { parseVRCError } from '../types/errors';
+
+export class NotificationService {
+  static async getInbox(includeHidden = false): Promise<InboxNotification[]> {
+    try {
+      return await invoke<InboxNotification[]>('get_notification_inbox', { includeHidden });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async refresh(): Promise<InboxNotification[]> {
+    try {
+      return await invoke<InboxNotification[]>('refresh_notifications');
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async see(notificationId: string): Promise<void> {
+    try {
+      return await invoke<void>('see_notification', { notificationId });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async hide(notificationId: string): Promise<void> {
+    try {
+      return await invoke<void>('hide_notification', { notificationId });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async delete(notificationId: string): Promise<void> {
+    try {
+      return await invoke<void>('delete_notification', { notificationId });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async clear(): Promise<void> {
+    try {
+      return await invoke<void>('clear_notifications');
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async acceptFriendRequest(notificationId: string): Promise<void> {
+    try {
+      return await invoke<void>('accept_friend_request', { notificationId });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async respond(notificationId: string, response: InboxResponse): Promise<void> {
+    try {
+      return await invoke<void>('respond_notification', { notificationId, response });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+}
@@ -1,5 +1,12 @@
 import { invoke } from '@tauri-apps/api/core';
-import type { AppSettings } from '../types/bindings';
+import type {
+  AppSettings,
+  StatusHistoryEntry,
+  StatusPreset,
+  StatusRule,
+  StatusRuleInput,
+  User,
+} from '../types/bindings';
 import { parseVRCError } from '../types/errors';
 
 export class SettingsService {
@@ -34,4 +41,76 @@ export class SettingsService {
       throw parseVRCError(error);
     }
   }
+
+  static async getStatusPresets(): Promise<StatusPreset[]> {
+    try {
+      return await invoke<StatusPreset[]>('get_status_presets');
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async saveStatusPreset(preset: StatusPreset): Promise<void> {
+    try {
+      return await invoke<void>('save_status_preset', { preset });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async deleteStatusPreset(name: string): Promise<boolean> {
+    try {
+      return await invoke<boolean>('delete_status_preset', { name });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async applyStatusPreset(name: string): Promise<User> {
+    try {
+      return await invoke<User>('apply_status_preset', { name });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async getStatusHistory(limit: number | null = null): Promise<StatusHistoryEntry[]> {
+    try {
+      return await invoke<StatusHistoryEntry[]>('get_status_history', { limit });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async clearStatusHistory(): Promise<void> {
+    try {
+      return await invoke<void>('clear_status_history');
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async getStatusRules(): Promise<StatusRule[]> {
+    try {
+      return await invoke<StatusRule[]>('get_status_rules');
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async saveStatusRule(rule: StatusRuleInput): Promise<StatusRule> {
+    try {
+      return await invoke<StatusRule>('save_status_rule', { rule });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async deleteStatusRule(id: number): Promise<boolean> {
+    try {
+      return await invoke<boolean>('delete_status_rule', { id });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
 }
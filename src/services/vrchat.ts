@@ -1,7 +1,11 @@
 import { invoke } from '@tauri-apps/api/core';
 import type { User, LoginResult, LimitedUserFriend, UserStatus } from '../types/bindings';
-import type { LimitedAvatar } from '../types/bindings';
-import type { LimitedWorld } from '../types/bindings';
+import type { UpdateUserRequest } from '../types/bindings';
+import type { AvatarPerformanceReport, LimitedAvatar } from '../types/bindings';
+import type { LimitedWorld, WorldSearchParams } from '../types/bindings';
+import type { CreateInstanceRequest, Instance, InstanceShortName } from '../types/bindings';
+import type { NotificationPayload, SentNotification } from '../types/bindings';
+import type { FriendStatus, LimitedUser, UserProfile } from '../types/bindings';
 import { parseVRCError } from '../types/errors';
 
 export class VRChatService {
@@ -21,6 +25,22 @@ export class VRChatService {
     }
   }
 
+  static async reauthenticate(email: string, password: string): Promise<LoginResult> {
+    try {
+      return await invoke<LoginResult>('vrchat_reauthenticate', { email, password });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async resumeSession(): Promise<User> {
+    try {
+      return await invoke<User>('vrchat_resume_session');
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
   static async getCurrentUser(): Promise<User> {
     try {
       return await invoke<User>('vrchat_get_current_user');
@@ -37,6 +57,14 @@ export class VRChatService {
     }
   }
 
+  static async updateCurrentUser(request: UpdateUserRequest): Promise<User> {
+    try {
+      return await invoke<User>('vrchat_update_current_user', { request });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
   static async logout(): Promise<void> {
     try {
       return await invoke<void>('vrchat_logout');
@@ -77,6 +105,22 @@ export class VRChatService {
     }
   }
 
+  static async searchWorlds(params: WorldSearchParams): Promise<LimitedWorld[]> {
+    try {
+      return await invoke<LimitedWorld[]>('vrchat_search_worlds', { params });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async getWorld(worldId: string, forceRefresh = false): Promise<LimitedWorld> {
+    try {
+      return await invoke<LimitedWorld>('get_world', { worldId, forceRefresh });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
   static async getUploadedAvatars(): Promise<LimitedAvatar[]> {
     try {
       return await invoke<LimitedAvatar[]>('vrchat_get_uploaded_avatars');
@@ -85,6 +129,154 @@ export class VRChatService {
     }
   }
 
+  static async getAvatar(avatarId: string): Promise<LimitedAvatar> {
+    try {
+      return await invoke<LimitedAvatar>('vrchat_get_avatar', { avatarId });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async getFavoritedAvatars(group: string | null = null): Promise<LimitedAvatar[]> {
+    try {
+      return await invoke<LimitedAvatar[]>('vrchat_get_favorited_avatars', { group });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async selectAvatar(avatarId: string): Promise<User> {
+    try {
+      return await invoke<User>('vrchat_select_avatar', { avatarId });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async selectFallbackAvatar(avatarId: string): Promise<User> {
+    try {
+      return await invoke<User>('vrchat_select_fallback_avatar', { avatarId });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async getAvatarPerformanceReport(
+    minUnityVersion: string | null = null
+  ): Promise<AvatarPerformanceReport> {
+    try {
+      return await invoke<AvatarPerformanceReport>('get_avatar_performance_report', {
+        minUnityVersion,
+      });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async exportAvatarPerformanceReport(minUnityVersion: string | null = null): Promise<string> {
+    try {
+      return await invoke<string>('export_avatar_performance_report', { minUnityVersion });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async getInstance(location: string): Promise<Instance> {
+    try {
+      return await invoke<Instance>('vrchat_get_instance', { location });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async getInstanceShortName(location: string): Promise<InstanceShortName> {
+    try {
+      return await invoke<InstanceShortName>('vrchat_get_instance_short_name', { location });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async createInstance(request: CreateInstanceRequest): Promise<Instance> {
+    try {
+      return await invoke<Instance>('vrchat_create_instance', { request });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async sendFriendRequest(userId: string): Promise<SentNotification> {
+    try {
+      return await invoke<SentNotification>('vrchat_send_friend_request', { userId });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async cancelFriendRequest(userId: string): Promise<void> {
+    try {
+      return await invoke<void>('vrchat_cancel_friend_request', { userId });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async getFriendStatus(userId: string): Promise<FriendStatus> {
+    try {
+      return await invoke<FriendStatus>('vrchat_get_friend_status', { userId });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async unfriend(userId: string): Promise<void> {
+    try {
+      return await invoke<void>('vrchat_unfriend', { userId });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async inviteUser(userId: string, location: string, messageSlot: number | null = null): Promise<SentNotification> {
+    try {
+      return await invoke<SentNotification>('vrchat_invite_user', { userId, location, messageSlot });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async requestInvite(userId: string, messageSlot: number | null = null): Promise<SentNotification> {
+    try {
+      return await invoke<SentNotification>('vrchat_request_invite', { userId, messageSlot });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async inviteMyself(location: string): Promise<SentNotification> {
+    try {
+      return await invoke<SentNotification>('vrchat_invite_myself', { location });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async respondInviteRequest(
+    notification: NotificationPayload,
+    location: string | null,
+    messageSlot: number | null = null,
+  ): Promise<SentNotification> {
+    try {
+      return await invoke<SentNotification>('vrchat_respond_invite_request', {
+        notification,
+        location,
+        messageSlot,
+      });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
   static async getUserById(userId: string): Promise<User> {
     try {
       return await invoke<User>('get_user_by_id', { userId });
@@ -92,4 +284,20 @@ export class VRChatService {
       throw parseVRCError(error);
     }
   }
+
+  static async searchUsers(query: string, n = 20, offset = 0): Promise<LimitedUser[]> {
+    try {
+      return await invoke<LimitedUser[]>('vrchat_search_users', { query, n, offset });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
+
+  static async getUserProfile(userId: string): Promise<UserProfile> {
+    try {
+      return await invoke<UserProfile>('vrchat_get_user_profile', { userId });
+    } catch (error) {
+      throw parseVRCError(error);
+    }
+  }
 }
@@ -1,14 +1,38 @@
+import { listen } from '@tauri-apps/api/event';
 import { ResourceStore } from './resource-store';
 import { VRChatService } from '@/services/vrchat';
-import type { LimitedWorld } from '@/types/bindings';
+import type { LimitedWorld, UploadedWorldsProgress } from '@/types/bindings';
 import { registerSingleton } from './singleton-registry';
 
 class WorldsStore extends ResourceStore<LimitedWorld[]> {
   constructor() {
     super({ staleTime: 5 * 60_000, storeName: 'worlds' });
   }
-  protected async load(_scopeId: string | null): Promise<LimitedWorld[]> {
-    return VRChatService.getUploadedWorlds();
+  protected async load(scopeId: string | null): Promise<LimitedWorld[]> {
+    // Render the list while statistics are still being backfilled
+    const unlisten = await listen<UploadedWorldsProgress>('uploaded-worlds-progress', (event) => {
+      const { worlds, completed } = event.payload;
+      if (completed === 0) {
+        this.set(worlds, { scopeId, stale: true });
+        return;
+      }
+      if (worlds.length === 0) {
+        return;
+      }
+
+      const updated = new Map(worlds.map((world) => [world.id, world]));
+      const current = this.getSnapshot(scopeId) ?? [];
+      this.set(
+        current.map((world) => updated.get(world.id) ?? world),
+        { scopeId, stale: true }
+      );
+    });
+
+    try {
+      return await VRChatService.getUploadedWorlds();
+    } finally {
+      unlisten();
+    }
   }
 }
 
@@ -40,6 +40,17 @@ async vrchatUpdateStatus(status: UserStatus, statusDescription: string) : Promis
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Edit bio, bio links, pronouns, languages, status or icon of the logged-in user
+ */
+async vrchatUpdateCurrentUser(request: UpdateUserRequest) : Promise<Result<User, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_update_current_user", { request }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async vrchatLogout() : Promise<Result<null, VRCError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("vrchat_logout") };
@@ -48,6 +59,28 @@ async vrchatLogout() : Promise<Result<null, VRCError>> {
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Sign in again after the session expired, keeping the cached UserStore state
+ */
+async vrchatReauthenticate(email: string, password: string) : Promise<Result<LoginResult, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_reauthenticate", { email, password }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Finish re-authentication once 2FA has been verified
+ */
+async vrchatResumeSession() : Promise<Result<User, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_resume_session") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async vrchatGetOnlineFriends() : Promise<Result<LimitedUserFriend[], VRCError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("vrchat_get_online_friends") };
@@ -64,6 +97,25 @@ async vrchatGetUploadedWorlds() : Promise<Result<LimitedWorld[], VRCError>> {
     else return { status: "error", error: e  as any };
 }
 },
+async vrchatSearchWorlds(params: WorldSearchParams) : Promise<Result<LimitedWorld[], VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_search_worlds", { params }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * World details, served from the world cache while fresh
+ */
+async getWorld(worldId: string, forceRefresh: boolean) : Promise<Result<LimitedWorld, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_world", { worldId, forceRefresh }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async vrchatGetUploadedAvatars() : Promise<Result<LimitedAvatar[], VRCError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("vrchat_get_uploaded_avatars") };
@@ -72,6 +124,118 @@ async vrchatGetUploadedAvatars() : Promise<Result<LimitedAvatar[], VRCError>> {
     else return { status: "error", error: e  as any };
 }
 },
+async vrchatGetAvatar(avatarId: string) : Promise<Result<LimitedAvatar, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_get_avatar", { avatarId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async vrchatGetFavoritedAvatars(group: string | null) : Promise<Result<LimitedAvatar[], VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_get_favorited_avatars", { group }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async vrchatSelectAvatar(avatarId: string) : Promise<Result<User, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_select_avatar", { avatarId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async vrchatSelectFallbackAvatar(avatarId: string) : Promise<Result<User, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_select_fallback_avatar", { avatarId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Summarise uploaded avatars per platform against `min_unity_version` (default `2022.3`)
+ */
+async getAvatarPerformanceReport(minUnityVersion: string | null) : Promise<Result<AvatarPerformanceReport, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_avatar_performance_report", { minUnityVersion }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async exportAvatarPerformanceReport(minUnityVersion: string | null) : Promise<Result<string, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("export_avatar_performance_report", { minUnityVersion }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async vrchatGetInstance(location: string) : Promise<Result<Instance, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_get_instance", { location }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async vrchatGetInstanceShortName(location: string) : Promise<Result<InstanceShortName, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_get_instance_short_name", { location }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async vrchatCreateInstance(request: CreateInstanceRequest) : Promise<Result<Instance, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_create_instance", { request }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async vrchatInviteUser(userId: string, location: string, messageSlot: number | null) : Promise<Result<SentNotification, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_invite_user", { userId, location, messageSlot }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async vrchatRequestInvite(userId: string, messageSlot: number | null) : Promise<Result<SentNotification, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_request_invite", { userId, messageSlot }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async vrchatInviteMyself(location: string) : Promise<Result<SentNotification, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_invite_myself", { location }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Reply to a `requestInvite` notification. With a `location` the sender is invited
+ * there (optionally with an invite message), otherwise the request is declined
+ * with the response message in `message_slot`.
+ */
+async vrchatRespondInviteRequest(notification: NotificationPayload, location: string | null, messageSlot: number | null) : Promise<Result<SentNotification, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_respond_invite_request", { notification, location, messageSlot }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async getOnlineFriends() : Promise<Result<LimitedUserFriend[], VRCError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("get_online_friends") };
@@ -104,6 +268,25 @@ async getUserById(userId: string) : Promise<Result<User, VRCError>> {
     else return { status: "error", error: e  as any };
 }
 },
+async vrchatSearchUsers(query: string, n: number, offset: number) : Promise<Result<LimitedUser[], VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_search_users", { query, n, offset }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Profile page data in one call; also refreshes the cached user
+ */
+async vrchatGetUserProfile(userId: string) : Promise<Result<UserProfile, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_get_user_profile", { userId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async isFriend(userId: string) : Promise<Result<boolean, VRCError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("is_friend", { userId }) };
@@ -120,298 +303,1321 @@ async isUserOnline(userId: string) : Promise<Result<boolean, VRCError>> {
     else return { status: "error", error: e  as any };
 }
 },
-async vrchatCheckSession() : Promise<Result<boolean, VRCError>> {
+async vrchatSendFriendRequest(userId: string) : Promise<Result<SentNotification, VRCError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("vrchat_check_session") };
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_send_friend_request", { userId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async vrchatClearSession() : Promise<Result<null, VRCError>> {
+async vrchatCancelFriendRequest(userId: string) : Promise<Result<null, VRCError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("vrchat_clear_session") };
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_cancel_friend_request", { userId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async websocketStart() : Promise<Result<null, VRCError>> {
+async vrchatGetFriendStatus(userId: string) : Promise<Result<FriendStatus, VRCError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("websocket_start") };
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_get_friend_status", { userId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async websocketStop() : Promise<Result<null, VRCError>> {
+async vrchatUnfriend(userId: string) : Promise<Result<null, VRCError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("websocket_stop") };
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_unfriend", { userId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async saveCurrentAccount(user: User) : Promise<Result<null, VRCError>> {
+async vrchatGetPlayerModerations(kind: PlayerModerationType | null) : Promise<Result<PlayerModeration[], VRCError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("save_current_account", { user }) };
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_get_player_moderations", { kind }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getAllAccounts() : Promise<Result<StoredAccount[], VRCError>> {
+async vrchatModerateUser(userId: string, kind: PlayerModerationType) : Promise<Result<PlayerModeration, VRCError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_all_accounts") };
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_moderate_user", { userId, kind }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async switchAccount(userId: string) : Promise<Result<User, VRCError>> {
+async vrchatUnmoderateUser(userId: string, kind: PlayerModerationType) : Promise<Result<null, VRCError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("switch_account", { userId }) };
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_unmoderate_user", { userId, kind }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async removeAccount(userId: string) : Promise<Result<null, VRCError>> {
+async exportBlockList() : Promise<Result<string, VRCError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("remove_account", { userId }) };
+    return { status: "ok", data: await TAURI_INVOKE("export_block_list") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async clearAllAccounts() : Promise<Result<null, VRCError>> {
+async vrchatGetMyGroups() : Promise<Result<UserGroup[], VRCError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("clear_all_accounts") };
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_get_my_groups") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async loadLastAccount() : Promise<Result<User | null, VRCError>> {
+async vrchatGetUserGroups(userId: string) : Promise<Result<UserGroup[], VRCError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("load_last_account") };
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_get_user_groups", { userId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getSettings() : Promise<Result<AppSettings, VRCError>> {
+async vrchatGetGroup(groupId: string) : Promise<Result<Group, VRCError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_settings") };
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_get_group", { groupId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async saveSettings(settings: AppSettings) : Promise<Result<null, VRCError>> {
+async vrchatGetGroupMembers(groupId: string, n: number, offset: number) : Promise<Result<GroupMember[], VRCError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("save_settings", { settings }) };
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_get_group_members", { groupId, n, offset }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getDeveloperMode() : Promise<Result<boolean, VRCError>> {
+async vrchatGetGroupRoles(groupId: string) : Promise<Result<GroupRole[], VRCError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_developer_mode") };
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_get_group_roles", { groupId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async setDeveloperMode(enabled: boolean) : Promise<Result<null, VRCError>> {
+async vrchatGetGroupAnnouncement(groupId: string) : Promise<Result<GroupAnnouncement | null, VRCError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("set_developer_mode", { enabled }) };
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_get_group_announcement", { groupId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getBackendLogs() : Promise<Result<LogEntry[], VRCError>> {
+async vrchatGetGroupPosts(groupId: string, n: number, offset: number) : Promise<Result<GroupPost[], VRCError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_backend_logs") };
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_get_group_posts", { groupId, n, offset }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async clearBackendLogs() : Promise<Result<null, VRCError>> {
+async vrchatGetGroupInstances(groupId: string) : Promise<Result<GroupInstance[], VRCError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("clear_backend_logs") };
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_get_group_instances", { groupId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async exportBackendLogs() : Promise<Result<string, VRCError>> {
+async vrchatBanGroupMember(groupId: string, userId: string) : Promise<Result<GroupMember, VRCError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("export_backend_logs") };
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_ban_group_member", { groupId, userId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getVrchatStatus() : Promise<Result<VRChatStatusResponse, string>> {
+async vrchatUnbanGroupMember(groupId: string, userId: string) : Promise<Result<null, VRCError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_vrchat_status") };
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_unban_group_member", { groupId, userId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async dbListTables() : Promise<Result<TableInfo[], VRCError>> {
+async vrchatGetGroupBans(groupId: string, n: number, offset: number) : Promise<Result<GroupMember[], VRCError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("db_list_tables") };
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_get_group_bans", { groupId, n, offset }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async dbGetTableSchema(tableName: string) : Promise<Result<ColumnInfo[], VRCError>> {
+async vrchatKickGroupMember(groupId: string, userId: string) : Promise<Result<null, VRCError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("db_get_table_schema", { tableName }) };
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_kick_group_member", { groupId, userId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async dbGetTableData(tableName: string, limit: number | null, offset: number | null) : Promise<Result<QueryResult, VRCError>> {
+async vrchatGetGroupJoinRequests(groupId: string, n: number, offset: number) : Promise<Result<GroupMember[], VRCError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("db_get_table_data", { tableName, limit, offset }) };
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_get_group_join_requests", { groupId, n, offset }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async dbGetTableCount(tableName: string) : Promise<Result<number, VRCError>> {
+async vrchatRespondGroupJoinRequest(groupId: string, userId: string, action: GroupJoinRequestAction, block: boolean) : Promise<Result<null, VRCError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("db_get_table_count", { tableName }) };
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_respond_group_join_request", { groupId, userId, action, block }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async dbExecuteQuery(query: string) : Promise<Result<QueryResult, VRCError>> {
+async vrchatAddGroupMemberRole(groupId: string, userId: string, roleId: string) : Promise<Result<string[], VRCError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("db_execute_query", { query }) };
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_add_group_member_role", { groupId, userId, roleId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async checkImageCached(url: string) : Promise<Result<string | null, VRCError>> {
+async vrchatRemoveGroupMemberRole(groupId: string, userId: string, roleId: string) : Promise<Result<string[], VRCError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("check_image_cached", { url }) };
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_remove_group_member_role", { groupId, userId, roleId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async cacheImage(url: string) : Promise<Result<string, VRCError>> {
+/**
+ * Pull audit log entries newer than the local mirror; returns how many were added
+ */
+async syncGroupAuditLog(groupId: string) : Promise<Result<number, VRCError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("cache_image", { url }) };
+    return { status: "ok", data: await TAURI_INVOKE("sync_group_audit_log", { groupId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getCacheDirectory() : Promise<Result<string, VRCError>> {
+async searchGroupAuditLog(query: GroupAuditQuery) : Promise<Result<GroupAuditPage, VRCError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_cache_directory") };
+    return { status: "ok", data: await TAURI_INVOKE("search_group_audit_log", { query }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async clearGroupAuditLog(groupId: string) : Promise<Result<null, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("clear_group_audit_log", { groupId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
+},
+async vrchatGetFavorites(kind: FavoriteType | null) : Promise<Result<Favorite[], VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_get_favorites", { kind }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
 }
+},
+async vrchatGetFavoriteGroups(kind: FavoriteType | null) : Promise<Result<FavoriteGroup[], VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_get_favorite_groups", { kind }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
 }
-
-/** user-defined events **/
-
-
-
-/** user-defined constants **/
-
-
-
-/** user-defined types **/
-
-/**
- * Age verification status
- * `verified` is obsolete. according to the unofficial docs, Users who have verified and are 18+ can switch to `plus18` status.
- */
-export type AgeVerificationStatus = 
-/**
- * Age verification status is hidden
- */
-"hidden" | 
+},
+async vrchatGetFavoriteLimits() : Promise<Result<FavoriteLimits, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_get_favorite_limits") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async vrchatAddFavorite(request: AddFavoriteRequest) : Promise<Result<Favorite, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_add_favorite", { request }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async vrchatRemoveFavorite(favoriteId: string) : Promise<Result<null, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_remove_favorite", { favoriteId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async vrchatUpdateFavoriteGroup(kind: FavoriteType, name: string, request: UpdateFavoriteGroupRequest) : Promise<Result<null, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_update_favorite_group", { kind, name, request }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async vrchatClearFavoriteGroup(kind: FavoriteType, name: string) : Promise<Result<null, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_clear_favorite_group", { kind, name }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
- * Legacy verified status (obsolete)
+ * Favorite groups of the given users, worlds or avatars; entries that are not favorited are left out
+ */
+async getFavoriteAnnotations(kind: FavoriteType, targetIds: string[]) : Promise<Result<FavoriteAnnotation[], VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_favorite_annotations", { kind, targetIds }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getLocalCollections(kind: LocalFavoriteKind | null) : Promise<Result<LocalCollection[], VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_local_collections", { kind }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async createLocalCollection(name: string, kind: LocalFavoriteKind, description: string | null) : Promise<Result<LocalCollection, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("create_local_collection", { name, kind, description }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async updateLocalCollection(collectionId: number, name: string, description: string | null) : Promise<Result<LocalCollection, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_local_collection", { collectionId, name, description }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async deleteLocalCollection(collectionId: number) : Promise<Result<null, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_local_collection", { collectionId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getLocalFavorites(collectionId: number, tag: string | null) : Promise<Result<LocalFavoriteEntry[], VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_local_favorites", { collectionId, tag }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async addLocalFavorite(collectionId: number, input: LocalFavoriteInput) : Promise<Result<LocalFavoriteEntry, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("add_local_favorite", { collectionId, input }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async updateLocalFavorite(entryId: number, notes: string | null, tags: string[]) : Promise<Result<LocalFavoriteEntry, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_local_favorite", { entryId, notes, tags }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async removeLocalFavorite(entryId: number) : Promise<Result<null, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("remove_local_favorite", { entryId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Export collections as JSON or CSV; an empty `collection_ids` exports everything
+ */
+async exportLocalFavorites(format: LocalFavoritesFormat, collectionIds: number[]) : Promise<Result<string, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("export_local_favorites", { format, collectionIds }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async importLocalFavorites(format: LocalFavoritesFormat, data: string) : Promise<Result<LocalFavoritesImport, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("import_local_favorites", { format, data }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Add a local entry to one of the user's VRChat favorite groups
+ */
+async promoteLocalFavorite(entryId: number, groupName: string) : Promise<Result<Favorite, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("promote_local_favorite", { entryId, groupName }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async vrchatCheckSession() : Promise<Result<boolean, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_check_session") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async vrchatClearSession() : Promise<Result<null, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("vrchat_clear_session") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async websocketStart() : Promise<Result<null, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("websocket_start") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async websocketStop() : Promise<Result<null, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("websocket_stop") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async saveCurrentAccount(user: User) : Promise<Result<null, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("save_current_account", { user }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getAllAccounts() : Promise<Result<StoredAccount[], VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_all_accounts") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async switchAccount(userId: string) : Promise<Result<User, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("switch_account", { userId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async removeAccount(userId: string) : Promise<Result<null, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("remove_account", { userId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async clearAllAccounts() : Promise<Result<null, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("clear_all_accounts") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async loadLastAccount() : Promise<Result<User | null, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("load_last_account") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getFriendFeed(query: FeedQuery) : Promise<Result<FeedPage, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_friend_feed", { query }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async clearFriendFeed() : Promise<Result<null, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("clear_friend_feed") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getNotificationInbox(includeHidden: boolean) : Promise<Result<InboxNotification[], VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_notification_inbox", { includeHidden }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Fetch both notification lists from VRChat and reconcile the local inbox
+ */
+async refreshNotifications() : Promise<Result<InboxNotification[], VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("refresh_notifications") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async seeNotification(notificationId: string) : Promise<Result<null, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("see_notification", { notificationId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Hide a notification. v2 notifications cannot be hidden on VRChat's side, so
+ * they are deleted there and only kept (hidden) locally.
+ */
+async hideNotification(notificationId: string) : Promise<Result<null, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("hide_notification", { notificationId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async deleteNotification(notificationId: string) : Promise<Result<null, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_notification", { notificationId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async clearNotifications() : Promise<Result<null, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("clear_notifications") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async acceptFriendRequest(notificationId: string) : Promise<Result<null, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("accept_friend_request", { notificationId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Trigger one of the `responses` of a v2 notification
+ */
+async respondNotification(notificationId: string, response: InboxResponse) : Promise<Result<null, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("respond_notification", { notificationId, response }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getSettings() : Promise<Result<AppSettings, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_settings") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async saveSettings(settings: AppSettings) : Promise<Result<null, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("save_settings", { settings }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getDeveloperMode() : Promise<Result<boolean, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_developer_mode") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async setDeveloperMode(enabled: boolean) : Promise<Result<null, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_developer_mode", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getStatusPresets() : Promise<Result<StatusPreset[], VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_status_presets") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Create a preset, or replace the one with the same name
+ */
+async saveStatusPreset(preset: StatusPreset) : Promise<Result<null, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("save_status_preset", { preset }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async deleteStatusPreset(name: string) : Promise<Result<boolean, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_status_preset", { name }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Set the status and description stored under `name`
+ */
+async applyStatusPreset(name: string) : Promise<Result<User, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("apply_status_preset", { name }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Statuses the logged-in account had, set from this app or anywhere else, newest first
+ */
+async getStatusHistory(limit: number | null) : Promise<Result<StatusHistoryEntry[], VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_status_history", { limit }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async clearStatusHistory() : Promise<Result<null, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("clear_status_history") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getStatusRules() : Promise<Result<StatusRule[], VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_status_rules") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Create a rule, or update the one with `rule.id`
+ */
+async saveStatusRule(rule: StatusRuleInput) : Promise<Result<StatusRule, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("save_status_rule", { rule }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async deleteStatusRule(id: number) : Promise<Result<boolean, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_status_rule", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getFriendAlerts() : Promise<Result<AlertRule[], VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_friend_alerts") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Create an alert rule, or update the one with `rule.id`
+ */
+async saveFriendAlert(rule: AlertRuleInput) : Promise<Result<AlertRule, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("save_friend_alert", { rule }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async deleteFriendAlert(id: number) : Promise<Result<boolean, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_friend_alert", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getBackendLogs() : Promise<Result<LogEntry[], VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_backend_logs") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async clearBackendLogs() : Promise<Result<null, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("clear_backend_logs") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async exportBackendLogs() : Promise<Result<string, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("export_backend_logs") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getVrchatStatus() : Promise<Result<VRChatStatusResponse, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_vrchat_status") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async dbListTables() : Promise<Result<TableInfo[], VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("db_list_tables") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async dbGetTableSchema(tableName: string) : Promise<Result<ColumnInfo[], VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("db_get_table_schema", { tableName }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async dbGetTableData(tableName: string, limit: number | null, offset: number | null) : Promise<Result<QueryResult, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("db_get_table_data", { tableName, limit, offset }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async dbGetTableCount(tableName: string) : Promise<Result<number, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("db_get_table_count", { tableName }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async dbExecuteQuery(query: string) : Promise<Result<QueryResult, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("db_execute_query", { query }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async checkImageCached(url: string) : Promise<Result<string | null, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("check_image_cached", { url }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async cacheImage(url: string) : Promise<Result<string, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("cache_image", { url }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getCacheDirectory() : Promise<Result<string, VRCError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_cache_directory") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+}
+}
+
+/** user-defined events **/
+
+
+
+/** user-defined constants **/
+
+
+
+/** user-defined types **/
+
+/**
+ * Body of `POST /favorites`
+ */
+export type AddFavoriteRequest = { type: FavoriteType; favoriteId: string; 
+/**
+ * Favorite group name to add the entry to
+ */
+tags: string[] }
+/**
+ * Age verification status
+ * `verified` is obsolete. according to the unofficial docs, Users who have verified and are 18+ can switch to `plus18` status.
+ */
+export type AgeVerificationStatus = 
+/**
+ * Age verification status is hidden
+ */
+"hidden" | 
+/**
+ * Legacy verified status (obsolete)
  */
 "verified" | 
 /**
- * User is verified to be 18+
+ * User is verified to be 18+
+ */
+"18+"
+/**
+ * What happens when a rule matches
+ */
+export type AlertAction = 
+/**
+ * Emit `friend-alert` to the frontend
+ */
+{ type: "event" } | 
+/**
+ * Ask the frontend to show an OS notification via `friend-alert-notification`
+ */
+{ type: "notification" } | 
+/**
+ * POST the alert as JSON
+ */
+{ type: "webhook"; url: string } | 
+/**
+ * Ask the frontend to play the sound registered under `key` via `friend-alert-sound`
+ */
+{ type: "sound"; key: string }
+/**
+ * A stored friend alert
+ */
+export type AlertRule = { id: number; name: string; enabled: boolean; trigger: AlertTrigger; 
+/**
+ * Only this friend, any friend when `None`
+ */
+user_id: string | null; 
+/**
+ * Only friends in this friend favorite group (its `name`, e.g. `group_0`)
+ */
+favorite_group: string | null; actions: AlertAction[]; 
+/**
+ * RFC 3339 timestamp in UTC
+ */
+created_at: string }
+/**
+ * Rule fields set by the user; `id` updates an existing rule instead of adding one
+ */
+export type AlertRuleInput = { id: number | null; name: string; enabled: boolean; trigger: AlertTrigger; user_id: string | null; favorite_group: string | null; actions: AlertAction[] }
+/**
+ * Pipeline event a friend alert reacts to
+ */
+export type AlertTrigger = "friend_online" | 
+/**
+ * A friend moved into a public or group public instance
+ */
+"joined_public_instance" | "avatar_changed"
+export type AppSettings = { developer_mode: boolean }
+export type AvatarPerformance = { android?: string | null; ios?: string | null; standalonewindows?: string | null }
+/**
+ * Aggregated performance overview of uploaded avatars
+ */
+export type AvatarPerformanceReport = { total: number; minUnityVersion: string; platforms: PlatformPerformanceSummary[]; 
+/**
+ * IDs of avatars without an Android build
+ */
+missingQuest: string[]; 
+/**
+ * IDs of avatars built with a Unity version older than `min_unity_version`
+ */
+outdatedUnity: string[]; 
+/**
+ * IDs of avatars with a build that failed the security scan
+ */
+failedScan: string[]; avatars: AvatarReportRow[] }
+/**
+ * Per-avatar line of the report
+ */
+export type AvatarReportRow = { id: string; name: string; releaseStatus: string; pcRating: PerformanceRatings | null; androidRating: PerformanceRatings | null; iosRating: PerformanceRatings | null; hasQuestBuild: boolean; 
+/**
+ * Oldest Unity version across the avatar's current builds
+ */
+unityVersion: string | null; outdatedUnity: boolean; failedScan: boolean }
+export type AvatarStyles = { primary?: string | null; secondary?: string | null }
+export type Badge = { badgeId: string; badgeName?: string; badgeDescription?: string; assignedAt?: string | null; showcased?: boolean; badgeImageUrl?: string | null; updatedAt?: string | null; hidden?: boolean }
+export type ColumnInfo = { cid: number; name: string; type: string; notnull: number; dflt_value: string | null; pk: number }
+/**
+ * Body of `POST /instances`
+ */
+export type CreateInstanceRequest = { worldId: string; type: InstanceType; region: string; 
+/**
+ * User ID for friends/invite instances, group ID for group instances
+ */
+ownerId?: string | null; roleIds: string[]; groupAccessType?: GroupAccessType | null; queueEnabled?: boolean; canRequestInvite?: boolean; ageGate?: boolean; displayName?: string | null }
+/**
+ * User's developer type/staff level
+ */
+export type DeveloperType = 
+/**
+ * Normal user
+ */
+"none" | 
+/**
+ * Trusted user
+ */
+"trusted" | 
+/**
+ * VRChat Developer/Staff
+ */
+"internal" | 
+/**
+ * VRChat Moderator
+ */
+"moderator"
+export type DiscordDetails = { globalName?: string | null; id?: string | null }
+/**
+ * Entry of `/favorites`
+ */
+export type Favorite = { 
+/**
+ * Favorite record ID (`fvrt_...`), used to remove it
+ */
+id: string; type: FavoriteType; 
+/**
+ * ID of the favorited user, world or avatar
+ */
+favoriteId: string; 
+/**
+ * Favorite group names (`name`, not `displayName`) the entry belongs to
+ */
+tags?: string[] }
+/**
+ * Which favorite group a user, world or avatar belongs to
+ */
+export type FavoriteAnnotation = { 
+/**
+ * ID of the favorited user, world or avatar
+ */
+targetId: string; 
+/**
+ * Favorite record ID, needed to remove the favorite
+ */
+favoriteId: string; kind: FavoriteType; groupName: string | null; groupDisplayName: string | null }
+/**
+ * Entry of `/favorite/groups`
+ */
+export type FavoriteGroup = { id: string; ownerId: string; ownerDisplayName?: string | null; 
+/**
+ * Internal name, e.g. `worlds1`, used as the tag on favorites
+ */
+name: string; displayName: string; type: FavoriteType; visibility?: FavoriteGroupVisibility; tags?: string[] }
+/**
+ * Who can see a favorite group
+ */
+export type FavoriteGroupVisibility = "private" | "friends" | "public"
+/**
+ * Response of `/auth/user/favoritelimits`
+ */
+export type FavoriteLimits = { defaultMaxFavoriteGroups?: number; defaultMaxFavoritesPerGroup?: number; maxFavoriteGroups?: FavoriteTypeLimits; maxFavoritesPerGroup?: FavoriteTypeLimits }
+/**
+ * What a favorite or favorite group holds
+ */
+export type FavoriteType = "friend" | "world" | "avatar"
+/**
+ * Per-type limit, as found in `/auth/user/favoritelimits`
+ */
+export type FavoriteTypeLimits = { avatar?: number; friend?: number; world?: number }
+/**
+ * A recorded friend transition
+ */
+export type FeedEntry = { id: number; 
+/**
+ * Logged-in account that observed the event
+ */
+account_id: string; user_id: string; display_name: string; event_type: FeedEventType; previous_value: string | null; value: string | null; 
+/**
+ * Extra context, e.g. the status description or the platform
+ */
+details: string | null; 
+/**
+ * RFC 3339 timestamp in UTC
+ */
+created_at: string }
+/**
+ * Kind of friend transition recorded in the feed
+ */
+export type FeedEventType = "online" | "offline" | "location" | "status" | "avatar" | "bio"
+export type FeedPage = { entries: FeedEntry[]; total: number; has_more: boolean }
+/**
+ * Feed filters, all optional. Time bounds are RFC 3339 timestamps.
+ */
+export type FeedQuery = { user_id: string | null; event_types: FeedEventType[] | null; since: string | null; until: string | null; limit: number | null; offset: number | null }
+/**
+ * Payload of `friend-alert` and `friend-alert-notification`, also the webhook body
+ */
+export type FriendAlert = { ruleId: number; ruleName: string; trigger: AlertTrigger; userId: string; displayName: string; 
+/**
+ * Raw location, when the friend's location is known
+ */
+location: string | null; avatarImageUrl: string | null }
+/**
+ * Payload of `friend-alert-sound`
+ */
+export type FriendAlertSound = { key: string; alert: FriendAlert }
+/**
+ * Friend request status
+ */
+export type FriendRequestStatus = 
+/**
+ * No friend request
+ */
+"" | 
+/**
+ * Outgoing friend request pending
+ */
+"outgoing" | 
+/**
+ * Incoming friend request pending
+ */
+"incoming" | 
+/**
+ * Completed friend request
+ */
+"completed"
+/**
+ * Response of `/user/{userId}/friendStatus`
+ */
+export type FriendStatus = { isFriend?: boolean; incomingRequest?: boolean; outgoingRequest?: boolean }
+export type GoogleDetails = { emailMatches?: boolean | null }
+/**
+ * Response of `/groups/{groupId}`
+ */
+export type Group = { id: string; name: string; shortCode?: string; discriminator?: string; description?: string; iconUrl?: string | null; bannerUrl?: string | null; privacy?: GroupPrivacy; ownerId?: string | null; rules?: string | null; links?: string[]; languages?: string[]; memberCount?: number; onlineMemberCount?: number | null; isVerified?: boolean; joinState?: GroupJoinState; tags?: string[]; membershipStatus?: GroupMemberStatus; 
+/**
+ * The current user's membership, absent when not a member
+ */
+myMember?: GroupMember | null; 
+/**
+ * Only included with `includeRoles=true`
+ */
+roles?: GroupRole[] | null; createdAt?: string | null }
+/**
+ * Who can join a group instance, from the `groupAccessType(...)` tag
+ */
+export type GroupAccessType = "public" | "plus" | "members"
+/**
+ * Response of `/groups/{groupId}/announcements`, the latest announcement only
+ */
+export type GroupAnnouncement = { 
+/**
+ * Missing when the group has never posted an announcement
+ */
+id?: string | null; groupId?: string | null; authorId?: string | null; title?: string | null; text?: string | null; imageUrl?: string | null; createdAt?: string | null; updatedAt?: string | null }
+/**
+ * A mirrored audit log entry
+ */
+export type GroupAuditEntry = { id: string; group_id: string; actor_id: string; actor_display_name: string | null; target_id: string | null; 
+/**
+ * e.g. `group.user.ban`
+ */
+event_type: string; description: string; 
+/**
+ * Event-specific details as JSON
+ */
+data: string | null; 
+/**
+ * RFC 3339 timestamp in UTC
  */
-"18+"
-export type AppSettings = { developer_mode: boolean }
-export type AvatarPerformance = { android?: string | null; ios?: string | null; standalonewindows?: string | null }
-export type AvatarStyles = { primary?: string | null; secondary?: string | null }
-export type Badge = { badgeId: string; badgeName?: string; badgeDescription?: string; assignedAt?: string | null; showcased?: boolean; badgeImageUrl?: string | null; updatedAt?: string | null; hidden?: boolean }
-export type ColumnInfo = { cid: number; name: string; type: string; notnull: number; dflt_value: string | null; pk: number }
+created_at: string }
+export type GroupAuditPage = { entries: GroupAuditEntry[]; total: number; has_more: boolean }
 /**
- * User's developer type/staff level
+ * Audit log filters; everything but `group_id` is optional. Time bounds are RFC 3339 timestamps.
  */
-export type DeveloperType = 
+export type GroupAuditQuery = { group_id: string; actor_id: string | null; target_id: string | null; 
 /**
- * Normal user
+ * Exact event types, or prefixes ending in `.` such as `group.member.`
  */
-"none" | 
+event_types: string[] | null; 
 /**
- * Trusted user
+ * Case-insensitive match against the description
  */
-"trusted" | 
+text: string | null; since: string | null; until: string | null; limit: number | null; offset: number | null }
 /**
- * VRChat Developer/Staff
+ * Entry of `/groups/{groupId}/instances`
  */
-"internal" | 
+export type GroupInstance = { instanceId: string; 
 /**
- * VRChat Moderator
+ * Full location, `worldId:instanceId`
  */
-"moderator"
-export type DiscordDetails = { globalName?: string | null; id?: string | null }
+location: string; memberCount?: number; world?: LimitedWorld | null }
 /**
- * Friend request status
+ * Answer to a pending join request
  */
-export type FriendRequestStatus = 
+export type GroupJoinRequestAction = "accept" | "reject"
 /**
- * No friend request
+ * How new members get in
  */
-"" | 
+export type GroupJoinState = 
+"open" | 
 /**
- * Outgoing friend request pending
+ * Join requests need approval
  */
-"outgoing" | 
+"request" | 
+"invite" | 
+"closed" | 
 /**
- * Incoming friend request pending
+ * A join state this client does not know yet
  */
-"incoming" | 
+"unknown"
+export type GroupMember = { 
 /**
- * Completed friend request
+ * Membership ID (`gmem_...`)
  */
-"completed"
-export type GoogleDetails = { emailMatches?: boolean | null }
+id: string; groupId: string; userId: string; isRepresenting?: boolean; 
+/**
+ * Absent in websocket updates
+ */
+user?: GroupMemberUser | null; roleIds?: string[]; 
+/**
+ * Roles that grant management permissions
+ */
+mRoleIds?: string[]; joinedAt?: string | null; membershipStatus?: GroupMemberStatus; visibility?: string | null; isSubscribedToAnnouncements?: boolean; createdAt?: string | null; bannedAt?: string | null; 
+/**
+ * Only visible to group managers
+ */
+managerNotes?: string | null }
+/**
+ * Membership state of a user in a group
+ */
+export type GroupMemberStatus = 
+"inactive" | 
+"member" | 
+"requested" | 
+"invited" | 
+"banned" | 
+/**
+ * The user blocked the group
+ */
+"userblocked" | 
+/**
+ * A membership status this client does not know yet
+ */
+"unknown"
+/**
+ * User summary embedded in group member listings
+ */
+export type GroupMemberUser = { id: string; displayName: string; thumbnailUrl?: string | null; iconUrl?: string | null; profilePicOverride?: string | null; currentAvatarThumbnailImageUrl?: string | null }
+export type GroupPost = { id: string; groupId: string; authorId?: string | null; editorId?: string | null; 
+/**
+ * `public` or `group`
+ */
+visibility?: string; 
+/**
+ * Roles allowed to see a `group` post, empty means every member
+ */
+roleId?: string[]; title?: string; text?: string; imageUrl?: string | null; createdAt?: string | null; updatedAt?: string | null }
+/**
+ * Who can see or join a group
+ */
+export type GroupPrivacy = 
+/**
+ * Listed in search
+ */
+"default" | 
+/**
+ * Hidden from search
+ */
+"private" | 
+/**
+ * A privacy level this client does not know yet
+ */
+"unknown"
+export type GroupRole = { id: string; groupId: string; name: string; description?: string; isSelfAssignable?: boolean; 
+/**
+ * Permission keys such as `group-bans-manage`, `*` grants everything
+ */
+permissions?: string[]; isManagementRole?: boolean; requiresTwoFactor?: boolean; requiresPurchase?: boolean; order?: number; createdAt?: string | null; updatedAt?: string | null }
+/**
+ * A notification kept in the local inbox, from either notification API version
+ */
+export type InboxNotification = { id: string; 
+/**
+ * Logged-in account that received the notification
+ */
+account_id: string; 
+/**
+ * 1 for `/auth/user/notifications`, 2 for `/notifications`
+ */
+version: number; kind: string; category: string | null; sender_user_id: string | null; sender_username: string | null; title: string | null; message: string | null; 
+/**
+ * Raw JSON `details` (v1) or `data` (v2)
+ */
+details: string | null; responses: InboxResponse[]; image_url: string | null; link: string | null; link_text: string | null; seen: boolean; hidden: boolean; expires_at: string | null; 
+/**
+ * RFC 3339 timestamp
+ */
+created_at: string }
+/**
+ * An action offered by a v2 notification, e.g. accept or decline
+ */
+export type InboxResponse = { kind: string; data: string | null; icon: string | null; text: string | null }
+export type Instance = { 
+/**
+ * Full location, `worldId:instanceId`
+ */
+id: string; location?: string; 
+/**
+ * Everything after `worldId:`
+ */
+instanceId: string; worldId: string; name?: string; displayName?: string | null; type?: InstanceType; region?: string; photonRegion?: string | null; ownerId?: string | null; groupAccessType?: GroupAccessType | null; capacity?: number; recommendedCapacity?: number; 
+/**
+ * Current player count
+ */
+userCount?: number; n_users?: number; platforms?: InstancePlatforms; 
+/**
+ * Only returned to the instance owner or group moderators
+ */
+users?: LimitedUserFriend[]; full?: boolean; hasCapacityForYou?: boolean | null; queueEnabled?: boolean; queueSize?: number; active?: boolean; canRequestInvite?: boolean; permanent?: boolean; strict?: boolean; ageGate?: boolean | null; roleRestricted?: boolean | null; closedAt?: string | null; hardClose?: boolean | null; nonce?: string | null; shortName?: string | null; secureName?: string | null; tags?: string[]; world?: LimitedWorld | null }
+/**
+ * Player count per platform
+ */
+export type InstancePlatforms = { android?: number; ios?: number; standalonewindows?: number }
+/**
+ * Response of `/instances/{location}/shortName`
+ */
+export type InstanceShortName = { secureName?: string | null; shortName?: string | null }
+/**
+ * Instance type as used by `/instances`
+ */
+export type InstanceType = 
+/**
+ * Anyone can join
+ */
+"public" | 
+/**
+ * Friends+ (friends of anyone in the instance)
+ */
+"hidden" | 
+/**
+ * Friends of the owner only
+ */
+"friends" | 
+/**
+ * Invite and Invite+
+ */
+"private" | 
+/**
+ * Group instance, see `groupAccessType`
+ */
+"group"
+export type JsonValue = null | boolean | number | string | JsonValue[] | Partial<{ [key in string]: JsonValue }>
 export type LimitedAvatar = { id: string; name: string; description?: string | null; authorId?: string | null; authorName?: string | null; imageUrl?: string | null; thumbnailImageUrl?: string | null; assetUrl?: string | null; unityPackageUrl?: string | null; releaseStatus?: ReleaseStatus; featured?: boolean | null; searchable?: boolean | null; listingDate?: string | null; createdAt?: string | null; updatedAt?: string | null; version?: number | null; tags?: string[]; performance?: AvatarPerformance | null; styles?: AvatarStyles | null; unityPackages?: UnityPackageSummary[] }
+/**
+ * Entry of `/users?search=` and `/users/{userId}/mutuals/friends`
+ */
+export type LimitedUser = { id: string; displayName: string; bio?: string; bioLinks?: string[]; currentAvatarImageUrl?: string | null; currentAvatarThumbnailImageUrl?: string | null; currentAvatarTags?: string[]; developerType?: DeveloperType; isFriend?: boolean; lastPlatform?: string | null; profilePicOverride?: string | null; profilePicOverrideThumbnail?: string | null; pronouns?: string | null; status?: UserStatus; statusDescription?: string; tags?: string[]; userIcon?: string | null }
 export type LimitedUserFriend = { id: string; displayName: string; bio?: string; bioLinks?: string[]; currentAvatarImageUrl?: string | null; currentAvatarThumbnailImageUrl?: string | null; currentAvatarTags?: string[]; developerType?: DeveloperType; friendKey?: string | null; isFriend?: boolean; imageUrl?: string | null; lastPlatform?: string | null; location?: string | null; lastLogin?: string | null; lastActivity?: string | null; lastMobile?: string | null; platform?: string; profilePicOverride?: string | null; profilePicOverrideThumbnail?: string | null; status?: UserStatus; statusDescription?: string; tags?: string[]; userIcon?: string | null }
 export type LimitedWorld = { id: string; name: string; description?: string | null; authorId?: string | null; authorName?: string | null; imageUrl?: string | null; thumbnailImageUrl?: string | null; releaseStatus?: ReleaseStatus; publicationDate?: string | null; createdAt?: string | null; updatedAt?: string | null; labsPublicationDate?: string | null; visits?: number | null; favorites?: number | null; popularity?: number | null; occupants?: number | null; capacity?: number | null; recommendedCapacity?: number | null; heat?: number | null; organization?: string | null; previewYoutubeId?: string | null; tags?: string[]; unityPackages?: UnityPackageSummary[] }
+/**
+ * A named, unlimited collection of worlds, avatars or users
+ */
+export type LocalCollection = { id: number; name: string; kind: LocalFavoriteKind; description: string | null; entry_count: number; 
+/**
+ * RFC 3339 timestamps in UTC
+ */
+created_at: string; updated_at: string }
+export type LocalFavoriteEntry = { id: number; collection_id: number; 
+/**
+ * ID of the world, avatar or user
+ */
+target_id: string; 
+/**
+ * Display name at the time it was saved
+ */
+name: string | null; notes: string | null; tags: string[]; 
+/**
+ * Favorite record ID once the entry was promoted to a VRChat favorite
+ */
+vrchat_favorite_id: string | null; added_at: string; updated_at: string }
+/**
+ * Entry fields set by the user; adding an existing target updates it instead
+ */
+export type LocalFavoriteInput = { target_id: string; name: string | null; notes: string | null; tags?: string[] }
+/**
+ * What a local collection holds
+ */
+export type LocalFavoriteKind = "world" | "avatar" | "user"
+/**
+ * Import/export file format
+ */
+export type LocalFavoritesFormat = "json" | "csv"
+export type LocalFavoritesImport = { collections_created: number; entries_added: number; entries_updated: number }
 export type LogEntry = { timestamp: string; level: string; source: string; module: string; message: string }
 export type LoginResult = { type: "Success"; user: User } | { type: "TwoFactorRequired"; methods: string[] }
 /**
  * Sort order for API queries
  */
+export type NotificationPayload = ({ id?: string | null; type?: string | null; category?: string | null; senderUserId?: string | null; senderUsername?: string | null; receiverUserId?: string | null; message?: string | null; details?: JsonValue | null; imageUrl?: string | null; link?: string | null; linkText?: string | null; seen?: boolean | null; canRespond?: boolean | null; expiresAt?: string | null; expiryAfterSeen?: number | null; requireSeen?: boolean | null; hideAfterSeen?: boolean | null; createdAt?: string | null; updatedAt?: string | null }) & (Partial<{ [key in string]: JsonValue }>)
 export type OrderOption = 
 /**
  * Ascending order
@@ -422,6 +1628,26 @@ export type OrderOption =
  */
 "descending"
 export type PastDisplayName = { displayName: string; updatedAt?: string | null; reverted?: boolean | null }
+/**
+ * Rating counts and flagged avatars for one platform
+ */
+export type PlatformPerformanceSummary = { 
+/**
+ * VRChat platform key, e.g. `standalonewindows` or `android`
+ */
+platform: string; 
+/**
+ * Avatars with a build for this platform
+ */
+built: number; excellent: number; good: number; medium: number; poor: number; veryPoor: number; 
+/**
+ * Built but not rated yet
+ */
+unrated: number; 
+/**
+ * IDs of avatars rated Poor or VeryPoor
+ */
+poorOrWorse: string[] }
 /**
  * Avatar performance ratings
  */
@@ -450,6 +1676,46 @@ export type PerformanceRatings =
  * Very poor performance
  */
 "VeryPoor"
+/**
+ * Entry of `/auth/user/playermoderations`
+ */
+export type PlayerModeration = { id: string; type: PlayerModerationType; sourceUserId: string; sourceDisplayName?: string | null; targetUserId: string; targetDisplayName?: string | null; created?: string | null }
+/**
+ * Kind of player moderation applied by the current user
+ */
+export type PlayerModerationType = 
+/**
+ * User is blocked
+ */
+"block" | 
+/**
+ * User's voice is muted
+ */
+"mute" | 
+/**
+ * User's voice is explicitly unmuted
+ */
+"unmute" | 
+/**
+ * User's avatar is hidden
+ */
+"hideAvatar" | 
+/**
+ * User's avatar is always shown
+ */
+"showAvatar" | 
+/**
+ * Interactions with the user are enabled
+ */
+"interactOn" | 
+/**
+ * Interactions with the user are disabled
+ */
+"interactOff" | 
+/**
+ * A type this client does not know yet, e.g. `muteChat`
+ */
+"unknown"
 export type QueryResult = { columns: string[]; rows: (Partial<{ [key in string]: string }>)[]; rows_affected: number | null }
 /**
  * Release status of avatars and worlds
@@ -471,10 +1737,106 @@ export type ReleaseStatus =
  * Filter for all statuses
  */
 "all"
+/**
+ * Response of `/users/{userId}/groups/represented`
+ */
+export type RepresentedGroup = { 
+/**
+ * Missing when the user doesn't represent any group
+ */
+groupId?: string | null; name?: string; shortCode?: string; discriminator?: string; description?: string; iconUrl?: string | null; bannerUrl?: string | null; privacy?: GroupPrivacy; ownerId?: string | null; memberCount?: number }
+/**
+ * Notification created by an invite call, as returned by the API
+ */
+export type SentNotification = { id: string; type: string; senderUserId?: string | null; receiverUserId?: string | null; message?: string | null; createdAt?: string | null }
+/**
+ * Payload of the `session-expired` event emitted when VRChat rejects the stored session
+ */
+export type SessionExpiredEvent = { userId: string | null; message: string }
+/**
+ * Sort field for list endpoints such as `/worlds` and `/avatars`
+ */
+export type SortOption = "popularity" | "heat" | "trust" | "shuffle" | "random" | "favorites" | "publicationDate" | "labsPublicationDate" | "created" | "updated" | "order" | "relevance" | "magic" | "name"
+/**
+ * A status the user had, set from this app or from anywhere else
+ */
+export type StatusHistoryEntry = { id: number; account_id: string; 
+/**
+ * `None` for entries seeded from VRChat's `statusHistory`, which only keeps descriptions
+ */
+status: UserStatus | null; status_description: string; 
+/**
+ * Preset the status was applied from, if any
+ */
+preset_name: string | null; 
+/**
+ * RFC 3339 timestamp in UTC
+ */
+created_at: string }
 export type StatusIndicator = "none" | "minor" | "major" | "critical"
 export type StatusPage = { id: string; name: string; url: string; time_zone: string; updated_at: string }
+/**
+ * A named status the user can switch to in one click
+ */
+export type StatusPreset = { name: string; status: UserStatus; status_description: string }
+/**
+ * A stored automatic status change
+ */
+export type StatusRule = { id: number; name: string; enabled: boolean; kind: StatusRuleKind; 
+/**
+ * Status the rule switches to
+ */
+status: UserStatus; 
+/**
+ * `None` keeps the current description
+ */
+status_description: string | null; 
+/**
+ * Local time of day as `HH:MM`, only for `at_time`
+ */
+time: string | null; 
+/**
+ * Status that is reverted, only for `revert_after`
+ */
+from_status: UserStatus | null; 
+/**
+ * Only for `revert_after`
+ */
+minutes: number | null; 
+/**
+ * RFC 3339 timestamp in UTC of the last change this rule made
+ */
+last_applied_at: string | null }
+/**
+ * A status change made by a rule, emitted as `status-rule-applied`
+ */
+export type StatusRuleChange = { rule_id: number; rule_name: string; status: UserStatus; status_description: string }
+/**
+ * Rule fields set by the user; `id` updates an existing rule instead of adding one
+ */
+export type StatusRuleInput = { id: number | null; name: string; enabled: boolean; kind: StatusRuleKind; status: UserStatus; status_description: string | null; time: string | null; from_status: UserStatus | null; minutes: number | null }
+/**
+ * What makes a status rule fire
+ */
+export type StatusRuleKind = 
+/**
+ * Every day at `time`
+ */
+"at_time" | 
+/**
+ * `minutes` after the status became `from_status`
+ */
+"revert_after" | 
+/**
+ * While the current user is in an invite or invite+ instance
+ */
+"private_instance"
 export type SteamDetails = { avatar?: string | null; avatarfull?: string | null; avatarhash?: string | null; avatarmedium?: string | null; communityvisibilitystate?: number | null; gameextrainfo?: string | null; gameid?: string | null; loccountrycode?: string | null; locstatecode?: string | null; personaname?: string | null; personastate?: number | null; personastateflags?: number | null; primaryclanid?: string | null; profilestate?: number | null; profileurl?: string | null; steamid?: string | null; timecreated?: number | null }
-export type StoredAccount = { user_id: string; username: string; display_name: string; avatar_url?: string | null; avatar_fallback_url?: string | null; auth_cookie: string | null; two_factor_cookie: string | null; last_login: string }
+export type StoredAccount = { user_id: string; username: string; display_name: string; avatar_url?: string | null; avatar_fallback_url?: string | null; auth_cookie: string | null; two_factor_cookie: string | null; last_login: string; 
+/**
+ * Set when VRChat rejected the stored session and the user has to sign in again
+ */
+needs_relogin?: boolean }
 export type SystemStatus = { 
 /**
  * Indicator of system status
@@ -486,8 +1848,77 @@ indicator: StatusIndicator;
 description: string }
 export type TableInfo = { name: string; sql: string }
 export type UnityPackageSummary = { id?: string | null; assetUrl?: string | null; assetVersion?: number | null; platform?: string | null; unityVersion?: string | null; createdAt?: string | null; performanceRating?: string | null; scanStatus?: string | null; variant?: string | null; unitySortNumber?: number | null; impostorizerVersion?: string | null }
+/**
+ * Body of `PUT /favorite/group/{type}/{name}/{userId}`
+ */
+export type UpdateFavoriteGroupRequest = { displayName?: string | null; visibility?: FavoriteGroupVisibility | null; tags?: string[] | null }
 export type UpdateStatusRequest = { status: UserStatus; statusDescription: string }
-export type User = { id: string; username?: string; displayName: string; acceptedPrivacyVersion?: number | null; acceptedTosVersion?: number | null; accountDeletionDate?: string | null; state?: string; status?: UserStatus; statusDescription?: string; statusFirstTime?: boolean | null; statusHistory?: string[]; bio?: string; bioLinks?: string[]; ageVerificationStatus?: AgeVerificationStatus; ageVerified?: boolean | null; isAdult?: boolean | null; dateJoined?: string | null; lastLogin?: string | null; lastActivity?: string | null; lastPlatform?: string | null; lastMobile?: string | null; platform?: string; platformHistory?: string[]; location?: string | null; travelingToWorld?: string | null; travelingToLocation?: string | null; travelingToInstance?: string | null; homeLocation?: string | null; instanceId?: string | null; worldId?: string | null; allowAvatarCopying?: boolean | null; twoFactorAuthEnabled?: boolean | null; twoFactorAuthEnabledDate?: string | null; currentAvatar?: string | null; fallbackAvatar?: string | null; currentAvatarTags?: string[]; profilePicOverride?: string | null; profilePicOverrideThumbnail?: string | null; userIcon?: string | null; currentAvatarImageUrl?: string | null; currentAvatarThumbnailImageUrl?: string | null; bannerId?: string | null; bannerUrl?: string | null; pronouns?: string | null; languages?: string[] | null; pronounsHistory?: string[]; friends?: string[]; friendGroupNames?: string[]; friendKey?: string | null; friendRequestStatus?: FriendRequestStatus; pastDisplayNames?: PastDisplayName[] | null; badges?: Badge[] | null; tags?: string[]; isFriend?: boolean | null; note?: string | null; developerType?: DeveloperType; isBoopingEnabled?: boolean | null; receiveMobileInvitations?: boolean | null; hideContentFilterSettings?: boolean | null; hasBirthday?: boolean | null; hasEmail?: boolean | null; hasPendingEmail?: boolean | null; hasLoggedInFromClient?: boolean | null; unsubscribe?: boolean | null; updatedAt?: string | null; emailVerified?: boolean | null; obfuscatedEmail?: string | null; userLanguage?: string | null; userLanguageCode?: string | null; discordId?: string | null; discordDetails?: DiscordDetails | null; googleId?: string | null; googleDetails?: GoogleDetails | null; steamId?: string | null; steamDetails?: SteamDetails | null; oculusId?: string | null; picoId?: string | null; viveId?: string | null }
+/**
+ * Profile fields of the current user to change; fields left `None` are not sent
+ */
+export type UpdateUserRequest = { status?: UserStatus | null; statusDescription?: string | null; bio?: string | null; bioLinks?: string[] | null; pronouns?: string | null; 
+/**
+ * Language tags such as `language_eng`; they replace the current language tags while the
+ * user's other tags are kept
+ */
+tags?: string[] | null; 
+/**
+ * File URL of an uploaded icon, VRC+ only
+ */
+userIcon?: string | null }
+/**
+ * Payload of the `uploaded-worlds-progress` event emitted while uploaded worlds are backfilled
+ */
+export type UploadedWorldsProgress = { 
+/**
+ * Worlds updated since the previous event; the first event carries the whole list
+ */
+worlds: LimitedWorld[]; 
+/**
+ * Detail requests finished so far, failed ones included
+ */
+completed: number; 
+/**
+ * Detail requests needed, after the world cache was consulted
+ */
+total: number }
+export type User = { id: string; username?: string; displayName: string; acceptedPrivacyVersion?: number | null; acceptedTosVersion?: number | null; accountDeletionDate?: string | null; state?: string; status?: UserStatus; statusDescription?: string; statusFirstTime?: boolean | null; statusHistory?: string[]; bio?: string; bioLinks?: string[]; ageVerificationStatus?: AgeVerificationStatus; ageVerified?: boolean | null; isAdult?: boolean | null; dateJoined?: string | null; lastLogin?: string | null; lastActivity?: string | null; lastPlatform?: string | null; lastMobile?: string | null; platform?: string; platformHistory?: string[]; location?: string | null; travelingToWorld?: string | null; travelingToLocation?: string | null; travelingToInstance?: string | null; homeLocation?: string | null; instanceId?: string | null; worldId?: string | null; allowAvatarCopying?: boolean | null; twoFactorAuthEnabled?: boolean | null; twoFactorAuthEnabledDate?: string | null; currentAvatar?: string | null; fallbackAvatar?: string | null; currentAvatarTags?: string[]; profilePicOverride?: string | null; profilePicOverrideThumbnail?: string | null; userIcon?: string | null; currentAvatarImageUrl?: string | null; currentAvatarThumbnailImageUrl?: string | null; bannerId?: string | null; bannerUrl?: string | null; pronouns?: string | null; languages?: string[] | null; pronounsHistory?: string[]; friends?: string[]; friendGroupNames?: string[]; friendKey?: string | null; friendRequestStatus?: FriendRequestStatus; pastDisplayNames?: PastDisplayName[] | null; badges?: Badge[] | null; tags?: string[]; isFriend?: boolean | null; note?: string | null; developerType?: DeveloperType; isBoopingEnabled?: boolean | null; receiveMobileInvitations?: boolean | null; hideContentFilterSettings?: boolean | null; hasBirthday?: boolean | null; hasEmail?: boolean | null; hasPendingEmail?: boolean | null; hasLoggedInFromClient?: boolean | null; unsubscribe?: boolean | null; updatedAt?: string | null; emailVerified?: boolean | null; obfuscatedEmail?: string | null; userLanguage?: string | null; userLanguageCode?: string | null; discordId?: string | null; discordDetails?: DiscordDetails | null; googleId?: string | null; googleDetails?: GoogleDetails | null; steamId?: string | null; steamDetails?: SteamDetails | null; oculusId?: string | null; picoId?: string | null; viveId?: string | null; 
+/**
+ * Our moderations of this user, filled in by `get_user_by_id`; never sent by VRChat
+ */
+moderation?: UserModerationState | null }
+/**
+ * Entry of `/users/{userId}/groups`
+ */
+export type UserGroup = { 
+/**
+ * Membership ID (`gmem_...`), see `group_id` for the group itself
+ */
+id: string; groupId: string; name: string; shortCode?: string; discriminator?: string; description?: string; iconUrl?: string | null; bannerUrl?: string | null; privacy?: GroupPrivacy; ownerId?: string | null; memberCount?: number; memberVisibility?: string | null; isRepresenting?: boolean; mutualGroup?: boolean; lastPostCreatedAt?: string | null }
+/**
+ * Moderations the current user applied to another user
+ */
+export type UserModerationState = { userId: string; blocked: boolean; muted: boolean; avatarHidden: boolean; avatarShown: boolean; interactionDisabled: boolean; moderations: PlayerModeration[] }
+/**
+ * Everything the profile page shows, fetched in one go
+ */
+export type UserProfile = { user: User; 
+/**
+ * Friends shared with the current user
+ */
+mutualFriends: LimitedUser[]; groups: UserGroup[]; representedGroup: RepresentedGroup | null; 
+/**
+ * Public worlds, most recently updated first
+ */
+worlds: LimitedWorld[]; 
+/**
+ * Public avatars, most recently updated first
+ */
+avatars: LimitedAvatar[]; 
+/**
+ * Sections that failed to load and were left empty, e.g. `mutualFriends`
+ */
+unavailable: string[] }
 /**
  * State of the user
  */
@@ -529,7 +1960,8 @@ export type UserStatus =
  */
 "offline"
 /**
- * Main error type for VRChat API operations
+ * Main error type for VRChat API operations.
+ * The serialized `type` tag doubles as a stable, machine-readable error code for the frontend.
  */
 export type VRCError = 
 /**
@@ -544,6 +1976,22 @@ export type VRCError =
  * Authentication errors
  */
 { type: "Authentication"; data: string } | 
+/**
+ * The stored session was rejected by VRChat and a new login is required
+ */
+{ type: "SessionExpired"; data: string } | 
+/**
+ * The request was made without any session credentials
+ */
+{ type: "MissingCredentials"; data: string } | 
+/**
+ * The session is valid but not allowed to access the resource (HTTP 403)
+ */
+{ type: "Forbidden"; data: string } | 
+/**
+ * The requested resource does not exist (HTTP 404)
+ */
+{ type: "NotFound"; data: string } | 
 /**
  * Rate limiting error
  */
@@ -564,6 +2012,46 @@ export type VRCError =
  * Response from VRChat status API
  */
 export type VRChatStatusResponse = { page: StatusPage; status: SystemStatus }
+/**
+ * Which world list to query
+ */
+export type WorldListSource = 
+/**
+ * `/worlds`, the general search
+ */
+"all" | 
+/**
+ * `/worlds/active`, worlds with players in them
+ */
+"active" | 
+/**
+ * `/worlds/recent`, recently visited by the current user
+ */
+"recent" | 
+/**
+ * `/worlds/favorites`, favorited by the current user
+ */
+"favorites"
+/**
+ * Filters for a world search. Everything is optional; one call returns one page.
+ */
+export type WorldSearchParams = { source?: WorldListSource; search?: string | null; 
+/**
+ * Worlds must have all of these tags
+ */
+tags?: string[]; 
+/**
+ * Worlds must have none of these tags
+ */
+excludeTags?: string[]; featured?: boolean | null; sort?: SortOption | null; order?: OrderOption | null; 
+/**
+ * Only worlds by this author
+ */
+userId?: string | null; releaseStatus?: ReleaseStatus | null; platform?: string | null; 
+/**
+ * Page size, 1 to 100
+ */
+n?: number | null; offset?: number | null }
 
 /** tauri-specta globals **/
 
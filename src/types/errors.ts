@@ -19,6 +19,13 @@ export class VRChatError extends Error {
     return this.vrcError.type;
   }
 
+  /**
+   * Machine-readable error code, match on this instead of the message text
+   */
+  get code(): VRCError['type'] {
+    return this.vrcError.type;
+  }
+
   /**
    * Check if this is a network error
    */
@@ -40,6 +47,34 @@ export class VRChatError extends Error {
     return this.vrcError.type === 'Authentication';
   }
 
+  /**
+   * Check if the stored session was rejected and the user must log in again
+   */
+  isSessionExpired(): boolean {
+    return this.vrcError.type === 'SessionExpired';
+  }
+
+  /**
+   * Check if the request was made without session credentials
+   */
+  isMissingCredentials(): boolean {
+    return this.vrcError.type === 'MissingCredentials';
+  }
+
+  /**
+   * Check if access to the resource was denied
+   */
+  isForbidden(): boolean {
+    return this.vrcError.type === 'Forbidden';
+  }
+
+  /**
+   * Check if the requested resource does not exist
+   */
+  isNotFound(): boolean {
+    return this.vrcError.type === 'NotFound';
+  }
+
   /**
    * Check if this is a rate limit error
    */
@@ -65,10 +100,21 @@ export class VRChatError extends Error {
    * Get the HTTP status code if this is an HTTP error
    */
   getStatusCode(): number | null {
-    if (this.vrcError.type === 'Http') {
-      return this.vrcError.data.status;
+    switch (this.vrcError.type) {
+      case 'Http':
+        return this.vrcError.data.status;
+      case 'SessionExpired':
+      case 'MissingCredentials':
+        return 401;
+      case 'Forbidden':
+        return 403;
+      case 'NotFound':
+        return 404;
+      case 'RateLimit':
+        return 429;
+      default:
+        return null;
     }
-    return null;
   }
 
   /**
@@ -90,6 +136,14 @@ export function getErrorMessage(error: VRCError): string {
       return `HTTP ${error.data.status}: ${error.data.message}`;
     case 'Authentication':
       return `Authentication error: ${error.data}`;
+    case 'SessionExpired':
+      return `Session expired: ${error.data}`;
+    case 'MissingCredentials':
+      return `Missing credentials: ${error.data}`;
+    case 'Forbidden':
+      return `Forbidden: ${error.data}`;
+    case 'NotFound':
+      return `Not found: ${error.data}`;
     case 'RateLimit':
       return `Rate limit: ${error.data}`;
     case 'Parse':
@@ -132,6 +186,18 @@ export function getUserFriendlyMessage(error: VRCError): string {
         return 'Two-factor authentication required.';
       }
       return 'Authentication failed. Please try again.';
+
+    case 'SessionExpired':
+      return 'Your session has expired. Please log in again.';
+
+    case 'MissingCredentials':
+      return 'You are not logged in. Please log in to continue.';
+
+    case 'Forbidden':
+      return 'Access forbidden. Your account may be restricted.';
+
+    case 'NotFound':
+      return 'Resource not found.';
     
     case 'RateLimit':
       return 'Too many attempts. Please wait a few minutes before trying again.';